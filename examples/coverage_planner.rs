@@ -0,0 +1,37 @@
+//! Plan a coverage pass over a rectangular field which enters and
+//! leaves through neighbouring cells on its bottom edge, printing the
+//! pass as a list of moves.
+//!
+//! Run with `cargo run --example coverage_planner`
+
+use grid_solver::gridextension::GridExtension;
+use grid_solver::gridpath::GridPath;
+use grid_solver::gridproblem::GridProblem;
+
+fn main() {
+    //Enter and leave the 8 by 6 field at cells 3 and 4 of its bottom edge
+    let mut problem: GridProblem = match GridProblem::entry_exit_on_edge(8, 6, GridExtension::Down, 3) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let pass: GridPath = problem.solve().unwrap();
+    println!("{}\n", pass);
+
+    //Print a move for each step of the pass
+    for (v, w) in pass.get_vertex_order().iter().zip(pass.get_vertex_order().iter().skip(1)) {
+        let direction: &str = if w[0] > v[0] {
+            "right"
+        } else if w[0] < v[0] {
+            "left"
+        } else if w[1] > v[1] {
+            "up"
+        } else {
+            "down"
+        };
+        println!("({}, {}) -> ({}, {}) {}", v[0], v[1], w[0], w[1], direction);
+    }
+    println!("{} bends over {} steps", pass.bend_points().len(), pass.get_vertex_order().len() - 1);
+}
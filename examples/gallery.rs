@@ -0,0 +1,29 @@
+//! Enumerate every acceptable pair of endpoints on a 5 by 4 grid and
+//! render the solution for each, using the unicode glyphs and marking
+//! the endpoints.
+//!
+//! Run with `cargo run --example gallery`
+
+use grid_solver::acceptablepairs::AcceptablePairs;
+use grid_solver::glyphset::GlyphSet;
+use grid_solver::gridpath::GridPath;
+use grid_solver::gridproblem::GridProblem;
+use grid_solver::pairoptions::PairOptions;
+use grid_solver::renderoptions::RenderOptions;
+
+fn main() {
+    let options: RenderOptions = RenderOptions {
+        glyphs: GlyphSet::UNICODE,
+        highlight: Vec::new()
+    };
+
+    //Solve and render each acceptable problem
+    let mut num_solved: usize = 0;
+    for (start, end) in AcceptablePairs::new(5, 4, PairOptions::default()) {
+        let mut problem: GridProblem = GridProblem::new(5, 4, start, end);
+        let solution: GridPath = problem.solve().unwrap();
+        println!("{:?} -> {:?}\n{}\n", start, end, solution.render(&options));
+        num_solved += 1;
+    }
+    println!("{} acceptable problems solved", num_solved);
+}
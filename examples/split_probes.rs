@@ -0,0 +1,37 @@
+//! Solve 200 by 200 grid problems with far apart endpoints and report
+//! how many split positions were probed, against the number a scan of
+//! every position along every cut would have probed.  Exits with an
+//! error if no positions were skipped.
+//!
+//! Run with `cargo run --release --example split_probes`
+
+use grid_solver::gridproblem::GridProblem;
+use grid_solver::solvestats::SolveStats;
+
+fn main() {
+    let endpoints: Vec<([usize; 2], [usize; 2])> = vec![
+        ([0, 0], [199, 198]),
+        ([0, 0], [198, 199]),
+        ([0, 199], [198, 0])
+    ];
+    for (start, end) in endpoints {
+        let mut problem: GridProblem = GridProblem::new(200, 200, start, end);
+        let (solution, stats): (_, SolveStats) = problem.solve_with_stats();
+        if solution.is_none() {
+            eprintln!("No solution from {:?} to {:?}", start, end);
+            std::process::exit(1);
+        }
+        let reduction: f64 = 1.0 - (stats.num_split_probes as f64 / stats.num_split_candidates.max(1) as f64);
+        println!(
+            "{:?} -> {:?}: {} probes of {} candidates ({:.1}% skipped) in {:?}",
+            start, end, stats.num_split_probes, stats.num_split_candidates, 100.0 * reduction, stats.wall_time
+        );
+
+        //The pruning should skip about half of the candidates, so fail
+        //loudly if it stops skipping any
+        if stats.num_split_probes >= stats.num_split_candidates {
+            eprintln!("No split positions were skipped from {:?} to {:?}", start, end);
+            std::process::exit(1);
+        }
+    }
+}
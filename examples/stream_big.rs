@@ -0,0 +1,35 @@
+//! Solve a 400 by 400 grid problem and check the solution by
+//! streaming its vertices through a `StreamingValidator`, which only
+//! keeps one bit per vertex.
+//!
+//! Run with `cargo run --release --example stream_big`
+
+use grid_solver::gridpath::GridPath;
+use grid_solver::gridproblem::GridProblem;
+use grid_solver::streamingvalidator::StreamingValidator;
+use std::time::Instant;
+
+fn main() {
+    //Solve the problem from the bottom left to the bottom right corner
+    let started: Instant = Instant::now();
+    let mut problem: GridProblem = GridProblem::new(400, 400, [0, 0], [399, 0]);
+    let solution: GridPath = problem.solve().unwrap();
+    println!("Solved in {:?}", started.elapsed());
+
+    //Stream the vertices of the solution through the validator
+    let started: Instant = Instant::now();
+    let mut validator: StreamingValidator = StreamingValidator::new(400, 400);
+    for vertex in solution.get_vertex_order().iter() {
+        if let Err(e) = validator.push(*vertex) {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+    match validator.finish() {
+        Ok(n) => println!("Validated {} vertices in {:?}", n, started.elapsed()),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
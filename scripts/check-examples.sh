@@ -0,0 +1,10 @@
+#!/bin/sh
+#Check that the examples build against the current library, and run the
+#ones which finish quickly in a debug build, and the split probe benchmark
+#in a release build
+set -e
+cd "$(dirname "$0")/.."
+cargo build --examples
+cargo run --quiet --example gallery > /dev/null
+cargo run --quiet --example coverage_planner > /dev/null
+cargo run --quiet --release --example split_probes
@@ -0,0 +1,15 @@
+#!/bin/sh
+#Check that the core builds and its tests pass without the standard
+#library, for the host and, when its target is installed, builds for a
+#bare metal microcontroller
+set -e
+cd "$(dirname "$0")/.."
+cargo build --lib --no-default-features
+cargo build --lib --no-default-features --features alloc
+cargo test --no-default-features
+cargo test --no-default-features --features alloc
+if rustup target list --installed 2>/dev/null | grep -q '^thumbv7em-none-eabihf$'; then
+    cargo build --lib --no-default-features --features alloc --target thumbv7em-none-eabihf
+else
+    echo "thumbv7em-none-eabihf is not installed, skipping the bare metal build"
+fi
@@ -0,0 +1,157 @@
+use crate::gridgraph::GridGraph;
+use crate::pairoptions::PairOptions;
+
+/// # AcceptablePairs struct
+///
+/// An `AcceptablePairs` iterates over the (start, end) vertex pairs of
+/// an n by m grid graph between which a Hamiltonian path exists, that
+/// is the pairs which are color compatible and not forbidden.
+///
+/// Pairs are yielded in row-major order of their start vertex, then in
+/// row-major order of their end vertex, and are never materialized.
+pub struct AcceptablePairs {
+    n: usize,
+    m: usize,
+    options: PairOptions,
+    start_index: usize,
+    end_index: usize
+}
+
+impl AcceptablePairs {
+    /// Initialize an AcceptablePairs given the grid dimensions (n by m)
+    /// and the options filtering the pairs
+    pub fn new(n: usize, m: usize, options: PairOptions) -> AcceptablePairs {
+        AcceptablePairs {
+            n: n,
+            m: m,
+            options: options,
+            start_index: 0,
+            end_index: 0
+        }
+    }
+
+    /// Check if the pair is acceptable and passes the options' filters
+    fn is_yielded(&self, start: [usize; 2], end: [usize; 2]) -> bool {
+        //A single vertex grid has a path from its only vertex to itself
+        if self.n * self.m == 1 {
+            return true;
+        }
+        if start == end {
+            return false;
+        }
+        if self.options.starts_on_boundary &&
+           start[0] != 0 && start[1] != 0 && start[0] != self.n - 1 && start[1] != self.m - 1 {
+            return false;
+        }
+        if let Some(region) = self.options.ends_in_region {
+            if !region.contains(end) {
+                return false;
+            }
+        }
+        GridGraph::are_color_compatible_with_dimensions(self.n, self.m, start, end) &&
+        !GridGraph::is_forbidden_with_dimensions(self.n, self.m, start, end)
+    }
+}
+
+impl Iterator for AcceptablePairs {
+    type Item = ([usize; 2], [usize; 2]);
+
+    /// Get the next acceptable pair
+    fn next(&mut self) -> Option<([usize; 2], [usize; 2])> {
+        let num_cells: usize = self.n * self.m;
+        while self.start_index < num_cells {
+            //Move on to the next start vertex once its end vertices run out
+            if self.end_index >= num_cells {
+                self.start_index += 1;
+                self.end_index = if self.options.distinct_unordered { self.start_index } else { 0 };
+                continue;
+            }
+
+            let start: [usize; 2] = GridGraph::coords_of_with_width(self.n, self.start_index);
+            let end: [usize; 2] = GridGraph::coords_of_with_width(self.n, self.end_index);
+            self.end_index += 1;
+            if self.is_yielded(start, end) {
+                return Some((start, end));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gridproblem::GridProblem;
+    use crate::rect::Rect;
+
+    #[test]
+    fn ordered_pairs_4_by_4() {
+        //The first ten pairs run through the ends of the first start
+        //vertex before moving on to the second
+        let pairs: Vec<([usize; 2], [usize; 2])> = AcceptablePairs::new(4, 4, PairOptions::default()).take(10).collect();
+        assert_eq!(pairs, vec![
+            ([0, 0], [1, 0]), ([0, 0], [3, 0]), ([0, 0], [0, 1]), ([0, 0], [2, 1]),
+            ([0, 0], [1, 2]), ([0, 0], [3, 2]), ([0, 0], [0, 3]), ([0, 0], [2, 3]),
+            ([1, 0], [0, 0]), ([1, 0], [2, 0])
+        ]);
+    }
+
+    #[test]
+    fn pairs_match_acceptability() {
+        //Every pair yielded should be acceptable and every acceptable pair
+        //should be yielded, in row-major order
+        for (n, m) in [(1, 1), (1, 5), (2, 3), (3, 3), (3, 4), (5, 4)] {
+            let mut expected: Vec<([usize; 2], [usize; 2])> = Vec::new();
+            for start in GridGraph::cells_with_dimensions(n, m) {
+                for end in GridGraph::cells_with_dimensions(n, m) {
+                    if (start != end || n * m == 1) && GridProblem::new(n, m, start, end).is_acceptable() {
+                        expected.push((start, end));
+                    }
+                }
+            }
+            assert_eq!(AcceptablePairs::new(n, m, PairOptions::default()).collect::<Vec<([usize; 2], [usize; 2])>>(), expected);
+        }
+    }
+
+    #[test]
+    fn distinct_unordered_pairs() {
+        //Each unordered pair should be yielded once, start first, and so
+        //there should be exactly half as many as ordered pairs
+        let options: PairOptions = PairOptions {
+            distinct_unordered: true,
+            ..PairOptions::default()
+        };
+        for (n, m) in [(2, 3), (3, 3), (4, 4), (5, 4)] {
+            let num_ordered: usize = AcceptablePairs::new(n, m, PairOptions::default()).count();
+            let unordered: Vec<([usize; 2], [usize; 2])> = AcceptablePairs::new(n, m, options).collect();
+            assert_eq!(unordered.len() * 2, num_ordered);
+            for (start, end) in unordered {
+                assert_eq!(GridGraph::cell_index_with_width(n, start) < GridGraph::cell_index_with_width(n, end), true);
+            }
+        }
+    }
+
+    #[test]
+    fn filtered_pairs() {
+        //Starts should be on the boundary and ends in the top right corner
+        let options: PairOptions = PairOptions {
+            starts_on_boundary: true,
+            ends_in_region: Rect::new([3, 3], [4, 4]),
+            ..PairOptions::default()
+        };
+        let pairs: Vec<([usize; 2], [usize; 2])> = AcceptablePairs::new(5, 5, options).collect();
+        assert_eq!(pairs.len() > 0, true);
+        for (start, end) in pairs.iter() {
+            assert_eq!(start[0] == 0 || start[1] == 0 || start[0] == 4 || start[1] == 4, true);
+            assert_eq!(end[0] >= 3 && end[1] >= 3, true);
+        }
+
+        //Filtering should agree with filtering the unfiltered pairs
+        let expected: Vec<([usize; 2], [usize; 2])> = AcceptablePairs::new(5, 5, PairOptions::default())
+            .filter(|(start, end)| {
+                (start[0] == 0 || start[1] == 0 || start[0] == 4 || start[1] == 4) && end[0] >= 3 && end[1] >= 3
+            })
+            .collect();
+        assert_eq!(pairs, expected);
+    }
+}
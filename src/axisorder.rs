@@ -0,0 +1,11 @@
+/// # AxisOrder enum
+///
+/// An `AxisOrder` describes which axis is given first in a pair of
+/// coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisOrder {
+    /// The column then the row, as (x, y)
+    ColumnRow,
+    /// The row then the column, as (row, col)
+    RowColumn
+}
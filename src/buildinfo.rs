@@ -0,0 +1,72 @@
+use crate::primetable::PrimeTable;
+use json::JsonValue;
+
+/// # BuildInfo struct
+///
+/// A `BuildInfo` describes the build of the library in use, so that a
+/// bug report can be matched to the code and prime table which produced
+/// it
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuildInfo {
+    /// The version of the crate
+    pub version: &'static str,
+    /// The cargo features the crate was built with
+    pub features: Vec<&'static str>,
+    /// The checksum of the stored prime solutions
+    pub prime_table_checksum: u64,
+    /// The version of the JSON formats written by the crate
+    pub format_version: u32
+}
+
+impl BuildInfo {
+    /// Convert the build info to a JSON object with the keys `version`,
+    /// `features`, `prime_table_checksum` and `format_version`.  The
+    /// checksum is written as a hex string since JSON numbers cannot hold
+    /// every u64.
+    pub fn to_json_value(&self) -> JsonValue {
+        json::object!{
+            version: self.version,
+            features: self.features.clone(),
+            prime_table_checksum: format!("{:#018x}", self.prime_table_checksum),
+            format_version: self.format_version
+        }
+    }
+}
+
+/// Get the build info of the library in use
+pub fn build_info() -> BuildInfo {
+    let mut features: Vec<&'static str> = Vec::new();
+    if cfg!(feature = "alloc") {
+        features.push("alloc");
+    }
+    if cfg!(feature = "std") {
+        features.push("std");
+    }
+    if cfg!(feature = "stats-alloc") {
+        features.push("stats-alloc");
+    }
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        features: features,
+        prime_table_checksum: PrimeTable::checksum(),
+        format_version: crate::FORMAT_VERSION
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn build_info_json() {
+        let info: BuildInfo = build_info();
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.features.contains(&"std"), true);
+
+        //The checksum is written in full as a hex string
+        let parsed: JsonValue = json::parse(&json::stringify(info.to_json_value())).unwrap();
+        assert_eq!(parsed["prime_table_checksum"].as_str(), Some(format!("{:#018x}", PrimeTable::checksum()).as_str()));
+        assert_eq!(parsed["format_version"].as_u32(), Some(crate::FORMAT_VERSION));
+        assert_eq!(parsed["features"].len(), info.features.len());
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+use crate::parity::Parity;
+
+/// # ColorRelation enum
+///
+/// A `ColorRelation` describes what the colors of the endpoints of a
+/// Hamiltonian path over a grid graph must satisfy.  A path alternates
+/// colors, so on a grid graph with an even number of vertices it ends
+/// on the opposite color to that it starts on, while on a grid graph
+/// with an odd number of vertices it starts and ends on the majority
+/// color, which is even.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorRelation {
+    /// The endpoints must have different colors
+    Different,
+    /// Both endpoints must have the even color
+    BothEven
+}
+
+impl ColorRelation {
+    /// Get the relation required of the endpoints of a grid graph whose
+    /// number of vertices has the given parity
+    pub fn for_grid_parity(grid_parity: Parity) -> ColorRelation {
+        match grid_parity {
+            Parity::Even => ColorRelation::Different,
+            Parity::Odd => ColorRelation::BothEven
+        }
+    }
+
+    /// Check if endpoints of the given colors satisfy the relation
+    pub fn is_satisfied_by(&self, start_color: Parity, end_color: Parity) -> bool {
+        match self {
+            ColorRelation::Different => start_color != end_color,
+            ColorRelation::BothEven => start_color == Parity::Even && end_color == Parity::Even
+        }
+    }
+}
+
+impl fmt::Display for ColorRelation {
+    /// Format a ColorRelation as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorRelation::Different => write!(
+                f, "the endpoints must have different colors"
+            ),
+            ColorRelation::BothEven => write!(
+                f, "both endpoints must have the even color"
+            )
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::axisorder::AxisOrder;
+use crate::corner::Corner;
+
+/// # Convention struct
+///
+/// A `Convention` describes how another tool gives the coordinates of
+/// a grid's vertices: the corner at which both coordinates are 0, from
+/// which they count towards the opposite corner, and the order of the
+/// axes.  The direction of y follows from the corner, counting down
+/// from a top corner and up from a bottom corner.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Convention {
+    /// The corner at which both coordinates are 0
+    pub origin: Corner,
+    /// The axis given first in a pair of coordinates
+    pub axis_order: AxisOrder
+}
+
+impl Convention {
+    /// The convention used throughout this crate, (x, y) from the bottom
+    /// left corner with y counting up
+    pub const CARTESIAN: Convention = Convention {
+        origin: Corner::BottomLeft,
+        axis_order: AxisOrder::ColumnRow
+    };
+
+    /// The convention of matrices and images, (row, col) from the top
+    /// left corner with rows counting down
+    pub const MATRIX: Convention = Convention {
+        origin: Corner::TopLeft,
+        axis_order: AxisOrder::RowColumn
+    };
+
+    /// Get the dimensions, in this convention's axis order, of an n by m
+    /// grid given in the crate's convention
+    pub fn dimensions(&self, n: usize, m: usize) -> [usize; 2] {
+        match self.axis_order {
+            AxisOrder::ColumnRow => [n, m],
+            AxisOrder::RowColumn => [m, n]
+        }
+    }
+
+    /// Convert coordinates of a vertex of an n by m grid from the crate's
+    /// convention to this convention
+    pub fn from_cartesian(&self, n: usize, m: usize, v_coords: [usize; 2]) -> [usize; 2] {
+        let x: usize = if self.origin.is_right() { n - 1 - v_coords[0] } else { v_coords[0] };
+        let y: usize = if self.origin.is_top() { m - 1 - v_coords[1] } else { v_coords[1] };
+        match self.axis_order {
+            AxisOrder::ColumnRow => [x, y],
+            AxisOrder::RowColumn => [y, x]
+        }
+    }
+
+    /// Convert coordinates of a vertex of an n by m grid from this
+    /// convention to the crate's convention, where n and m are the
+    /// dimensions in the crate's convention
+    pub fn to_cartesian(&self, n: usize, m: usize, v_coords: [usize; 2]) -> [usize; 2] {
+        let [x, y]: [usize; 2] = match self.axis_order {
+            AxisOrder::ColumnRow => v_coords,
+            AxisOrder::RowColumn => [v_coords[1], v_coords[0]]
+        };
+        [
+            if self.origin.is_right() { n - 1 - x } else { x },
+            if self.origin.is_top() { m - 1 - y } else { y }
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matrix_coords_by_hand() {
+        //On a 3 by 2 grid the bottom left vertex is in the last row and
+        //first column of a matrix, and the top right in the first row and
+        //last column
+        assert_eq!(Convention::MATRIX.from_cartesian(3, 2, [0, 0]), [1, 0]);
+        assert_eq!(Convention::MATRIX.from_cartesian(3, 2, [2, 1]), [0, 2]);
+        assert_eq!(Convention::MATRIX.dimensions(3, 2), [2, 3]);
+
+        //The crate's own convention leaves coordinates unchanged
+        assert_eq!(Convention::CARTESIAN.from_cartesian(3, 2, [2, 0]), [2, 0]);
+    }
+
+    #[test]
+    fn conventions_round_trip() {
+        //Every convention should invert on every vertex of a 4 by 3 grid
+        let corners: [Corner; 4] = [Corner::BottomLeft, Corner::BottomRight, Corner::TopLeft, Corner::TopRight];
+        for origin in corners {
+            for axis_order in [AxisOrder::ColumnRow, AxisOrder::RowColumn] {
+                let convention: Convention = Convention { origin: origin, axis_order: axis_order };
+                for x in 0..4 {
+                    for y in 0..3 {
+                        let v_coords: [usize; 2] = convention.from_cartesian(4, 3, [x, y]);
+                        let dimensions: [usize; 2] = convention.dimensions(4, 3);
+                        assert_eq!(v_coords[0] < dimensions[0] && v_coords[1] < dimensions[1], true);
+                        assert_eq!(convention.to_cartesian(4, 3, v_coords), [x, y]);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+/// # Corner enum
+///
+/// A `Corner` names one of the four corners of a grid, as seen with
+/// the top row drawn first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Corner {
+    BottomLeft,
+    BottomRight,
+    TopLeft,
+    TopRight
+}
+
+impl Corner {
+    /// Check if the corner is on the right of the grid
+    pub fn is_right(&self) -> bool {
+        matches!(self, Corner::BottomRight | Corner::TopRight)
+    }
+
+    /// Check if the corner is on the top of the grid
+    pub fn is_top(&self) -> bool {
+        matches!(self, Corner::TopLeft | Corner::TopRight)
+    }
+}
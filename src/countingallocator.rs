@@ -0,0 +1,66 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// # CountingAllocator struct
+///
+/// A `CountingAllocator` wraps the system allocator and tracks the number
+/// of bytes currently allocated along with the peak since the last reset.
+/// It is installed as the global allocator when the crate is built with
+/// the `stats-alloc` feature.  The counters are process-wide, so solves
+/// running concurrently on other threads are included in the peak.
+pub struct CountingAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize
+}
+
+impl CountingAllocator {
+    /// Initialize a counting allocator with zeroed counters
+    pub const fn new() -> CountingAllocator {
+        CountingAllocator {
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0)
+        }
+    }
+
+    /// Get the number of bytes currently allocated
+    pub fn get_current(&self) -> usize {
+        self.current.load(Ordering::Relaxed)
+    }
+
+    /// Get the peak number of bytes allocated since the last reset
+    pub fn get_peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    /// Reset the peak to the number of bytes currently allocated
+    pub fn reset_peak(&self) {
+        self.peak.store(self.get_current(), Ordering::Relaxed);
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> CountingAllocator {
+        CountingAllocator::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr: *mut u8 = System.alloc(layout);
+        if !ptr.is_null() {
+            let current: usize = self.current.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            self.peak.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.current.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+/// The global allocator used to measure solver memory usage
+#[cfg(feature = "stats-alloc")]
+#[global_allocator]
+pub static ALLOCATOR: CountingAllocator = CountingAllocator::new();
@@ -0,0 +1,65 @@
+/// # GlyphSet struct
+///
+/// A `GlyphSet` holds the strings used to draw each part of a
+/// `GridPath` when it is rendered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GlyphSet {
+    /// Drawn for a vertex of the path
+    pub node: &'static str,
+    /// Repeated to fill the gap between two horizontally joined vertices
+    pub horizontal_edge: &'static str,
+    /// Drawn between two vertically joined vertices
+    pub vertical_edge: &'static str,
+    /// Drawn for the first vertex of the path
+    pub start: &'static str,
+    /// Drawn for the last vertex of the path
+    pub end: &'static str,
+    /// Drawn for a highlighted vertex of the path
+    pub highlight: &'static str,
+    /// Repeated to fill the gaps where there is no edge
+    pub blank: &'static str
+}
+
+impl GlyphSet {
+    /// The glyphs used by the `GridPath` display
+    pub const ASCII: GlyphSet = GlyphSet {
+        node: "o",
+        horizontal_edge: "-",
+        vertical_edge: "|",
+        start: "o",
+        end: "o",
+        highlight: "*",
+        blank: " "
+    };
+
+    /// Box drawing glyphs
+    pub const UNICODE: GlyphSet = GlyphSet {
+        node: "○",
+        horizontal_edge: "─",
+        vertical_edge: "│",
+        start: "●",
+        end: "◉",
+        highlight: "◆",
+        blank: " "
+    };
+
+    /// Get the number of columns taken by a glyph
+    ///
+    /// Each char is taken to be one column wide, which holds for the
+    /// box drawing and geometric shape glyphs but not for wide glyphs
+    /// such as CJK characters or emoji
+    pub fn display_width(glyph: &str) -> usize {
+        glyph.chars().count()
+    }
+
+    /// Get the number of columns each vertex of the grid takes, the
+    /// widest of the glyphs which can be drawn in a vertex column
+    pub fn get_cell_width(&self) -> usize {
+        [self.node, self.start, self.end, self.highlight, self.vertical_edge]
+            .iter()
+            .map(|g| GlyphSet::display_width(g))
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    }
+}
@@ -1,5 +1,11 @@
 //Import library modules
-use clap::{Parser};
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use clap::{Parser, Subcommand, ValueEnum};
+use grid_solver::gridextension::GridExtension;
+use grid_solver::gridproblem::GridProblem;
+use crate::parse::{self, ParseError};
 
 /** GridCli struct schema
  *
@@ -12,6 +18,9 @@ use clap::{Parser};
 #[command(version="0.1.0")]
 #[command(about="Draw a Hamiltonian path between two vertices in a grid graph G(n, m)")]
 pub struct GridCli {
+    #[command(subcommand)]
+    pub command: Option<GridCommand>,
+
     /// Width of the grid
     #[arg(long="width")]
     pub width: Option<usize>,
@@ -34,5 +43,313 @@ pub struct GridCli {
 
     /// End vertex y coordinate
     #[arg(long="end-y")]
-    pub end_y: Option<usize>
-}
\ No newline at end of file
+    pub end_y: Option<usize>,
+
+    /// Start and end next to each other on an edge, given as the edge
+    /// (bottom, top, left or right) and a position along it, e.g. bottom:3
+    #[arg(long="entry", value_parser=parse_entry)]
+    pub entry: Option<(GridExtension, usize)>,
+
+    /// Solve by exhaustive search, expanding at most this many nodes
+    #[arg(long="bruteforce-limit")]
+    pub bruteforce_limit: Option<usize>,
+
+    /// Glyphs to draw the path with, falling back to ascii when the
+    /// terminal does not support unicode
+    #[arg(long="glyphs", value_enum, default_value_t=GlyphMode::Ascii)]
+    pub glyphs: GlyphMode,
+
+    /// Use the requested glyphs even if the terminal does not support them
+    #[arg(long="force")]
+    pub force: bool,
+
+    /// Draw the path in ascii colored with ANSI escape codes, the same as
+    /// --glyphs color, falling back to plain ascii when the terminal does
+    /// not support color
+    #[arg(long="color", conflicts_with="glyphs")]
+    pub color: bool,
+
+    /// Write a support bundle describing the problem and why it could not
+    /// be solved to this file, to attach to a bug report
+    #[arg(long="bug-report")]
+    pub bug_report: Option<PathBuf>,
+
+    /// Validate the problem and print whether it is acceptable along with
+    /// an estimate of the work to solve it, without solving it
+    #[arg(long="dry-run")]
+    pub dry_run: bool
+}
+
+/** GlyphMode enum schema
+ *
+ * The GlyphMode enum lists the glyph sets the path can be
+ * drawn with, from the most to the least widely supported
+ */
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum GlyphMode {
+    Ascii,
+    Unicode,
+    /// Ascii colored with ANSI escape codes
+    Color
+}
+
+impl GlyphMode {
+    /// Get the name of the glyph mode as given on the command line
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            GlyphMode::Ascii => "ascii",
+            GlyphMode::Unicode => "unicode",
+            GlyphMode::Color => "color"
+        }
+    }
+}
+
+/** TerminalEnv struct schema
+ *
+ * The TerminalEnv struct holds the parts of the environment
+ * used to detect what the terminal can display, so that the
+ * detection can be run against a fake environment in tests
+ */
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TerminalEnv {
+    pub term: Option<String>,
+    pub lang: Option<String>,
+    pub lc_all: Option<String>,
+    pub is_tty: bool
+}
+
+impl TerminalEnv {
+    /// Read the terminal environment of the running process
+    pub fn from_process() -> TerminalEnv {
+        TerminalEnv {
+            term: env::var("TERM").ok(),
+            lang: env::var("LANG").ok(),
+            lc_all: env::var("LC_ALL").ok(),
+            is_tty: io::stdout().is_terminal()
+        }
+    }
+
+    /// Check if the locale uses UTF-8, with LC_ALL taking precedence
+    /// over LANG when it is set and not empty
+    pub fn is_utf8_locale(&self) -> bool {
+        let locale: Option<&String> = match self.lc_all {
+            Some(ref x) if !x.is_empty() => Some(x),
+            _ => self.lang.as_ref()
+        };
+        match locale {
+            Some(x) => {
+                let x: String = x.to_lowercase();
+                x.contains("utf-8") || x.contains("utf8")
+            },
+            None => false
+        }
+    }
+
+    /// Check if the terminal can display the given glyphs
+    pub fn supports(&self, mode: GlyphMode) -> bool {
+        match mode {
+            GlyphMode::Ascii => true,
+            GlyphMode::Unicode => {
+                self.is_tty && self.term.as_deref() != Some("dumb") && self.is_utf8_locale()
+            },
+            GlyphMode::Color => {
+                self.is_tty && self.term.as_deref() != Some("dumb")
+            }
+        }
+    }
+}
+
+/// Resolve the glyphs to draw with given those requested, falling back
+/// to the next most widely supported glyphs until the terminal supports
+/// them unless forced.  A note is returned when a fallback was made.
+pub fn resolve_glyph_mode(requested: GlyphMode, force: bool, terminal: &TerminalEnv) -> (GlyphMode, Option<String>) {
+    if force || terminal.supports(requested) {
+        return (requested, None);
+    }
+    let resolved: GlyphMode = match requested {
+        GlyphMode::Unicode => GlyphMode::Ascii,
+        GlyphMode::Color => GlyphMode::Ascii,
+        GlyphMode::Ascii => GlyphMode::Ascii
+    };
+    let note: String = format!(
+        "The terminal does not appear to support {} glyphs, falling back to {} (use --force to override)",
+        requested.get_name(), resolved.get_name()
+    );
+    (resolved, Some(note))
+}
+
+/// Describe the plan for solving a grid problem without solving it,
+/// giving whether it is acceptable along with the verdict and, for an
+/// acceptable problem, the estimated work
+pub fn describe_plan(problem: &GridProblem) -> (bool, String) {
+    let start: [usize; 2] = problem.get_start_coords();
+    let end: [usize; 2] = problem.get_end_coords();
+    let mut plan: String = format!(
+        "Problem: {}x{} from ({}, {}) to ({}, {})\n",
+        problem.get_width(), problem.get_height(), start[0], start[1], end[0], end[1]
+    );
+    if problem.is_acceptable() {
+        let estimate = problem.estimate();
+        plan += "Verdict: acceptable\n";
+        plan += &format!(
+            "Estimate: {} cells, {} strip{}, split likely: {}",
+            estimate.num_cells, estimate.get_num_strips(), if estimate.get_num_strips() == 1 { "" } else { "s" },
+            if estimate.is_split_likely { "yes" } else { "no" }
+        );
+        return (true, plan);
+    }
+    if !problem.required_relation().is_satisfied_by(problem.start_color(), problem.end_color()) {
+        plan += &format!("Verdict: not acceptable, {}", problem.required_relation());
+    } else {
+        plan += "Verdict: not acceptable, the endpoints form a forbidden case";
+    }
+    (false, plan)
+}
+
+/** GridCommand enum schema
+ *
+ * The GridCommand enum lists the subcommands which can be
+ * run in place of solving a grid problem
+ */
+#[derive(Subcommand)]
+pub enum GridCommand {
+    /// Report how many acceptable endpoint pairs have stored prime solutions
+    Primes,
+
+    /// Print the JSON Schema describing the problem and solution formats
+    Schema
+}
+
+/// The syntax expected of an entry
+const ENTRY_SYNTAX: &str = "an edge and a position, e.g. bottom:3";
+
+/// Parse an edge and a position along it from a string such as bottom:3
+fn parse_entry(entry: &str) -> Result<(GridExtension, usize), ParseError> {
+    let ((edge, edge_start), (position, position_start)) = parse::split_pair(entry, ':', ENTRY_SYNTAX)?;
+    let edge: GridExtension = match edge {
+        "bottom" => GridExtension::Down,
+        "top"    => GridExtension::Up,
+        "left"   => GridExtension::Left,
+        "right"  => GridExtension::Right,
+        _ => return Err(ParseError::new(
+            entry, edge_start..(edge_start + edge.len()),
+            format!("unknown edge '{}'", edge), "bottom, top, left or right"
+        ))
+    };
+    let position: usize = parse::parse_number(entry, position, position_start, ENTRY_SYNTAX)?;
+    Ok((edge, position))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Initialize a fake terminal environment
+    fn fake_env(term: Option<&str>, lang: Option<&str>, lc_all: Option<&str>, is_tty: bool) -> TerminalEnv {
+        TerminalEnv {
+            term: term.map(String::from),
+            lang: lang.map(String::from),
+            lc_all: lc_all.map(String::from),
+            is_tty: is_tty
+        }
+    }
+
+    #[test]
+    fn parse_entry_inputs() {
+        //Valid
+        assert_eq!(parse_entry("bottom:3"), Ok((GridExtension::Down, 3)));
+        assert_eq!(parse_entry("right:0"), Ok((GridExtension::Right, 0)));
+
+        //Empty, missing the separator or the position
+        assert_eq!(parse_entry("").unwrap_err().span, 0..0);
+        assert_eq!(parse_entry("bottom").unwrap_err().span, 6..6);
+        assert_eq!(parse_entry("bottom:").unwrap_err().span, 7..7);
+
+        //Unknown edges are spanned in full
+        let error: ParseError = parse_entry("side:3").unwrap_err();
+        assert_eq!(error.span, 0..4);
+        assert_eq!(error.expected, "bottom, top, left or right");
+
+        //Overlong and non-ASCII digit positions
+        assert_eq!(parse_entry("top:123456789012345678901").unwrap_err().span, 4..25);
+        assert_eq!(parse_entry("top:٣").unwrap_err().span, 4..6);
+        assert_eq!(parse_entry("top:-1").unwrap_err().span, 4..5);
+    }
+
+    #[test]
+    fn utf8_locale_detection() {
+        //Either spelling of UTF-8 is accepted in any case
+        assert_eq!(fake_env(None, Some("en_US.UTF-8"), None, true).is_utf8_locale(), true);
+        assert_eq!(fake_env(None, Some("de_DE.utf8"), None, true).is_utf8_locale(), true);
+        assert_eq!(fake_env(None, Some("C"), None, true).is_utf8_locale(), false);
+        assert_eq!(fake_env(None, None, None, true).is_utf8_locale(), false);
+
+        //LC_ALL overrides LANG unless it is empty
+        assert_eq!(fake_env(None, Some("en_US.UTF-8"), Some("C"), true).is_utf8_locale(), false);
+        assert_eq!(fake_env(None, Some("C"), Some("en_US.UTF-8"), true).is_utf8_locale(), true);
+        assert_eq!(fake_env(None, Some("en_US.UTF-8"), Some(""), true).is_utf8_locale(), true);
+    }
+
+    #[test]
+    fn glyph_mode_fallback_matrix() {
+        let capable: TerminalEnv = fake_env(Some("xterm-256color"), Some("en_US.UTF-8"), None, true);
+        let incapable: Vec<TerminalEnv> = vec![
+            fake_env(Some("xterm-256color"), Some("en_US.UTF-8"), None, false),
+            fake_env(Some("dumb"), Some("en_US.UTF-8"), None, true),
+            fake_env(Some("xterm-256color"), Some("C"), None, true),
+            fake_env(Some("xterm-256color"), Some("en_US.UTF-8"), Some("POSIX"), true)
+        ];
+
+        //A capable terminal gets what it asks for without a note
+        assert_eq!(resolve_glyph_mode(GlyphMode::Unicode, false, &capable), (GlyphMode::Unicode, None));
+        assert_eq!(resolve_glyph_mode(GlyphMode::Ascii, false, &capable), (GlyphMode::Ascii, None));
+
+        for terminal in incapable.iter() {
+            //Unicode falls back to ascii with a note
+            let (mode, note) = resolve_glyph_mode(GlyphMode::Unicode, false, terminal);
+            assert_eq!(mode, GlyphMode::Ascii);
+            assert_eq!(note.unwrap().contains("--force"), true);
+
+            //Forcing keeps unicode and ascii never falls back
+            assert_eq!(resolve_glyph_mode(GlyphMode::Unicode, true, terminal), (GlyphMode::Unicode, None));
+            assert_eq!(resolve_glyph_mode(GlyphMode::Ascii, false, terminal), (GlyphMode::Ascii, None));
+
+            //Forcing keeps color whatever the terminal
+            assert_eq!(resolve_glyph_mode(GlyphMode::Color, true, terminal), (GlyphMode::Color, None));
+        }
+
+        //Color needs only a terminal which is not dumb, so it is kept on
+        //the terminals without a UTF-8 locale and otherwise falls back to
+        //ascii with a note
+        assert_eq!(resolve_glyph_mode(GlyphMode::Color, false, &capable), (GlyphMode::Color, None));
+        for (terminal, is_kept) in incapable.iter().zip([false, false, true, true]) {
+            let (mode, note) = resolve_glyph_mode(GlyphMode::Color, false, terminal);
+            if is_kept {
+                assert_eq!((mode, note), (GlyphMode::Color, None));
+            } else {
+                assert_eq!(mode, GlyphMode::Ascii);
+                assert_eq!(note.unwrap(), "The terminal does not appear to support color glyphs, falling back to ascii (use --force to override)");
+            }
+        }
+    }
+
+    #[test]
+    fn describe_plan_verdicts() {
+        //An acceptable problem is given with its estimate
+        let (is_acceptable, plan) = describe_plan(&GridProblem::new(4, 4, [0, 0], [3, 0]));
+        assert_eq!(is_acceptable, true);
+        assert_eq!(plan.lines().collect::<Vec<&str>>(), vec![
+            "Problem: 4x4 from (0, 0) to (3, 0)",
+            "Verdict: acceptable",
+            "Estimate: 16 cells, 1 strip, split likely: no"
+        ]);
+
+        //Unacceptable problems are given with the reason
+        let (is_acceptable, plan) = describe_plan(&GridProblem::new(4, 4, [0, 0], [2, 2]));
+        assert_eq!(is_acceptable, false);
+        assert_eq!(plan.lines().last(), Some("Verdict: not acceptable, the endpoints must have different colors"));
+        let (is_acceptable, plan) = describe_plan(&GridProblem::new(1, 4, [0, 1], [0, 2]));
+        assert_eq!(is_acceptable, false);
+        assert_eq!(plan.lines().last(), Some("Verdict: not acceptable, the endpoints form a forbidden case"));
+    }
+}
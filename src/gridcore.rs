@@ -0,0 +1,326 @@
+//The pure math behind the solver, which needs neither the standard
+//library nor petgraph so that it can be built without the std feature.
+//The serpentine generator and direction codec need the alloc feature.
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+/// Get the index of the vertex at the given coordinates in a grid graph
+/// of width n
+pub fn cell_index(n: usize, v_coords: [usize; 2]) -> usize {
+    (v_coords[1] * n) + v_coords[0]
+}
+
+/// Get the coordinates of the vertex at the given index in a grid graph
+/// of width n
+pub fn coords_of(n: usize, index: usize) -> [usize; 2] {
+    [index % n, index / n]
+}
+
+/// Iterate over the coordinates of the vertices of an n by m grid graph
+/// row by row
+pub fn cells(n: usize, m: usize) -> impl Iterator<Item = [usize; 2]> {
+    (0..m).flat_map(move |i| (0..n).map(move |j| [j, i]))
+}
+
+/// Check if the coordinates are within an n by m grid graph
+pub fn in_bounds(n: usize, m: usize, v_coords: [usize; 2]) -> bool {
+    v_coords[0] < n && v_coords[1] < m
+}
+
+/// Determine whether two vertices of an n by m grid graph are color
+/// compatible.  The coordinates are assumed to be in bounds.
+pub fn are_color_compatible(n: usize, m: usize, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+    //Determine if the graph is even or odd
+    let graph_is_odd: bool = ((n*m) & 1) == 1;
+
+    //If the graph is odd then the majority color has even parity
+    if graph_is_odd {
+        //We therefore check if v and w both have even parity
+        return ((w_coords[0]+w_coords[1]) & 1 == 0) && ((v_coords[0]+v_coords[1]) & 1 == 0);
+    }
+
+    //If the graph is even then the vertices must share parity
+    return (w_coords[0]+w_coords[1]) & 1 != (v_coords[0]+v_coords[1]) & 1;
+}
+
+/// Determine whether the Hamiltonian path problem over an n by m
+/// grid graph is forbidden when either m or n is 1
+fn is_forbidden_case_1(n: usize, m: usize, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+    //Return true if neither v or w are the origin vertex
+    if v_coords != [0, 0] && w_coords != [0, 0] {
+        return true;
+    }
+
+    //Determine which dimension is 1 and capture the opposite
+    let is_n: bool = n == 1;
+    let bound: usize = match is_n {
+        true => m,
+        false => n
+    };
+
+    //Return true if neither v or w are the opposite corner vertex
+    if is_n && (v_coords != [0, bound - 1] && w_coords != [0, bound - 1]) {
+        return true;
+    } else if !is_n && (v_coords != [bound - 1, 0] && w_coords != [bound - 1, 0]) {
+        return true;
+    }
+
+    //Return true if both v and w are corner vertices
+    return false;
+}
+
+/// Determine whether the Hamiltonian path problem over an n by m
+/// grid graph is forbidden when either m or n is 2
+fn is_forbidden_case_2(n: usize, m: usize, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+    //Break if v or w is a corner vertex, as the edge between them
+    //cannot be a nonboundary edge in this case
+    let is_corner = |coords: [usize; 2]| (coords[0] == 0 || coords[0] == n - 1) && (coords[1] == 0 || coords[1] == m - 1);
+    if is_corner(v_coords) || is_corner(w_coords) {
+        return false;
+    }
+
+    //Determine which dimension is 2
+    let is_n: bool = n == 2;
+
+    //If n is 2 then check if the vertices share a y coord
+    if is_n && (v_coords[1] == w_coords[1]) {
+        return true;
+    }
+
+    //If m is 2 then check if the vertices share an x coord
+    if !is_n && (v_coords[0] == w_coords[0]) {
+        return true;
+    }
+
+    //Return false if v and w lack a nonboundary edge between them
+    return false;
+}
+
+/// Determine whether the Hamiltonian path problem over an n by m
+/// grid graph is forbidden when either m or n is 3
+#[allow(clippy::eq_op, clippy::erasing_op)]
+fn is_forbidden_case_3(n: usize, m: usize, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+    //Determine which dimension is 3 and capture the opposite
+    let is_n: bool = n == 3;
+    let opp_dim: usize = match is_n {
+        true => m,
+        false => n
+    };
+
+    //Check if the opposite dimension is odd, if so then break
+    if opp_dim & 1 == 1 {
+        return false;
+    }
+
+    //Check if v has the same color as w, if they share the same
+    //color then break
+    if (w_coords[0]+w_coords[1]) & 1 == (v_coords[0]+v_coords[1]) & 1 {
+        return false;
+    }
+
+    //Check if v's position in relation to that of w satisfies the
+    //required conditions
+    let comp_coords: [usize; 2] = if is_n { [v_coords[1], w_coords[1]] } else { [v_coords[0], w_coords[0]] };
+    let opp_coord: usize = if is_n { v_coords[0] } else { v_coords[1] };
+    let is_greater: bool = comp_coords[0] > comp_coords[1];
+    let distance: usize = if is_greater { comp_coords[0] - comp_coords[1] } else { comp_coords[1] - comp_coords[0] };
+    let is_dst_sat: bool = if opp_coord == 1 { distance > 0 } else { distance > 1 };
+
+    //Break if the distance condition is not satisfied
+    if !is_dst_sat {
+        return false;
+    }
+
+    //If the distance condition is satisfied then check if the
+    //vertex matches the parity of the far corner vertices if
+    //v is greater than w, or the near corner vertices otherwise
+    if is_greater && ((v_coords[0]+v_coords[1]) & 1 == (1 & 1)) {
+        return false; //v shares color with far corner vertices
+    } else if !is_greater && ((v_coords[0]+v_coords[1]) & 1 == (0 & 1)) {
+        return false; //v shares color with near corner vertices
+    }
+
+    //If we satisfy all of the conditions then the problem is forbidden
+    true
+}
+
+/// Determine whether the Hamiltonian path problem over an n by m grid
+/// graph is forbidden.  The coordinates are assumed to be in bounds.
+pub fn is_forbidden(n: usize, m: usize, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+    //Check if either m or n is 1, if so then check the forbidden
+    //conditions for this case
+    if n == 1 || m == 1 {
+        return is_forbidden_case_1(n, m, v_coords, w_coords);
+    }
+
+    //Check if either m or n is 2, if so then check the forbidden
+    //conditions for this case
+    if n == 2 || m == 2 {
+        return is_forbidden_case_2(n, m, v_coords, w_coords);
+    }
+
+    //Check if either m or n is 3, if so then check the forbidden
+    //conditions for this case
+    if n == 3 || m == 3 {
+        return is_forbidden_case_3(n, m, v_coords, w_coords);
+    }
+
+    //If none of the forbidden cases are satisfied then return false
+    false
+}
+
+/// Determine whether the Hamiltonian path problem between two in bounds
+/// vertices of an n by m grid graph has a solution, that is whether they
+/// are color compatible and the problem is not forbidden.  If the
+/// vertices coincide then it has a solution only on a 1 by 1 grid.
+pub fn is_acceptable(n: usize, m: usize, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+    if v_coords == w_coords {
+        return n * m == 1;
+    }
+    are_color_compatible(n, m, v_coords, w_coords) && !is_forbidden(n, m, v_coords, w_coords)
+}
+
+/// Get the vertex order of the serpentine over an n by m grid graph,
+/// which runs along each row in turn from the bottom left vertex,
+/// alternating direction so that it ends in a corner of the top row
+#[cfg(feature = "alloc")]
+pub fn serpentine(n: usize, m: usize) -> Vec<[usize; 2]> {
+    let mut vertex_order: Vec<[usize; 2]> = Vec::with_capacity(n * m);
+    for y in 0..m {
+        for i in 0..n {
+            vertex_order.push([if y % 2 == 0 { i } else { n - 1 - i }, y]);
+        }
+    }
+    vertex_order
+}
+
+/// # RleError enum
+///
+/// An `RleError` describes why run-length encoded directions could not
+/// be decoded, holding the byte range of the offending run.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RleError {
+    InvalidRun(Range<usize>),
+    RunOutOfBounds(Range<usize>)
+}
+
+/// Encode the steps of a vertex order as runs of a count followed by the
+/// direction of the run, R, U, L or D, with up towards increasing y
+#[cfg(feature = "alloc")]
+pub fn encode_rle(vertex_order: &[[usize; 2]]) -> String {
+    let mut rle: String = String::new();
+    let mut run: Option<(char, usize)> = None;
+    for pair in vertex_order.windows(2) {
+        let direction: char = if pair[1][0] > pair[0][0] {
+            'R'
+        } else if pair[1][1] > pair[0][1] {
+            'U'
+        } else if pair[1][0] < pair[0][0] {
+            'L'
+        } else {
+            'D'
+        };
+        run = match run {
+            Some((c, count)) if c == direction => Some((c, count + 1)),
+            Some((c, count)) => {
+                rle.push_str(&count.to_string());
+                rle.push(c);
+                Some((direction, 1))
+            },
+            None => Some((direction, 1))
+        };
+    }
+    if let Some((c, count)) = run {
+        rle.push_str(&count.to_string());
+        rle.push(c);
+    }
+    rle
+}
+
+/// Decode the vertex order over an n by m grid graph from the given
+/// start vertex and the runs written by `encode_rle`.  The decoded
+/// vertex order stays within the grid but is not otherwise checked.
+#[cfg(feature = "alloc")]
+pub fn decode_rle(start: [usize; 2], s: &str, n: usize, m: usize) -> Result<Vec<[usize; 2]>, RleError> {
+    let mut vertex_order: Vec<[usize; 2]> = Vec::from([start]);
+    let mut current: [usize; 2] = start;
+    let mut run_start: usize = 0;
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_digit() {
+            continue;
+        }
+
+        //Parse the count preceding the direction
+        let run: Range<usize> = run_start..(i + c.len_utf8());
+        run_start = run.end;
+        let count: usize = match s[run.start..i].parse::<usize>() {
+            Ok(x) if x > 0 => x,
+            _ => return Err(RleError::InvalidRun(run))
+        };
+
+        //Step along the run, stopping if it leaves the grid
+        for _ in 0..count {
+            current = match c {
+                'R' if current[0] + 1 < n => [current[0] + 1, current[1]],
+                'U' if current[1] + 1 < m => [current[0], current[1] + 1],
+                'L' if current[0] > 0 => [current[0] - 1, current[1]],
+                'D' if current[1] > 0 => [current[0], current[1] - 1],
+                'R' | 'U' | 'L' | 'D' => return Err(RleError::RunOutOfBounds(run)),
+                _ => return Err(RleError::InvalidRun(run))
+            };
+            vertex_order.push(current);
+        }
+    }
+
+    //A count with no direction is left over at the end
+    if run_start < s.len() {
+        return Err(RleError::InvalidRun(run_start..s.len()));
+    }
+    Ok(vertex_order)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn is_acceptable_small_grids() {
+        //Corner to corner along a row is the only acceptable 1 wide problem
+        assert_eq!(is_acceptable(4, 1, [0, 0], [3, 0]), true);
+        assert_eq!(is_acceptable(4, 1, [0, 0], [2, 0]), false);
+
+        //Color incompatible and forbidden problems are not acceptable
+        assert_eq!(is_acceptable(4, 4, [0, 0], [1, 1]), false);
+        assert_eq!(is_acceptable(4, 2, [1, 0], [1, 1]), false);
+        assert_eq!(is_acceptable(4, 4, [0, 0], [1, 0]), true);
+
+        //Coinciding vertices are acceptable only on a single vertex grid,
+        //even where they are color compatible with themselves
+        assert_eq!(is_acceptable(1, 1, [0, 0], [0, 0]), true);
+        assert_eq!(are_color_compatible(3, 3, [0, 0], [0, 0]), true);
+        assert_eq!(is_acceptable(3, 3, [0, 0], [0, 0]), false);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn serpentine_visits_rows_in_turn() {
+        assert_eq!(serpentine(3, 2), Vec::from([[0, 0], [1, 0], [2, 0], [2, 1], [1, 1], [0, 1]]));
+        assert_eq!(encode_rle(&serpentine(4, 3)), "3R1U3L1U3R");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_rle_ranges() {
+        //Errors hold the byte range of the offending run
+        assert_eq!(decode_rle([0, 0], "1R1X", 2, 2), Err(RleError::InvalidRun(2..4)));
+        assert_eq!(decode_rle([0, 0], "1U2R", 2, 2), Err(RleError::RunOutOfBounds(2..4)));
+        assert_eq!(decode_rle([0, 0], "1R12", 2, 2), Err(RleError::InvalidRun(2..4)));
+        assert_eq!(decode_rle([0, 0], "1R1U", 2, 2), Ok(Vec::from([[0, 0], [1, 0], [1, 1]])));
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+use std::error::Error;
+
+/// # GridError enum
+///
+/// A `GridError` describes why a grid problem could not be
+/// constructed from the given description.
+#[derive(Debug, PartialEq)]
+pub enum GridError {
+    PositionOutOfBounds { position: usize, length: usize },
+    EdgeTooShort { length: usize },
+    NotAcceptable { start_coords: [usize; 2], end_coords: [usize; 2] },
+    ZeroDimension { width: usize, height: usize },
+    TooManyVertices { width: usize, height: usize, limit: usize },
+    CoordsOutOfBounds { coords: [usize; 2], width: usize, height: usize },
+    EqualEndpoints { coords: [usize; 2] }
+}
+
+impl fmt::Display for GridError {
+    /// Format a GridError as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::PositionOutOfBounds { position, length } => write!(
+                f, "Position {} is out of bounds of an edge of length {}",
+                position, length
+            ),
+            GridError::EdgeTooShort { length } => write!(
+                f, "Edge of length {} is too short to hold both endpoints",
+                length
+            ),
+            GridError::NotAcceptable { start_coords, end_coords } => write!(
+                f, "Grid problem from ({}, {}) to ({}, {}) is not acceptable",
+                start_coords[0], start_coords[1], end_coords[0], end_coords[1]
+            ),
+            GridError::ZeroDimension { width, height } => write!(
+                f, "Grid dimensions must be at least 1 x 1, got {} x {}",
+                width, height
+            ),
+            GridError::TooManyVertices { width, height, limit } => write!(
+                f, "Grid of {} x {} vertices exceeds the limit of {} vertices",
+                width, height, limit
+            ),
+            GridError::CoordsOutOfBounds { coords, width, height } => write!(
+                f, "Vertex ({}, {}) is out of bounds of a {} x {} grid, coordinates must be below ({}, {})",
+                coords[0], coords[1], width, height, width, height
+            ),
+            GridError::EqualEndpoints { coords } => write!(
+                f, "Start and end vertices must differ on a grid of more than one vertex, both are ({}, {})",
+                coords[0], coords[1]
+            )
+        }
+    }
+}
+
+impl Error for GridError {}
@@ -1,4 +1,4 @@
-#[derive(Clone,Copy)]
+#[derive(Clone,Copy,Debug,PartialEq)]
 pub enum GridExtension {
     Right,
     Up,
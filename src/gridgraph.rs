@@ -3,6 +3,9 @@ use std::fmt;
 use petgraph::Undirected;
 use petgraph::graph::Graph;
 use petgraph::visit::NodeIndexable;
+use crate::gridcore;
+use crate::textnormalize;
+use crate::validate;
 
 /// # GridGraph struct
 ///
@@ -21,35 +24,36 @@ impl GridGraph {
     /// ### Example
     ///
     /// ```rust
+    /// use grid_solver::gridgraph::GridGraph;
+    ///
     /// let my_grid_graph: GridGraph = GridGraph::new(4_usize, 3_usize);
     /// ```
     pub fn new(n: usize, m: usize) -> GridGraph {
         //Initialize the graph
         let mut graph = Graph::new_undirected();
 
-        //Add nodes to the graph
-        for i in 0..m {
-            for j in 0..n {
-                //Add the node
-                graph.add_node(format!("({},{})",i,j));
-
-                //Draw an edge in the left direction if node to the left
-                if j > 0 {
-                    graph.add_edge(
-                        NodeIndexable::from_index(&graph, (i*n) + j),
-                        NodeIndexable::from_index(&graph, (i*n) + j - 1),
-                        String::from("")
-                    );
-                }
+        //Add nodes to the graph in row-major order
+        for v_coords in GridGraph::cells_with_dimensions(n, m) {
+            //Add the node
+            graph.add_node(format!("({},{})", v_coords[1], v_coords[0]));
+            let node_index = NodeIndexable::from_index(&graph, GridGraph::cell_index_with_width(n, v_coords));
+
+            //Draw an edge in the left direction if node to the left
+            if v_coords[0] > 0 {
+                graph.add_edge(
+                    node_index,
+                    NodeIndexable::from_index(&graph, GridGraph::cell_index_with_width(n, [v_coords[0] - 1, v_coords[1]])),
+                    String::from("")
+                );
+            }
 
-                //Draw an edge in the up direction if node above
-                if i > 0 {
-                    graph.add_edge(
-                        NodeIndexable::from_index(&graph, (i*n) + j),
-                        NodeIndexable::from_index(&graph, ((i-1)*n) + j),
-                        String::from("")
-                    );
-                }
+            //Draw an edge in the up direction if node above
+            if v_coords[1] > 0 {
+                graph.add_edge(
+                    node_index,
+                    NodeIndexable::from_index(&graph, GridGraph::cell_index_with_width(n, [v_coords[0], v_coords[1] - 1])),
+                    String::from("")
+                );
             }
         }
 
@@ -71,41 +75,91 @@ impl GridGraph {
         self.m
     }
 
-    /// Determine whether two vertices are color compatible
+    /// Get the underlying petgraph graph, for algorithms this crate does
+    /// not implement.  Its nodes are indexed as by `cell_index` and are
+    /// labelled with their coordinates as "(y,x)".
+    pub fn get_graph(&self) -> &Graph<String, String, Undirected> {
+        &self.graph
+    }
+
+    /// Get the index of the vertex at the given coordinates, counting
+    /// the vertices row by row from the origin
+    pub fn cell_index(&self, v_coords: [usize; 2]) -> usize {
+        GridGraph::cell_index_with_width(self.n, v_coords)
+    }
+
+    /// Get the coordinates of the vertex at the given index, counting
+    /// the vertices row by row from the origin
+    pub fn coords_of(&self, index: usize) -> [usize; 2] {
+        GridGraph::coords_of_with_width(self.n, index)
+    }
+
+    /// Iterate over the coordinates of the vertices row by row from the
+    /// origin, which is the order in which they are indexed
+    pub fn cells(&self) -> impl Iterator<Item = [usize; 2]> {
+        GridGraph::cells_with_dimensions(self.n, self.m)
+    }
+
+    /// Get the index of the vertex at the given coordinates in a grid
+    /// graph of width n without constructing the grid graph
+    pub fn cell_index_with_width(n: usize, v_coords: [usize; 2]) -> usize {
+        gridcore::cell_index(n, v_coords)
+    }
+
+    /// Get the coordinates of the vertex at the given index in a grid
+    /// graph of width n without constructing the grid graph
+    pub fn coords_of_with_width(n: usize, index: usize) -> [usize; 2] {
+        gridcore::coords_of(n, index)
+    }
+
+    /// Iterate over the coordinates of the vertices of an n by m grid
+    /// graph row by row without constructing the grid graph
+    pub fn cells_with_dimensions(n: usize, m: usize) -> impl Iterator<Item = [usize; 2]> {
+        gridcore::cells(n, m)
+    }
+
+    /// Iterate over the edges of the grid graph as (from, to) pairs in
+    /// canonical order.  Each edge runs left to right or bottom to top, and
+    /// edges are ordered by their from vertex row by row from the origin,
+    /// the horizontal edge before the vertical edge at each vertex.
+    pub fn all_edges(&self) -> impl Iterator<Item = ([usize; 2], [usize; 2])> + '_ {
+        self.cells().flat_map(move |v_coords| {
+            let right: Option<([usize; 2], [usize; 2])> = (v_coords[0] + 1 < self.n).then(|| (v_coords, [v_coords[0] + 1, v_coords[1]]));
+            let up: Option<([usize; 2], [usize; 2])> = (v_coords[1] + 1 < self.m).then(|| (v_coords, [v_coords[0], v_coords[1] + 1]));
+            right.into_iter().chain(up)
+        })
+    }
+
+    /// Determine whether two vertices are color compatible.  A vertex is
+    /// color compatible with itself exactly when it has the majority
+    /// color of an odd grid graph, so the single vertex of a 1 by 1 grid
+    /// graph is color compatible with itself.
     pub fn are_color_compatible(&self, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
         //Sanity check on the input parameters
-        if v_coords[0] >= self.n || v_coords[1] >= self.m ||
-           w_coords[0] >= self.n || w_coords[1] >= self.m {
-            eprintln!(
-                "Coordinates out of bounds: ({},{}), ({},{})",
-                v_coords[0], v_coords[1],
-                w_coords[0], w_coords[1]
-            );
-            process::exit(1);
+        for coords in [v_coords, w_coords] {
+            if let Err(e) = validate::validate_coords(self.n, self.m, coords) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
         }
 
-        //Determine if the graph is even or odd
-        let graph_is_odd: bool = ((self.n*self.m) & 1) == 1;
-
-        //If the graph is odd then the majority color has even parity
-        if graph_is_odd {
-            //We therefore check if v and w both have even parity
-            return ((w_coords[0]+w_coords[1]) & 1 == 0) && ((v_coords[0]+v_coords[1]) & 1 == 0);
-        }
+        //Check the color conditions against the graph's dimensions
+        GridGraph::are_color_compatible_with_dimensions(self.n, self.m, v_coords, w_coords)
+    }
 
-        //If the graph is even then the vertices must share parity
-        return (w_coords[0]+w_coords[1]) & 1 != (v_coords[0]+v_coords[1]) & 1;
+    /// Determine whether two vertices of an n by m grid graph are color
+    /// compatible without constructing the grid graph.  The coordinates
+    /// are assumed to be in bounds.
+    pub fn are_color_compatible_with_dimensions(n: usize, m: usize, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+        gridcore::are_color_compatible(n, m, v_coords, w_coords)
     }
 
     /// Determine whether the vertex at the given coordinates
     /// is a corner vertex
     pub fn is_corner_vertex(&self, v_coords: [usize; 2]) -> bool {
         //Sanity check on the input parameters
-        if v_coords[0] >= self.n || v_coords[1] >= self.m {
-            eprintln!(
-                "Coordinate out of bounds: ({},{})",
-                v_coords[0], v_coords[1]
-            );
+        if let Err(e) = validate::validate_coords(self.n, self.m, v_coords) {
+            eprintln!("{}", e);
             process::exit(1);
         }
 
@@ -124,138 +178,155 @@ impl GridGraph {
     }
 
     /// Determine whether the Hamiltonian path problem over this
-    /// grid grpah is forbidden when either m or n is 1
-    fn is_forbidden_case_1(&self, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
-        //Return true if neither v or w are the origin vertex
-        if v_coords != [0, 0] && w_coords != [0, 0] {
-            return true;
-        }
-
-        //Determine which dimension is 1 and capture the opposite
-        let is_n: bool = self.n == 1;
-        let bound: usize = match is_n {
-            true => self.m,
-            false => self.n
-        };
-
-        //Return true if neither v or w are the opposite corner vertex
-        if is_n && (v_coords != [0, bound - 1] && w_coords != [0, bound - 1]) {
-            return true;
-        } else if !is_n && (v_coords != [bound - 1, 0] && w_coords != [bound - 1, 0]) {
-            return true;
+    /// grid graph is forbidden
+    pub fn is_forbidden(&self, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+        //Sanity check on the input parameters
+        for coords in [v_coords, w_coords] {
+            if let Err(e) = validate::validate_coords(self.n, self.m, coords) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
         }
 
-        //Return true if both v and w are corner vertices
-        return false;
+        //Check the forbidden conditions against the graph's dimensions
+        GridGraph::is_forbidden_with_dimensions(self.n, self.m, v_coords, w_coords)
     }
 
-    /// Determine whether the Hamiltonian path problem over this
-    /// grid grpah is forbidden when either m or n is 2
-    fn is_forbidden_case_2(&self, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
-        //Break if v or w is a corner vertex, as the edge between them
-        //cannot be a nonboundary edge in this case
-        if self.is_corner_vertex(v_coords) || self.is_corner_vertex(w_coords) {
-            return false;
-        }
+    /// Determine whether the Hamiltonian path problem over an n by m
+    /// grid graph is forbidden without constructing the grid graph.
+    /// The coordinates are assumed to be in bounds.
+    pub fn is_forbidden_with_dimensions(n: usize, m: usize, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
+        gridcore::is_forbidden(n, m, v_coords, w_coords)
+    }
 
-        //Determine which dimension is 2
-        let is_n: bool = self.n == 2;
+    /// Encode the Hamiltonian path problem over this grid graph between
+    /// the given vertices as an integer linear program in LP format.
+    ///
+    /// Each edge has a binary variable `x_i_j`, joining the vertices with
+    /// row-major indices i < j, which is 1 when the edge is on the path.
+    /// The start and end vertices have degree 1, all others degree 2, and
+    /// the path has one fewer edge than there are vertices.  Cycles are
+    /// forbidden by a single-commodity flow: the start sends one unit to
+    /// every other vertex over the continuous variables `f_i_j`, which
+    /// may only carry flow along edges on the path, so the chosen edges
+    /// connect every vertex.  The program has a constraint per vertex and
+    /// per edge, so it grows linearly with the grid's size.
+    pub fn to_lp_format(&self, start: [usize; 2], end: [usize; 2]) -> String {
+        //Collect the edges of the grid graph by vertex index
+        let num_cells: usize = self.n * self.m;
+        let edges: Vec<(usize, usize)> = self.all_edges()
+            .map(|(from, to)| (self.cell_index(from), self.cell_index(to)))
+            .collect();
+        let var = |edge: &(usize, usize)| format!("x_{}_{}", edge.0, edge.1);
+        let flow = |from: usize, to: usize| format!("f_{}_{}", from, to);
+
+        //Write the objective, which is constant as every path has the
+        //same number of edges
+        let mut lp: String = format!(
+            "\\ Hamiltonian path in a {} x {} grid graph from ({}, {}) to ({}, {})\nMinimize\n obj:{}\nSubject To\n",
+            self.n, self.m, start[0], start[1], end[0], end[1],
+            GridGraph::lp_sum(edges.iter().map(var).collect())
+        );
 
-        //If n is 2 then check if the vertices share a y coord
-        if is_n && (v_coords[1] == w_coords[1]) {
-            return true;
+        //Constrain the degree of each vertex
+        for v_coords in self.cells() {
+            let v: usize = self.cell_index(v_coords);
+            let degree: usize = if v_coords == start || v_coords == end { 1 } else { 2 };
+            let terms: Vec<String> = edges.iter().filter(|e| e.0 == v || e.1 == v).map(var).collect();
+            lp += &format!(" deg_{}:{} = {}\n", v, GridGraph::lp_sum(terms), degree);
         }
 
-        //If m is 2 then check if the vertices share an x coord
-        if !is_n && (v_coords[0] == w_coords[0]) {
-            return true;
+        //Constrain the number of edges on the path
+        lp += &format!(" num_edges:{} = {}\n", GridGraph::lp_sum(edges.iter().map(var).collect()), num_cells - 1);
+
+        //Send one unit of flow from the start to each other vertex, so
+        //the start sends out all but one unit per vertex and every other
+        //vertex keeps one of the units it receives
+        let start_index: usize = self.cell_index(start);
+        for v in 0..num_cells {
+            let mut neighbors: Vec<usize> = self.get_index_neighbors(v);
+            neighbors.sort();
+            let mut terms: Vec<String> = neighbors.iter().map(|u| flow(v, *u)).collect();
+            terms.extend(neighbors.iter().map(|u| format!("-{}", flow(*u, v))));
+            let supply: isize = if v == start_index { num_cells as isize - 1 } else { -1 };
+            lp += &format!(" flow_{}:{} = {}\n", v, GridGraph::lp_sum(terms), supply);
         }
 
-        //Return false if v and w lack a nonboundary edge between them
-        return false;
-    }
-
-    /// Determine whether the Hamiltonian path problem over this
-    /// grid grpah is forbidden when either m or n is 3
-    fn is_forbidden_case_3(&self, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
-        //Determine which dimension is 3 and capture the opposite
-        let is_n: bool = self.n == 3;
-        let opp_dim: usize = match is_n {
-            true => self.m,
-            false => self.n
-        };
-
-        //Check if the opposite dimension is odd, if so then break
-        if opp_dim & 1 == 1 {
-            return false;
+        //Only let flow along the edges on the path, in either direction
+        for edge in edges.iter() {
+            lp += &format!(
+                " cap_{}_{}: {} + {} - {} {} <= 0\n",
+                edge.0, edge.1, flow(edge.0, edge.1), flow(edge.1, edge.0), num_cells - 1, var(edge)
+            );
         }
 
-        //Check if v has the same color as w, if they share the same
-        //color then break
-        if (w_coords[0]+w_coords[1]) & 1 == (v_coords[0]+v_coords[1]) & 1 {
-            return false;
+        //Declare the edge variables binary, leaving the flow variables
+        //continuous and nonnegative
+        lp += "Binary\n";
+        for edge in edges.iter() {
+            lp += &format!(" {}\n", var(edge));
         }
+        lp += "End\n";
+        lp
+    }
 
-        //Check if v's position in relation to that of w satisfies the
-        //required conditions
-        let comp_coords: [usize; 2] = if is_n { [v_coords[1], w_coords[1]] } else { [v_coords[0], w_coords[0]] };
-        let opp_coord: usize = if is_n { v_coords[0] } else { v_coords[1] };
-        let is_greater: bool = comp_coords[0] > comp_coords[1];
-        let distance: usize = if is_greater { comp_coords[0] - comp_coords[1] } else { comp_coords[1] - comp_coords[0] };
-        let is_dst_sat: bool = if opp_coord == 1 { distance > 0 } else { distance > 1 };
-        
-        //Break if the distance condition is not satisfied
-        if !is_dst_sat {
-            return false;
+    /// Join terms into an LP format sum, breaking the line every 8 terms.
+    /// A term starting with `-` is subtracted.
+    fn lp_sum(terms: Vec<String>) -> String {
+        let mut sum: String = String::new();
+        for (i, term) in terms.iter().enumerate() {
+            if i > 0 && i % 8 == 0 {
+                sum += "\n   ";
+            }
+            match term.strip_prefix('-') {
+                Some(negated) => {
+                    sum += " - ";
+                    sum += negated;
+                },
+                None => {
+                    sum += if i > 0 { " + " } else { " " };
+                    sum += term;
+                }
+            }
         }
+        sum
+    }
 
-        //If the distance condition is satisfied then check if the
-        //vertex matches the parity of the far corner vertices if
-        //v is greater than w, or the near corner vertices otherwise
-        if is_greater && ((v_coords[0]+v_coords[1]) & 1 == (1 & 1)) {
-            return false; //v shares color with far corner vertices
-        } else if !is_greater && ((v_coords[0]+v_coords[1]) & 1 == (0 & 1)) {
-            return false; //v shares color with near corner vertices
+    /// Get the adjacency matrix of the grid graph in compressed sparse row
+    /// (CSR) form, as the (indptr, indices, data) triple used by
+    /// scipy.sparse and sprs.  Rows and columns are the row-major vertex
+    /// indices, and the column indices of each row are sorted.
+    pub fn to_sparse_csr(&self) -> (Vec<usize>, Vec<usize>, Vec<f64>) {
+        let mut indptr: Vec<usize> = vec![0];
+        let mut indices: Vec<usize> = Vec::new();
+        for v in 0..(self.n * self.m) {
+            let mut neighbors: Vec<usize> = self.get_index_neighbors(v);
+            neighbors.sort();
+            indices.extend(neighbors);
+            indptr.push(indices.len());
         }
-
-        //If we satisfy all of the conditions then the problem is forbidden
-        true
+        let data: Vec<f64> = vec![1.0; indices.len()];
+        (indptr, indices, data)
     }
 
-    /// Determine whether the Hamiltonian path problem over this
-    /// grid graph is forbidden
-    pub fn is_forbidden(&self, v_coords: [usize; 2], w_coords: [usize; 2]) -> bool {
-        //Sanity check on the input parameters
-        if v_coords[0] >= self.n || v_coords[1] >= self.m ||
-           w_coords[0] >= self.n || w_coords[1] >= self.m {
-            eprintln!(
-                "Coordinates out of bounds: ({},{}), ({},{})",
-                v_coords[0], v_coords[1],
-                w_coords[0], w_coords[1]
-            );
-            process::exit(1);
+    /// Get the row-major indices of the vertices adjacent to the vertex
+    /// with the given row-major index
+    fn get_index_neighbors(&self, v: usize) -> Vec<usize> {
+        let v_coords: [usize; 2] = self.coords_of(v);
+        let mut neighbors: Vec<usize> = Vec::new();
+        if v_coords[0] + 1 < self.n {
+            neighbors.push(v + 1);
         }
-
-        //Check if either m or n is 1, if so then check the forbidden
-        //conditions for this case
-        if self.n == 1 || self.m == 1 {
-            return self.is_forbidden_case_1(v_coords, w_coords);
+        if v_coords[1] + 1 < self.m {
+            neighbors.push(v + self.n);
         }
-
-        //Check if either m or n is 2, if so then check the forbidden
-        //conditions for this case
-        if self.n == 2 || self.m == 2 {
-            return self.is_forbidden_case_2(v_coords, w_coords);
+        if v_coords[0] > 0 {
+            neighbors.push(v - 1);
         }
-
-        //Check if either m or n is 3, if so then check the forbidden
-        //conditions for this case
-        if self.n == 3 || self.m == 3 {
-            return self.is_forbidden_case_3(v_coords, w_coords);
+        if v_coords[1] > 0 {
+            neighbors.push(v - self.n);
         }
-
-        //If none of the forbidden cases are satisfied then return false
-        false
+        neighbors
     }
 }
 
@@ -264,12 +335,14 @@ impl fmt::Display for GridGraph {
     ///
     /// For example, for a 3 by 2 grid graph:
     /// ```rust
+    /// use grid_solver::gridgraph::GridGraph;
+    ///
     /// let my_grid_graph: GridGraph = GridGraph::new(3, 2);
     /// println!("{}", my_grid_graph);
     /// ```
     ///
     /// Yields the following
-    /// ```
+    /// ```text
     /// o---o---o
     /// |   |   |
     /// o---o---o
@@ -291,12 +364,12 @@ impl fmt::Display for GridGraph {
                 let mut inter_node_display: String = String::from("");
 
                 //Get the node index
-                let node_index = NodeIndexable::from_index(&self.graph, (i*self.n) + j);
+                let node_index = NodeIndexable::from_index(&self.graph, self.cell_index([j, i]));
 
                 //Draw an edge in the left direction if node to the left
                 if j > 0 {
                     inter_node_display += "   ";
-                    if self.graph.contains_edge(node_index, NodeIndexable::from_index(&self.graph, (i*self.n) + j - 1)) {
+                    if self.graph.contains_edge(node_index, NodeIndexable::from_index(&self.graph, self.cell_index([j - 1, i]))) {
                         node_display += "---o";
                     } else {
                         node_display += "   o";
@@ -307,7 +380,7 @@ impl fmt::Display for GridGraph {
 
                 //Draw an edge in the up direction if node above
                 if i > 0 {
-                    if self.graph.contains_edge(node_index, NodeIndexable::from_index(&self.graph, ((i-1)*self.n) + j)) {
+                    if self.graph.contains_edge(node_index, NodeIndexable::from_index(&self.graph, self.cell_index([j, i - 1]))) {
                         inter_node_display += "|";
                     } else {
                         inter_node_display += " ";
@@ -327,8 +400,8 @@ impl fmt::Display for GridGraph {
             }
         }
 
-        //Write the graph display
-        f.write_str(&graph_display)
+        //Write the normalized graph display
+        f.write_str(&textnormalize::normalize_rendered(&graph_display))
     }
 }
 
@@ -447,6 +520,15 @@ mod test {
         );
     }
 
+    #[test]
+    fn color_comp_single_vertex() {
+        //The single vertex of a 1 by 1 grid graph has the majority color
+        //and so is color compatible with itself
+        let my_grid_graph: GridGraph = GridGraph::new(1, 1);
+        assert_eq!(my_grid_graph.are_color_compatible([0, 0], [0, 0]), true);
+        assert_eq!(my_grid_graph.is_forbidden([0, 0], [0, 0]), false);
+    }
+
     #[test]
     fn forbidden_case_1_width_part_forb() {
         //Initialize a width 1 grid graph
@@ -680,4 +762,232 @@ mod test {
             true
         )
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn cell_index_round_trip() {
+        //Loop through grid graphs of several dimensions
+        for (n, m) in [(1, 1), (1, 5), (5, 1), (3, 4), (4, 3), (7, 7)] {
+            let my_grid_graph: GridGraph = GridGraph::new(n, m);
+
+            //Every vertex should map to its index and back again
+            for v_coords in my_grid_graph.cells() {
+                assert_eq!(
+                    my_grid_graph.coords_of(my_grid_graph.cell_index(v_coords)),
+                    v_coords
+                );
+            }
+
+            //Every index should map to its vertex and back again
+            for index in 0..(n * m) {
+                assert_eq!(
+                    my_grid_graph.cell_index(my_grid_graph.coords_of(index)),
+                    index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn cells_match_node_order() {
+        //Loop through grid graphs of several dimensions
+        for (n, m) in [(1, 1), (1, 5), (5, 1), (3, 4), (4, 3), (7, 7)] {
+            let my_grid_graph: GridGraph = GridGraph::new(n, m);
+
+            //The cells should be iterated in the order the nodes were added,
+            //each node being labelled with its row and then its column
+            assert_eq!(my_grid_graph.cells().count(), my_grid_graph.graph.node_count());
+            for (index, v_coords) in my_grid_graph.cells().enumerate() {
+                assert_eq!(index, my_grid_graph.cell_index(v_coords));
+                assert_eq!(
+                    my_grid_graph.graph[NodeIndexable::from_index(&my_grid_graph.graph, index)],
+                    format!("({},{})", v_coords[1], v_coords[0])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn edge_count_formula() {
+        //Loop through grid graphs of several dimensions, including those
+        //a single vertex wide or tall
+        for (n, m) in [(1, 1), (1, 5), (5, 1), (2, 2), (3, 4), (4, 3), (7, 7), (10, 13)] {
+            let my_grid_graph: GridGraph = GridGraph::new(n, m);
+
+            //Each row has n-1 horizontal edges and each column m-1
+            //vertical edges
+            assert_eq!(my_grid_graph.graph.edge_count(), (n - 1) * m + n * (m - 1));
+        }
+    }
+
+    #[test]
+    fn corner_vertices() {
+        //Initialize a 5 by 7 grid graph
+        let my_grid_graph: GridGraph = GridGraph::new(5, 7);
+
+        //Each of the four corners is a corner vertex
+        for v_coords in [[0, 0], [4, 0], [0, 6], [4, 6]] {
+            assert_eq!(my_grid_graph.is_corner_vertex(v_coords), true);
+        }
+
+        //Interior and boundary vertices which are not corners are not
+        for v_coords in [[2, 3], [0, 3], [4, 3], [2, 0], [2, 6]] {
+            assert_eq!(my_grid_graph.is_corner_vertex(v_coords), false);
+        }
+    }
+
+    #[test]
+    fn to_lp_format_constraints() {
+        //A 3 by 2 grid graph has 6 vertices and 7 edges, so 6 degree and
+        //flow constraints and 7 capacity constraints
+        let my_grid_graph: GridGraph = GridGraph::new(3, 2);
+        let lp: String = my_grid_graph.to_lp_format([0, 0], [2, 0]);
+        assert_eq!(lp.lines().filter(|line| line.starts_with(" deg_")).count(), 6);
+        assert_eq!(lp.lines().filter(|line| line.starts_with(" flow_")).count(), 6);
+        assert_eq!(lp.lines().filter(|line| line.starts_with(" cap_")).count(), 7);
+        assert_eq!(lp.lines().filter(|line| line.starts_with(" x_")).count(), 7);
+
+        //The endpoints should have degree 1 and the rest degree 2
+        assert!(lp.contains(" deg_0: x_0_1 + x_0_3 = 1\n"));
+        assert!(lp.contains(" deg_1: x_0_1 + x_1_2 + x_1_4 = 2\n"));
+        assert!(lp.contains(" deg_2: x_1_2 + x_2_5 = 1\n"));
+
+        //The start should supply a unit of flow to each other vertex, along
+        //edges on the path only
+        assert!(lp.contains(" flow_0: f_0_1 + f_0_3 - f_1_0 - f_3_0 = 5\n"));
+        assert!(lp.contains(" flow_4: f_4_1 + f_4_3 + f_4_5 - f_1_4 - f_3_4 - f_5_4 = -1\n"));
+        assert!(lp.contains(" cap_0_1: f_0_1 + f_1_0 - 5 x_0_1 <= 0\n"));
+        assert!(lp.ends_with("Binary\n x_0_1\n x_0_3\n x_1_2\n x_1_4\n x_2_5\n x_3_4\n x_4_5\nEnd\n"));
+    }
+
+    #[test]
+    fn to_lp_format_grows_linearly() {
+        //A 30 by 30 grid graph has 900 vertices and 1740 edges, and one
+        //constraint of each kind per vertex or edge
+        let my_grid_graph: GridGraph = GridGraph::new(30, 30);
+        let lp: String = my_grid_graph.to_lp_format([0, 0], [29, 0]);
+        assert_eq!(lp.lines().filter(|line| line.starts_with(" deg_")).count(), 900);
+        assert_eq!(lp.lines().filter(|line| line.starts_with(" flow_")).count(), 900);
+        assert_eq!(lp.lines().filter(|line| line.starts_with(" cap_")).count(), 1740);
+        assert!(lp.len() < 1_000_000);
+    }
+
+    #[test]
+    fn to_lp_format_accepts_hamiltonian_path() {
+        //Set the edge variables from a Hamiltonian path of a 3 by 3 grid
+        //graph, and send the flow along it, each edge carrying a unit for
+        //every vertex after it
+        let my_grid_graph: GridGraph = GridGraph::new(3, 3);
+        let order: Vec<usize> = vec![0, 1, 2, 5, 4, 3, 6, 7, 8];
+        let mut values: Vec<(String, f64)> = Vec::new();
+        for (k, pair) in order.windows(2).enumerate() {
+            let (v, w): (usize, usize) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            values.push((format!("x_{}_{}", v, w), 1.0));
+            values.push((format!("f_{}_{}", pair[0], pair[1]), (order.len() - 1 - k) as f64));
+        }
+        let value = |name: &str| values.iter().find(|(var, _)| var == name).map(|(_, x)| *x).unwrap_or(0.0);
+
+        //Evaluate each constraint, joining its continuation lines
+        let lp: String = my_grid_graph.to_lp_format([0, 0], [2, 2]).replace("\n   ", "");
+        let constraints: Vec<&str> = lp.lines()
+            .skip_while(|line| *line != "Subject To")
+            .skip(1)
+            .take_while(|line| *line != "Binary")
+            .collect();
+        assert_eq!(constraints.len(), 9 + 1 + 9 + 12);
+        for constraint in constraints {
+            let body: &str = constraint.split_once(':').unwrap().1;
+            let (lhs, op, rhs): (&str, &str, &str) = if let Some((lhs, rhs)) = body.split_once(" <= ") {
+                (lhs, "<=", rhs)
+            } else {
+                let (lhs, rhs) = body.split_once(" = ").unwrap();
+                (lhs, "=", rhs)
+            };
+            let mut total: f64 = 0.0;
+            for signed_term in lhs.replace(" - ", " + -").split(" + ") {
+                let (sign, term): (f64, &str) = match signed_term.trim().strip_prefix('-') {
+                    Some(term) => (-1.0, term),
+                    None => (1.0, signed_term.trim())
+                };
+                total += sign * match term.split_once(' ') {
+                    Some((coefficient, var)) => coefficient.parse::<f64>().unwrap() * value(var),
+                    None => value(term)
+                };
+            }
+            let rhs: f64 = rhs.trim().parse::<f64>().unwrap();
+            match op {
+                "<=" => assert!(total <= rhs, "{}", constraint),
+                _ => assert_eq!(total, rhs, "{}", constraint)
+            }
+        }
+    }
+
+    #[test]
+    fn to_sparse_csr() {
+        //A 3 by 2 grid graph has 7 edges, each stored in both directions
+        let my_grid_graph: GridGraph = GridGraph::new(3, 2);
+        let (indptr, indices, data) = my_grid_graph.to_sparse_csr();
+        assert_eq!(indptr, vec![0, 2, 5, 7, 9, 12, 14]);
+        assert_eq!(indices, vec![1, 3, 0, 2, 4, 1, 5, 0, 4, 1, 3, 5, 2, 4]);
+        assert_eq!(data, vec![1.0; 14]);
+
+        //The matrix should agree with the underlying graph's edges
+        for v in 0..6 {
+            for u in indices[indptr[v]..indptr[v + 1]].iter() {
+                assert_eq!(my_grid_graph.graph.contains_edge(
+                    NodeIndexable::from_index(&my_grid_graph.graph, v),
+                    NodeIndexable::from_index(&my_grid_graph.graph, *u)
+                ), true);
+            }
+        }
+
+        //A single vertex has no edges
+        assert_eq!(GridGraph::new(1, 1).to_sparse_csr(), (vec![0, 0], Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn display_exact_ascii() {
+        //Every vertex and edge of a 3 by 2 grid graph is drawn, with no
+        //trailing newline
+        let my_grid_graph: GridGraph = GridGraph::new(3, 2);
+        assert_eq!(format!("{}", my_grid_graph), "o---o---o\n|   |   |\no---o---o");
+
+        //Every inter-row line is drawn between consecutive rows
+        let my_grid_graph: GridGraph = GridGraph::new(2, 3);
+        assert_eq!(format!("{}", my_grid_graph), "o---o\n|   |\no---o\n|   |\no---o");
+    }
+
+    #[test]
+    fn get_graph_matches_grid() {
+        //The petgraph graph holds every vertex and edge of the grid, with
+        //nodes indexed row by row
+        let my_grid_graph: GridGraph = GridGraph::new(4, 3);
+        let graph: &Graph<String, String, Undirected> = my_grid_graph.get_graph();
+        assert_eq!(graph.node_count(), 12);
+        assert_eq!(graph.edge_count(), 17);
+        assert_eq!(graph[NodeIndexable::from_index(graph, my_grid_graph.cell_index([3, 1]))], "(1,3)");
+    }
+
+    #[test]
+    fn all_edges_canonical_order() {
+        //The edges of a 3 by 2 grid graph run from each vertex in turn,
+        //rightward before upward
+        let my_grid_graph: GridGraph = GridGraph::new(3, 2);
+        assert_eq!(my_grid_graph.all_edges().collect::<Vec<([usize; 2], [usize; 2])>>(), vec![
+            ([0, 0], [1, 0]), ([0, 0], [0, 1]), ([1, 0], [2, 0]), ([1, 0], [1, 1]),
+            ([2, 0], [2, 1]), ([0, 1], [1, 1]), ([1, 1], [2, 1])
+        ]);
+
+        //Every edge of the underlying graph is yielded exactly once
+        for (n, m) in [(1, 1), (1, 4), (4, 1), (5, 3)] {
+            let my_grid_graph: GridGraph = GridGraph::new(n, m);
+            let edges: Vec<([usize; 2], [usize; 2])> = my_grid_graph.all_edges().collect();
+            assert_eq!(edges.len(), my_grid_graph.graph.edge_count());
+            for (from, to) in edges {
+                assert_eq!(my_grid_graph.graph.contains_edge(
+                    NodeIndexable::from_index(&my_grid_graph.graph, my_grid_graph.cell_index(from)),
+                    NodeIndexable::from_index(&my_grid_graph.graph, my_grid_graph.cell_index(to))
+                ), true);
+            }
+        }
+    }
+}
@@ -1,13 +1,40 @@
+use crate::axisorder::AxisOrder;
+use crate::convention::Convention;
+use crate::corner::Corner;
+use crate::glyphset::GlyphSet;
+use crate::gridcore::{self, RleError};
 use crate::gridextension::GridExtension;
+use crate::gridgraph::GridGraph;
+use crate::gridpatherror::GridPathError;
+use crate::gridproblem::GridProblem;
+use crate::pathparseerror::PathParseError;
+use crate::primetable::PrimeTable;
+use crate::rect::Rect;
+use crate::renderoptions::RenderOptions;
+use crate::textnormalize;
+use crate::validationmode::ValidationMode;
+use crate::validationreport::ValidationReport;
 
 use std::fmt;
 use std::process;
 use petgraph::Undirected;
-use petgraph::graph::Graph;
+use petgraph::graph::{Graph, NodeIndex};
 use petgraph::visit::NodeIndexable;
 use lazy_static::lazy_static;
 use json::JsonValue;
 
+/// Screen coordinates, (x, y) from the top left corner with y counting down
+const SCREEN_CONVENTION: Convention = Convention {
+    origin: Corner::TopLeft,
+    axis_order: AxisOrder::ColumnRow
+};
+
+/// Row and column coordinates, with rows counting up from the bottom
+const ROW_COL_CONVENTION: Convention = Convention {
+    origin: Corner::BottomLeft,
+    axis_order: AxisOrder::RowColumn
+};
+
 /// # GridPath struct
 ///
 /// A `GridPath` is an n by m grid of vertices joined by
@@ -15,17 +42,29 @@ use json::JsonValue;
 pub struct GridPath {
     n: usize,
     m: usize,
-    pub vertex_order: Vec<[usize; 2]>,
+    vertex_order: Vec<[usize; 2]>,
     graph: Graph<String, String, Undirected>
 }
 
 impl GridPath {
     /// Initialize a GridPath given its dimensions (n by m)
     ///
+    /// The vertex order is not validated, so this is the constructor to
+    /// use for paths which are correct by construction.  The vertex order
+    /// should form a Hamiltonian path over the grid graph, otherwise the
+    /// methods of the GridPath give meaningless results, and a vertex out
+    /// of bounds panics.  Use `from_problem_and_order` to validate it.
+    ///
     /// ### Example
     ///
     /// ```rust
-    /// let my_grid_graph: GridPath = GridPath::new(4_usize, 3_usize);
+    /// use grid_solver::gridpath::GridPath;
+    ///
+    /// let my_vertex_order: Vec<[usize; 2]> = vec![
+    ///     [0, 0], [0, 1], [1, 1],
+    ///     [2, 1], [2, 0], [1, 0]
+    /// ];
+    /// let my_grid_path: GridPath = GridPath::new(3_usize, 2_usize, my_vertex_order);
     /// ```
     pub fn new(n: usize, m: usize, vertex_order: Vec<[usize; 2]>) -> GridPath {
         //Get the graph given the vertex order
@@ -40,29 +79,164 @@ impl GridPath {
         }
     }
 
+    /// Get the width of the grid the path is over
+    pub fn get_width(&self) -> usize {
+        self.n
+    }
+
+    /// Get the height of the grid the path is over
+    pub fn get_height(&self) -> usize {
+        self.m
+    }
+
+    /// Get the vertices of the path in the order it visits them
+    pub fn get_vertex_order(&self) -> &[[usize; 2]] {
+        &self.vertex_order
+    }
+
+    /// Get the underlying petgraph graph, for algorithms this crate does
+    /// not implement.  It holds every vertex of the grid, indexed as by
+    /// `GridGraph::cell_index`, and only the edges of the path.
+    pub fn get_graph(&self) -> &Graph<String, String, Undirected> {
+        &self.graph
+    }
+
+    /// Get the coordinates of the vertex the path visits after k steps,
+    /// or None if the path has fewer than k + 1 vertices
+    pub fn vertex_at_step(&self, k: usize) -> Option<[usize; 2]> {
+        self.vertex_order.get(k).copied()
+    }
+
+    /// Consume the GridPath and get its vertex order without cloning it
+    pub fn into_vertex_order(self) -> Vec<[usize; 2]> {
+        self.vertex_order
+    }
+
+    /// Initialize a GridPath given a GridProblem and a vertex order, such
+    /// as one read from a file or received over the network.  The vertex
+    /// order must form a Hamiltonian path over the problem's grid graph
+    /// between its start and end vertices.
+    pub fn from_problem_and_order(problem: &GridProblem, vertex_order: Vec<[usize; 2]>) -> Result<GridPath, GridPathError> {
+        //Get the dimensions of the grid graph from the problem and
+        //validate the vertex order against them
+        let n: usize = problem.get_width();
+        let m: usize = problem.get_height();
+        GridPath::validate_vertex_order(n, m, &vertex_order)?;
+
+        //Check that the path runs between the problem's start and end vertices
+        let expected: ([usize; 2], [usize; 2]) = (problem.get_start_coords(), problem.get_end_coords());
+        let actual: ([usize; 2], [usize; 2]) = (vertex_order[0], vertex_order[vertex_order.len() - 1]);
+        if actual != expected {
+            return Err(GridPathError::WrongEndpoints { expected: expected, actual: actual });
+        }
+
+        //Initialize the GridPath
+        Ok(GridPath::new(n, m, vertex_order))
+    }
+
+    /// Given dimensions and a vertex order, check that the vertex order
+    /// visits each vertex of the grid exactly once, moving only between
+    /// adjacent vertices
+    pub(crate) fn validate_vertex_order(n: usize, m: usize, vertex_order: &Vec<[usize; 2]>) -> Result<(), GridPathError> {
+        //Check that there is one vertex in the order per grid vertex
+        if vertex_order.len() != n * m {
+            return Err(GridPathError::WrongLength { expected: n * m, actual: vertex_order.len() });
+        }
+
+        //Check that each vertex is in bounds and visited only once
+        let mut visited: Vec<bool> = vec![false; n * m];
+        for vertex in vertex_order.iter() {
+            if vertex[0] >= n || vertex[1] >= m {
+                return Err(GridPathError::OutOfBounds(*vertex));
+            }
+            if visited[GridGraph::cell_index_with_width(n, *vertex)] {
+                return Err(GridPathError::RepeatedVertex(*vertex));
+            }
+            visited[GridGraph::cell_index_with_width(n, *vertex)] = true;
+        }
+
+        //Check that each pair of consecutive vertices is adjacent
+        for i in 1..vertex_order.len() {
+            let v: [usize; 2] = vertex_order[i-1];
+            let w: [usize; 2] = vertex_order[i];
+            if v[0].abs_diff(w[0]) + v[1].abs_diff(w[1]) != 1 {
+                return Err(GridPathError::NonAdjacent(v, w));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the GridPath is a Hamiltonian path over its grid,
+    /// running between the given endpoints if any
+    pub(crate) fn check_hamiltonian(&self, endpoints: Option<([usize; 2], [usize; 2])>) -> Result<(), GridPathError> {
+        GridPath::validate_vertex_order(self.n, self.m, &self.vertex_order)?;
+        if let Some(expected) = endpoints {
+            let actual: ([usize; 2], [usize; 2]) = (self.vertex_order[0], self.vertex_order[self.vertex_order.len() - 1]);
+            if actual != expected {
+                return Err(GridPathError::WrongEndpoints { expected: expected, actual: actual });
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the GridPath's vertex order against the given mode,
+    /// reporting how much of the grid it covers, which vertices lie
+    /// outside it, which it repeats, and where it first steps between
+    /// non-adjacent vertices.  A vertex outside the grid fails every mode.
+    pub fn validate(&self, mode: ValidationMode) -> ValidationReport {
+        //Count the vertices visited and note those visited more than once,
+        //setting aside those outside the grid before indexing into it
+        let mut visited: Vec<bool> = vec![false; self.n * self.m];
+        let mut num_visited: usize = 0;
+        let mut repeated_vertices: Vec<[usize; 2]> = Vec::new();
+        let mut out_of_bounds_vertices: Vec<[usize; 2]> = Vec::new();
+        for vertex in self.vertex_order.iter() {
+            if vertex[0] >= self.n || vertex[1] >= self.m {
+                if !out_of_bounds_vertices.contains(vertex) {
+                    out_of_bounds_vertices.push(*vertex);
+                }
+                continue;
+            }
+            let index: usize = GridGraph::cell_index_with_width(self.n, *vertex);
+            if !visited[index] {
+                visited[index] = true;
+                num_visited += 1;
+            } else if !repeated_vertices.contains(vertex) {
+                repeated_vertices.push(*vertex);
+            }
+        }
+
+        //Find the first pair of consecutive vertices which are not adjacent
+        let first_adjacency_violation: Option<([usize; 2], [usize; 2])> = self.vertex_order.windows(2)
+            .find(|pair| pair[0][0].abs_diff(pair[1][0]) + pair[0][1].abs_diff(pair[1][1]) != 1)
+            .map(|pair| (pair[0], pair[1]));
+
+        //Initialize the report
+        ValidationReport {
+            mode: mode,
+            coverage: num_visited as f64 / (self.n * self.m) as f64,
+            out_of_bounds_vertices: out_of_bounds_vertices,
+            repeated_vertices: repeated_vertices,
+            first_adjacency_violation: first_adjacency_violation
+        }
+    }
+
     /// Given dimensions and a vertext order, get a grid-shaped petgraph graph
     /// structure with edges forming the path given by the vertex order.
     fn get_graph_from_vertex_order(n: usize, m: usize, vertex_order: &Vec<[usize; 2]>) -> Graph<String, String, Undirected> {
         //Initialize the graph
         let mut graph = Graph::new_undirected();
 
-        //Add nodes to the graph
-        for i in 0..m {
-            for j in 0..n {
-                //Add the node
-                graph.add_node(format!("({},{})",i,j));
-            }
+        //Add nodes to the graph in row-major order
+        for v_coords in GridGraph::cells_with_dimensions(n, m) {
+            graph.add_node(format!("({},{})", v_coords[1], v_coords[0]));
         }
 
         //Add edges to the graph
         for i in 1..vertex_order.len() {
             //Determine the nodes at the ith and i-1th coordinate pairs
-            let n1_x: usize = vertex_order[i-1][0];
-            let n1_y: usize = vertex_order[i-1][1];
-            let n2_x: usize = vertex_order[i][0];
-            let n2_y: usize = vertex_order[i][1];
-            let n1_index: usize = (n1_y * n) + n1_x;
-            let n2_index: usize = (n2_y * n) + n2_x;
+            let n1_index: usize = GridGraph::cell_index_with_width(n, vertex_order[i-1]);
+            let n2_index: usize = GridGraph::cell_index_with_width(n, vertex_order[i]);
             let n1 = NodeIndexable::from_index(&graph, n1_index);
             let n2 = NodeIndexable::from_index(&graph, n2_index);
 
@@ -77,69 +251,13 @@ impl GridPath {
     /// Check if there exists a prime solution for the given
     /// dimensions and start and end coordinates
     pub fn is_prime(width: usize, height: usize, start: [usize; 2], end: [usize; 2]) -> bool {
-        //Get the static ref to the prime solutions JSON
-        let prime_solution_json_ref = &*PRIME_SOLUTION_JSON;
-
-        //Loop through dimension-specific solution objects
-        for graph_dimension_solutions in prime_solution_json_ref.members() {
-            //If the dimensions do not match those given then continue
-            if graph_dimension_solutions["n"] != width || graph_dimension_solutions["m"] != height {
-                continue;
-            }
-
-            //If the dimensions match then loop through its paths
-            for prime_path in graph_dimension_solutions["paths"].members() {
-                //If the start and end vertices match those given then return true
-                if prime_path[0][0] == start[0] && prime_path[0][1] == start[1] &&
-                   prime_path[(width * height) - 1][0] == end[0] && prime_path[(width * height) - 1][1] == end[1] {
-                    return true;
-                }
-            }
-
-            //If the dimensions match but no matching start & end vertex paths were
-            //found then return 
-            return false;
-        }
-
-        //If we make it out of the loop then no solution was found, return false
-        return false;
+        PrimeTable::find(width, height, start, end).is_some()
     }
 
-    /// Check if there exists a prime solution for the given
-    /// dimensions and start and end coordinates
+    /// Get the prime solution for the given dimensions and start and
+    /// end coordinates, if there is one
     pub fn get_prime(width: usize, height: usize, start: [usize; 2], end: [usize; 2]) -> Option<GridPath> {
-        //Get the static ref to the prime solutions JSON
-        let prime_solution_json_ref = &*PRIME_SOLUTION_JSON;
-
-        //Loop through dimension-specific solution objects
-        for graph_dimension_solutions in prime_solution_json_ref.members() {
-            //If the dimensions do not match those given then continue
-            if graph_dimension_solutions["n"] != width || graph_dimension_solutions["m"] != height {
-                continue;
-            }
-
-            //If the dimensions match then loop through its paths
-            for prime_path in graph_dimension_solutions["paths"].members() {
-                //If the start and end vertices match those given then instantiate
-                //and return the path
-                if prime_path[0][0] == start[0] && prime_path[0][1] == start[1] &&
-                   prime_path[(width * height) - 1][0] == end[0] && prime_path[(width * height) - 1][1] == end[1] {
-                    return Some(
-                        GridPath::new(
-                            width, height,
-                            prime_path.members().map(|v| [v[0].as_usize().unwrap(), v[1].as_usize().unwrap()]).collect()
-                        )
-                    );
-                }
-            }
-
-            //If the dimensions match but no matching start & end vertex paths were
-            //found then return None
-            return None;
-        }
-
-        //If we make it out of the loop then no solution was found, return None
-        return None;
+        PrimeTable::find(width, height, start, end).map(|vertex_order| GridPath::new(width, height, vertex_order.clone()))
     }
 
     /// Increment the x coordinate of all vertices by a usize
@@ -402,33 +520,568 @@ impl GridPath {
     }
 
     /// Given a Vec<GridExtension>, extend the GridPath in those directions
+    ///
+    /// The extensions are recorded in the order the strips were taken off,
+    /// so they are applied in reverse to undo the last strip first
     pub fn extend_many(&mut self, extensions: &Vec<GridExtension>) {
-        for direction in extensions.iter() {
+        for direction in extensions.iter().rev() {
             self.extend(*direction);
         }
     }
-}
 
-impl fmt::Display for GridPath {
-    /// Format a GridPath as a string
+    /// Get the edges of the path, as pairs of consecutive vertices in path
+    /// order, which lie along the given boundary of the grid.  The path
+    /// can be extended in a direction exactly when it has such an edge on
+    /// that boundary, and the extension is made along the first of them.
+    pub fn shared_boundary_edges(&self, boundary: GridExtension) -> Vec<([usize; 2], [usize; 2])> {
+        let is_on_boundary = |v: [usize; 2]| -> bool {
+            match boundary {
+                GridExtension::Right => v[0] == self.n - 1,
+                GridExtension::Up => v[1] == self.m - 1,
+                GridExtension::Left => v[0] == 0,
+                GridExtension::Down => v[1] == 0
+            }
+        };
+        self.vertex_order.windows(2)
+            .filter(|edge| is_on_boundary(edge[0]) && is_on_boundary(edge[1]))
+            .map(|edge| (edge[0], edge[1]))
+            .collect()
+    }
+
+    /// Get the Shannon entropy, in nats, of the distribution of the
+    /// directions of the steps along the GridPath.  A path stepping
+    /// equally often in all four directions has entropy ln(4), while a
+    /// straight path has entropy 0.
+    pub fn direction_entropy(&self) -> f64 {
+        //Count the steps taken right, up, left and down
+        let mut counts: [usize; 4] = [0; 4];
+        for i in 1..self.vertex_order.len() {
+            let v: [usize; 2] = self.vertex_order[i-1];
+            let w: [usize; 2] = self.vertex_order[i];
+            if w[0] > v[0] {
+                counts[0] += 1;
+            } else if w[1] > v[1] {
+                counts[1] += 1;
+            } else if w[0] < v[0] {
+                counts[2] += 1;
+            } else {
+                counts[3] += 1;
+            }
+        }
+
+        //Sum -p ln(p) over the directions which were taken
+        let num_steps: usize = counts.iter().sum();
+        let mut entropy: f64 = 0.0;
+        for count in counts.iter().filter(|count| **count > 0) {
+            let p: f64 = *count as f64 / num_steps as f64;
+            entropy -= p * p.ln();
+        }
+        entropy
+    }
+
+    /// Check if the path changes direction at the middle of three
+    /// consecutive vertices
+    fn is_turn(u: [usize; 2], v: [usize; 2], w: [usize; 2]) -> bool {
+        let step_in: [isize; 2] = [v[0] as isize - u[0] as isize, v[1] as isize - u[1] as isize];
+        let step_out: [isize; 2] = [w[0] as isize - v[0] as isize, w[1] as isize - v[1] as isize];
+        step_in != step_out
+    }
+
+    /// Get the number of direction changes the path makes at the given
+    /// vertex.  This is 0 if the path does not visit the vertex, starts
+    /// or ends there, or goes straight through it, and 1 if it turns.
+    pub fn num_turns_at_vertex(&self, coords: [usize; 2]) -> usize {
+        let mut num_turns: usize = 0;
+        for i in 1..self.vertex_order.len().saturating_sub(1) {
+            if self.vertex_order[i] == coords && GridPath::is_turn(self.vertex_order[i-1], self.vertex_order[i], self.vertex_order[i+1]) {
+                num_turns += 1;
+            }
+        }
+        num_turns
+    }
+
+    /// Get the bend points of the path, the vertices at which it turns,
+    /// in the order the path visits them
+    pub fn bend_points(&self) -> Vec<[usize; 2]> {
+        let mut bend_points: Vec<[usize; 2]> = Vec::new();
+        for i in 1..self.vertex_order.len().saturating_sub(1) {
+            if GridPath::is_turn(self.vertex_order[i-1], self.vertex_order[i], self.vertex_order[i+1]) {
+                bend_points.push(self.vertex_order[i]);
+            }
+        }
+        bend_points
+    }
+
+    /// Get the vertices the path goes straight through without turning,
+    /// in the order the path visits them.  With the bend points and the
+    /// endpoints these make up every vertex of the path.
+    pub fn straight_through_vertices(&self) -> Vec<[usize; 2]> {
+        let mut straight_through_vertices: Vec<[usize; 2]> = Vec::new();
+        for i in 1..self.vertex_order.len().saturating_sub(1) {
+            if !GridPath::is_turn(self.vertex_order[i-1], self.vertex_order[i], self.vertex_order[i+1]) {
+                straight_through_vertices.push(self.vertex_order[i]);
+            }
+        }
+        straight_through_vertices
+    }
+
+    /// Get the number of leading vertices this path shares with another,
+    /// visited in the same order
+    pub fn common_prefix_length(&self, other: &GridPath) -> usize {
+        self.vertex_order.iter()
+            .zip(other.vertex_order.iter())
+            .take_while(|(v, w)| v == w)
+            .count()
+    }
+
+    /// Get the number of trailing vertices this path shares with another,
+    /// visited in the same order
+    pub fn common_suffix_length(&self, other: &GridPath) -> usize {
+        self.vertex_order.iter().rev()
+            .zip(other.vertex_order.iter().rev())
+            .take_while(|(v, w)| v == w)
+            .count()
+    }
+
+    /// Split the path into its maximal straight runs.  Each segment is a
+    /// run of consecutive vertices joined by steps in a single direction,
+    /// and each new segment begins at the vertex following a change of
+    /// direction, so the segments together hold every vertex exactly once.
+    /// A boustrophedon path over an n by m grid has m segments of n vertices.
+    pub fn path_segments(&self) -> Vec<Vec<[usize; 2]>> {
+        let mut segments: Vec<Vec<[usize; 2]>> = Vec::new();
+        let mut segment: Vec<[usize; 2]> = Vec::new();
+        for vertex in self.vertex_order.iter() {
+            //Continue the segment if it is a single vertex or the step to
+            //this vertex keeps its direction
+            let is_collinear: bool = match segment.len() {
+                0 | 1 => true,
+                len => !GridPath::is_turn(segment[len - 2], segment[len - 1], *vertex)
+            };
+            if !is_collinear {
+                segments.push(segment);
+                segment = Vec::new();
+            }
+            segment.push(*vertex);
+        }
+        if !segment.is_empty() {
+            segments.push(segment);
+        }
+        segments
+    }
+
+    /// Initialize a GridPath from straight-line segments, such as those
+    /// returned by `path_segments`.  Consecutive segments may either be
+    /// joined by a step, as `path_segments` splits them, or share the
+    /// vertex at which they meet, in which case it is only kept once.
+    /// The merged vertex order must form a Hamiltonian path over the grid.
+    pub fn from_segments(n: usize, m: usize, segments: Vec<Vec<[usize; 2]>>) -> Result<GridPath, GridPathError> {
+        let mut vertex_order: Vec<[usize; 2]> = Vec::new();
+        for (i, segment) in segments.into_iter().enumerate() {
+            //Check that the segment runs in a straight line
+            if segment.is_empty() {
+                return Err(GridPathError::EmptySegment(i));
+            }
+            if segment.windows(3).any(|w| GridPath::is_turn(w[0], w[1], w[2])) {
+                return Err(GridPathError::NonCollinearSegment(i));
+            }
+
+            //Merge the segment, dropping its first vertex if it is shared
+            //with the end of the previous segment
+            let shared: usize = if vertex_order.last() == segment.first() { 1 } else { 0 };
+            vertex_order.extend_from_slice(&segment[shared..]);
+        }
+        GridPath::validate_vertex_order(n, m, &vertex_order)?;
+        Ok(GridPath::new(n, m, vertex_order))
+    }
+
+    /// Initialize a GridPath from a petgraph graph whose nodes carry their
+    /// coordinates and whose edges form a single path covering every node,
+    /// such as one computed with petgraph's algorithms.  The vertex order
+    /// is recovered by walking the path from the first node of degree 1.
+    /// Every node must have degree 1 or 2, with exactly two of degree 1
+    /// unless the grid is a single vertex, the path must reach every node
+    /// and the recovered vertex order must form a Hamiltonian path over
+    /// the grid.
+    pub fn from_petgraph(n: usize, m: usize, graph: &Graph<[usize; 2], (), Undirected>) -> Result<GridPath, GridPathError> {
+        let total: usize = graph.node_count();
+        if total != n * m {
+            return Err(GridPathError::WrongLength { expected: n * m, actual: total });
+        }
+
+        //Check the degree of each node, noting the first end of the path
+        let mut num_endpoints: usize = 0;
+        let mut first_end: Option<NodeIndex> = None;
+        for node in graph.node_indices() {
+            let degree: usize = graph.edges(node).count();
+            match degree {
+                1 => {
+                    num_endpoints += 1;
+                    first_end = first_end.or(Some(node));
+                },
+                2 => {},
+                0 if total == 1 => first_end = Some(node),
+                _ => return Err(GridPathError::InvalidDegree(graph[node], degree))
+            }
+        }
+        if total > 1 && num_endpoints != 2 {
+            return Err(GridPathError::WrongNumEndpoints(num_endpoints));
+        }
+
+        //Walk the path from its first end until no unvisited node is left
+        //adjacent to the last node visited
+        let mut vertex_order: Vec<[usize; 2]> = Vec::with_capacity(total);
+        let mut visited: Vec<bool> = vec![false; total];
+        let mut current: Option<NodeIndex> = first_end;
+        while let Some(node) = current {
+            visited[node.index()] = true;
+            vertex_order.push(graph[node]);
+            current = graph.neighbors(node).find(|neighbor| !visited[neighbor.index()]);
+        }
+        if vertex_order.len() < total {
+            return Err(GridPathError::Disconnected { reached: vertex_order.len(), total: total });
+        }
+        GridPath::validate_vertex_order(n, m, &vertex_order)?;
+        Ok(GridPath::new(n, m, vertex_order))
+    }
+
+    /// Get a fingerprint of the path's dimensions and vertex order.  It
+    /// is computed with 64 bit FNV-1a so it is stable across platforms
+    /// and releases, and equal paths always have equal fingerprints.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let values = [self.n, self.m].into_iter().chain(self.vertex_order.iter().flatten().copied());
+        for value in values {
+            for byte in (value as u64).to_le_bytes() {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        }
+        hash
+    }
+
+    /// Get the smallest rectangle containing every vertex of the path,
+    /// or None if the path has no vertices
+    pub fn bounding_box(&self) -> Option<Rect> {
+        let first: [usize; 2] = *self.vertex_order.first()?;
+        let mut min: [usize; 2] = first;
+        let mut max: [usize; 2] = first;
+        for v in self.vertex_order.iter() {
+            min = [min[0].min(v[0]), min[1].min(v[1])];
+            max = [max[0].max(v[0]), max[1].max(v[1])];
+        }
+        Rect::new(min, max)
+    }
+
+    /// Encode the steps of the path as runs of a count followed by the
+    /// direction of the run, R, U, L or D, with up towards increasing y.
+    /// For example a path stepping right 5 times then up once is "5R1U".
+    pub fn to_rle(&self) -> String {
+        gridcore::encode_rle(&self.vertex_order)
+    }
+
+    /// Decode a path over an n by m grid from the given start vertex and
+    /// the runs written by `to_rle`.  The decoded vertex order must form a
+    /// Hamiltonian path over the grid.
+    pub fn from_rle(start: [usize; 2], s: &str, n: usize, m: usize) -> Result<GridPath, PathParseError> {
+        let vertex_order: Vec<[usize; 2]> = match gridcore::decode_rle(start, s, n, m) {
+            Ok(x) => x,
+            Err(RleError::InvalidRun(run)) => return Err(PathParseError::InvalidRun(String::from(&s[run]))),
+            Err(RleError::RunOutOfBounds(run)) => return Err(PathParseError::RunOutOfBounds(String::from(&s[run])))
+        };
+        if let Err(e) = GridPath::validate_vertex_order(n, m, &vertex_order) {
+            return Err(PathParseError::InvalidPath(e));
+        }
+        Ok(GridPath::new(n, m, vertex_order))
+    }
+
+    /// Get the path with its vertices given in another convention, such
+    /// as `Convention::MATRIX` for comparing against tools which index
+    /// from the top left corner by row then column.  The path is over the
+    /// grid's dimensions in that convention's axis order.
+    pub fn reoriented(&self, convention: Convention) -> GridPath {
+        let [n, m]: [usize; 2] = convention.dimensions(self.n, self.m);
+        GridPath::new(n, m, self.vertex_order.iter().map(|v| convention.from_cartesian(self.n, self.m, *v)).collect())
+    }
+
+    /// Initialize a GridPath over an n by m grid given its vertex order in
+    /// another convention, the inverse of `reoriented`
+    pub fn from_convention(n: usize, m: usize, convention: Convention, vertex_order: Vec<[usize; 2]>) -> GridPath {
+        GridPath::new(n, m, vertex_order.into_iter().map(|v| convention.to_cartesian(n, m, v)).collect())
+    }
+
+    /// Get the vertex order in screen coordinates, where y counts down
+    /// from the top row of the grid rather than up from the bottom
     ///
-    /// For example, for a 3 by 2 grid graph:
-    /// ```rust
-    /// let my_vertex_order: Vec<[usize; 2]> = vec![
-    ///     [0, 0], [0, 1], [1, 1],
-    ///     [2, 1], [2, 0], [1, 0]
-    /// ];
-    /// let my_grid_path: GridPath = GridPath::new(3, 2, my_vertex_order);
-    /// println!("{}", my_grid_graph);
-    /// ```
+    /// For example, on a 3 by 2 grid the bottom left vertex (0, 0) is
+    /// (0, 1) in screen coordinates
+    pub fn to_screen_coords(&self) -> Vec<[usize; 2]> {
+        self.reoriented(SCREEN_CONVENTION).into_vertex_order()
+    }
+
+    /// Initialize a GridPath of the given dimensions (n by m) from a vertex
+    /// order in screen coordinates, where y counts down from the top row.
+    /// A vertex outside the grid is returned as a `GridPathError::OutOfBounds`
+    /// error, in the screen coordinates given.
+    pub fn from_screen_coords(n: usize, m: usize, screen_coords: Vec<[usize; 2]>) -> Result<GridPath, GridPathError> {
+        if let Some(v) = screen_coords.iter().find(|v| v[0] >= n || v[1] >= m) {
+            return Err(GridPathError::OutOfBounds(*v));
+        }
+        Ok(GridPath::from_convention(n, m, SCREEN_CONVENTION, screen_coords))
+    }
+
+    /// Get the vertex order as (row, column) pairs, with rows counted up
+    /// from the bottom of the grid as for (x, y) coordinates
     ///
-    /// Yields the following
-    /// ```
-    /// o---o---o
-    /// |       |
-    /// o   o---o
+    /// For example, on a 3 by 2 grid the vertex (2, 1) is row 1, column 2
+    pub fn to_row_col(&self) -> Vec<[usize; 2]> {
+        self.reoriented(ROW_COL_CONVENTION).into_vertex_order()
+    }
+
+    /// Initialize a GridPath of the given dimensions (n by m) from a vertex
+    /// order given as (row, column) pairs, with rows counted up from the
+    /// bottom of the grid
+    pub fn from_row_col(n: usize, m: usize, row_col: Vec<[usize; 2]>) -> GridPath {
+        GridPath::from_convention(n, m, ROW_COL_CONVENTION, row_col)
+    }
+
+    /// Encode the GridPath as a Game of Life pattern in RLE format.
+    ///
+    /// Each vertex becomes a live cell, spaced one cell apart, and each
+    /// edge of the path fills the cell between its two vertices.  Rows
+    /// are written from the top of the grid down, as in `Display`.
+    ///
+    /// For example, the 3 by 2 path shown for `Display` yields
+    /// ```text
+    /// x = 5, y = 3, rule = B3/S23
+    /// 5o$o3bo$ob3o!
     /// ```
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    pub fn to_game_of_life_rle(&self) -> String {
+        //Mark the cells of the pattern which are alive, with each vertex
+        //at even screen coordinates and each edge between its two vertices
+        let width: usize = (2 * self.n) - 1;
+        let height: usize = (2 * self.m) - 1;
+        let screen_coords: Vec<[usize; 2]> = self.to_screen_coords();
+        let mut cells: Vec<bool> = vec![false; width * height];
+        for v_coords in screen_coords.iter() {
+            cells[GridGraph::cell_index_with_width(width, [2 * v_coords[0], 2 * v_coords[1]])] = true;
+        }
+        for i in 1..screen_coords.len() {
+            let v: [usize; 2] = screen_coords[i-1];
+            let w: [usize; 2] = screen_coords[i];
+            cells[GridGraph::cell_index_with_width(width, [v[0] + w[0], v[1] + w[1]])] = true;
+        }
+
+        //Run-length encode each row from the top down, dropping the
+        //trailing dead cells of each row
+        let mut tokens: Vec<String> = Vec::new();
+        for y in 0..height {
+            let mut runs: Vec<(usize, bool)> = Vec::new();
+            for x in 0..width {
+                let is_alive: bool = cells[GridGraph::cell_index_with_width(width, [x, y])];
+                match runs.last_mut() {
+                    Some((count, state)) if *state == is_alive => *count += 1,
+                    _ => runs.push((1, is_alive))
+                }
+            }
+            if let Some((_, false)) = runs.last() {
+                runs.pop();
+            }
+            for (count, is_alive) in runs {
+                let tag: char = if is_alive { 'o' } else { 'b' };
+                tokens.push(if count == 1 { format!("{}", tag) } else { format!("{}{}", count, tag) });
+            }
+            tokens.push(String::from(if y + 1 < height { "$" } else { "!" }));
+        }
+
+        //Write the header followed by the tokens, wrapping lines so that
+        //they do not exceed 70 characters
+        let mut rle: String = format!("x = {}, y = {}, rule = B3/S23\n", width, height);
+        let mut line_length: usize = 0;
+        for token in tokens {
+            if line_length + token.len() > 70 {
+                rle += "\n";
+                line_length = 0;
+            }
+            line_length += token.len();
+            rle += &token;
+        }
+        rle += "\n";
+        rle
+    }
+
+    /// Convert the path to a JSON object in the solution format of the
+    /// embedded schema, with the keys `format_version`, `problem` and
+    /// `path`.  The problem is in the problem format of the schema and
+    /// runs between the first and last vertices.
+    pub fn to_json_value(&self) -> JsonValue {
+        let start: [usize; 2] = self.vertex_order.first().copied().unwrap_or([0, 0]);
+        let end: [usize; 2] = self.vertex_order.last().copied().unwrap_or([0, 0]);
+        json::object!{
+            format_version: crate::FORMAT_VERSION,
+            problem: json::object!{
+                format_version: crate::FORMAT_VERSION,
+                width: self.n,
+                height: self.m,
+                start: start.to_vec(),
+                end: end.to_vec()
+            },
+            path: self.vertex_order.iter().map(|v| v.to_vec()).collect::<Vec<Vec<usize>>>()
+        }
+    }
+
+    /// Convert the path to a single-line JSON object, as in `to_json_value`
+    pub fn to_json(&self) -> String {
+        json::stringify(self.to_json_value())
+    }
+
+    /// Draw the path as in `Display`, colored with ANSI escape codes for
+    /// the terminal.  Edges are drawn in green and each vertex is shaded
+    /// by the step at which the path visits it, from blue at the first
+    /// step to red at the last, with the start and end vertices in bold.
+    pub fn to_ascii_colored(&self) -> String {
+        //Note the step at which the path visits each vertex
+        let mut steps: Vec<usize> = vec![0; self.n * self.m];
+        for (k, v) in self.vertex_order.iter().enumerate() {
+            steps[GridGraph::cell_index_with_width(self.n, *v)] = k;
+        }
+        let last_step: usize = (self.n * self.m).max(2) - 1;
+
+        //Color the plain drawing, in which the rows of vertices are every
+        //other line from the top and the vertices every fourth char
+        let plain: String = self.render(&RenderOptions::default());
+        let mut colored: String = String::from("");
+        for (l, line) in plain.lines().enumerate() {
+            if l > 0 {
+                colored += "\n";
+            }
+            let mut is_in_edge: bool = false;
+            for (col, c) in line.chars().enumerate() {
+                let is_vertex: bool = l % 2 == 0 && col % 4 == 0;
+                let is_edge: bool = c != ' ' && !is_vertex;
+
+                //Open or close a run of green edge glyphs
+                if is_edge != is_in_edge {
+                    colored += if is_edge { "\x1b[32m" } else { "\x1b[0m" };
+                    is_in_edge = is_edge;
+                }
+                if !is_vertex {
+                    colored.push(c);
+                    continue;
+                }
+
+                //Shade the vertex by its step, bolding the endpoints
+                let step: usize = steps[GridGraph::cell_index_with_width(self.n, [col / 4, self.m - 1 - (l / 2)])];
+                let red: usize = (255 * step.min(last_step) + last_step / 2) / last_step;
+                let bold: &str = if step == 0 || step + 1 == self.vertex_order.len() { "1;" } else { "" };
+                colored += &format!("\x1b[{}38;2;{};0;{}m{}\x1b[0m", bold, red, 255 - red, c);
+            }
+            if is_in_edge {
+                colored += "\x1b[0m";
+            }
+        }
+        colored
+    }
+
+    /// Draw the path as a self-contained HTML document holding a table
+    /// with a cell for each vertex, from the top of the grid down as in
+    /// `Display`.  Each cell is labelled with the step at which the path
+    /// visits it and shaded from green at the first step to red at the
+    /// last, and a wall is drawn on each side of a cell the path does not
+    /// leave it through.
+    pub fn to_html(&self) -> String {
+        //Note the step at which the path visits each vertex
+        let mut steps: Vec<usize> = vec![0; self.n * self.m];
+        for (k, v) in self.vertex_order.iter().enumerate() {
+            steps[GridGraph::cell_index_with_width(self.n, *v)] = k;
+        }
+        let last_step: usize = (self.n * self.m).max(2) - 1;
+
+        //Check whether the path runs between a vertex and its neighbor
+        let is_joined = |v: [usize; 2], w: [usize; 2]| -> bool {
+            steps[GridGraph::cell_index_with_width(self.n, v)].abs_diff(steps[GridGraph::cell_index_with_width(self.n, w)]) == 1
+        };
+
+        //Write the head of the document, with a class for each wall
+        let mut html: String = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html += &format!("<title>{}x{} grid path</title>\n", self.n, self.m);
+        html += "<style>\n";
+        html += "table { border-collapse: collapse; font-family: sans-serif; }\n";
+        html += "td { width: 2.5em; height: 2.5em; text-align: center; border: 1px dotted #bbbbbb; }\n";
+        html += "td.t { border-top: 3px solid #000000; }\n";
+        html += "td.r { border-right: 3px solid #000000; }\n";
+        html += "td.b { border-bottom: 3px solid #000000; }\n";
+        html += "td.l { border-left: 3px solid #000000; }\n";
+        html += "</style>\n</head>\n<body>\n<table>\n";
+
+        //Add a row of cells for each row of the grid from the top down
+        for y in (0..self.m).rev() {
+            html += "<tr>";
+            for x in 0..self.n {
+                //Wall off each side of the cell the path does not cross
+                let mut walls: Vec<&str> = Vec::new();
+                if y + 1 == self.m || !is_joined([x, y], [x, y + 1]) {
+                    walls.push("t");
+                }
+                if x + 1 == self.n || !is_joined([x, y], [x + 1, y]) {
+                    walls.push("r");
+                }
+                if y == 0 || !is_joined([x, y], [x, y - 1]) {
+                    walls.push("b");
+                }
+                if x == 0 || !is_joined([x, y], [x - 1, y]) {
+                    walls.push("l");
+                }
+
+                //Shade the cell by its step, with the hue running from
+                //green down to red
+                let step: usize = steps[GridGraph::cell_index_with_width(self.n, [x, y])];
+                let hue: usize = (120 * (last_step - step.min(last_step)) + last_step / 2) / last_step;
+                html += &format!(
+                    "<td class=\"{}\" style=\"background-color: hsl({}, 75%, 60%);\">{}</td>",
+                    walls.join(" "), hue, step
+                );
+            }
+            html += "</tr>\n";
+        }
+        html += "</table>\n</body>\n</html>\n";
+        html
+    }
+
+    /// Draw the path using the given glyphs
+    ///
+    /// Every vertex column is padded to the width of the widest vertex
+    /// glyph and every gap between columns is three glyph widths wide,
+    /// so multi-char glyphs keep the columns aligned
+    pub fn render(&self, options: &RenderOptions) -> String {
+        let glyphs: &GlyphSet = &options.glyphs;
+        let cell_width: usize = glyphs.get_cell_width();
+        let gap_width: usize = 3 * cell_width;
+
+        //Repeat a glyph to fill the given number of columns, padding any
+        //remainder with spaces
+        let fill = |glyph: &str, columns: usize| -> String {
+            let glyph_width: usize = GlyphSet::display_width(glyph).max(1);
+            let mut filled: String = glyph.repeat(columns / glyph_width);
+            filled += &" ".repeat(columns % glyph_width);
+            filled
+        };
+
+        //Get the glyph to draw a vertex with
+        let start: Option<&[usize; 2]> = self.vertex_order.first();
+        let end: Option<&[usize; 2]> = self.vertex_order.last();
+        let node_glyph = |v: [usize; 2]| -> &str {
+            if options.highlight.contains(&v) {
+                glyphs.highlight
+            } else if start == Some(&v) {
+                glyphs.start
+            } else if end == Some(&v) {
+                glyphs.end
+            } else {
+                glyphs.node
+            }
+        };
+
         //Initialize a string for the graph display
         let mut graph_display: String = String::from("");
 
@@ -440,37 +1093,34 @@ impl fmt::Display for GridPath {
 
             //Loop through the nodes in this row
             for j in 0..self.n {
-                //Initialize strings for the node and inter node display
-                let mut node_display: String = String::from("");
-                let mut inter_node_display: String = String::from("");
-
                 //Get the node index
-                let node_index = NodeIndexable::from_index(&self.graph, (i*self.n) + j);
+                let node_index = NodeIndexable::from_index(&self.graph, GridGraph::cell_index_with_width(self.n, [j, i]));
 
                 //Draw an edge in the left direction if node to the left
                 if j > 0 {
-                    inter_node_display += "   ";
-                    if self.graph.contains_edge(node_index, NodeIndexable::from_index(&self.graph, (i*self.n) + j - 1)) {
-                        node_display += "---o";
+                    inter_row_display += &fill(glyphs.blank, gap_width);
+                    if self.graph.contains_edge(node_index, NodeIndexable::from_index(&self.graph, GridGraph::cell_index_with_width(self.n, [j - 1, i]))) {
+                        row_display += &fill(glyphs.horizontal_edge, gap_width);
                     } else {
-                        node_display += "   o";
+                        row_display += &fill(glyphs.blank, gap_width);
                     }
-                } else {
-                    node_display += "o"
+                }
+                let node: &str = node_glyph([j, i]);
+                row_display += node;
+                if j + 1 < self.n {
+                    row_display += &" ".repeat(cell_width - GlyphSet::display_width(node).min(cell_width));
                 }
 
                 //Draw an edge in the up direction if node above
                 if i > 0 {
-                    if self.graph.contains_edge(node_index, NodeIndexable::from_index(&self.graph, ((i-1)*self.n) + j)) {
-                        inter_node_display += "|";
+                    let vertical: &str = if self.graph.contains_edge(node_index, NodeIndexable::from_index(&self.graph, GridGraph::cell_index_with_width(self.n, [j, i - 1]))) {
+                        glyphs.vertical_edge
                     } else {
-                        inter_node_display += " ";
-                    }
+                        glyphs.blank
+                    };
+                    inter_row_display += vertical;
+                    inter_row_display += &" ".repeat(cell_width - GlyphSet::display_width(vertical).min(cell_width));
                 }
-
-                //Add the node displays to the row displays
-                row_display += &node_display;
-                inter_row_display += &inter_node_display;
             }
 
             //Add the row and inter-row display to the graph display
@@ -480,14 +1130,63 @@ impl fmt::Display for GridPath {
                 graph_display += &row_display;
             }
         }
+        textnormalize::normalize_rendered(&graph_display)
+    }
+}
 
-        //Write the graph display
-        f.write_str(&graph_display)
+impl fmt::Display for GridPath {
+    /// Format a GridPath as a string
+    ///
+    /// For example, for a 3 by 2 grid graph:
+    /// ```rust
+    /// use grid_solver::gridpath::GridPath;
+    ///
+    /// let my_vertex_order: Vec<[usize; 2]> = vec![
+    ///     [0, 0], [0, 1], [1, 1],
+    ///     [2, 1], [2, 0], [1, 0]
+    /// ];
+    /// let my_grid_path: GridPath = GridPath::new(3, 2, my_vertex_order);
+    /// println!("{}", my_grid_path);
+    /// ```
+    ///
+    /// Yields the following
+    /// ```text
+    /// o---o---o
+    /// |       |
+    /// o   o---o
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(&RenderOptions::default()))
+    }
+}
+
+impl fmt::Debug for GridPath {
+    /// Format a GridPath for debugging without listing every vertex of
+    /// a long path.  Paths of more than 10 vertices show only their first
+    /// and last 5, and the alternate form adds the number of turns and
+    /// the bounding box.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is_alternate: bool = f.alternate();
+        let mut debug = f.debug_struct("GridPath");
+        debug.field("dimensions", &format_args!("{}x{}", self.n, self.m));
+        debug.field("len", &self.vertex_order.len());
+        if self.vertex_order.len() <= 10 {
+            debug.field("vertex_order", &self.vertex_order);
+        } else {
+            debug.field("head", &&self.vertex_order[..5]);
+            debug.field("tail", &&self.vertex_order[(self.vertex_order.len() - 5)..]);
+        }
+        debug.field("fingerprint", &format_args!("{:#018x}", self.fingerprint()));
+        if is_alternate {
+            debug.field("num_turns", &self.bend_points().len());
+            debug.field("bounding_box", &self.bounding_box());
+        }
+        debug.finish()
     }
 }
 
 lazy_static!{
-    static ref PRIME_SOLUTION_JSON: JsonValue = json::parse(r#"
+    pub(crate) static ref PRIME_SOLUTION_JSON: JsonValue = json::parse(r#"
     [
         {
             "n" : 2,
@@ -509,12 +1208,12 @@ lazy_static!{
             "m" : 3,
             "paths" : [
                 [ [0, 0], [1, 0], [1, 1], [1, 2], [0, 2], [0, 1] ],
-                [ [0, 0], [0, 1], [0, 2], [1, 2], [1, 1], [0, 1] ],
+                [ [0, 0], [0, 1], [0, 2], [1, 2], [1, 1], [1, 0] ],
                 [ [0, 0], [1, 0], [1, 1], [0, 1], [0, 2], [1, 2] ],
                 [ [0, 1], [0, 2], [1, 2], [1, 1], [1, 0], [0, 0] ],
                 [ [0, 1], [0, 0], [1, 0], [1, 1], [1, 2], [0, 2] ],
                 [ [0, 2], [1, 2], [1, 1], [1, 0], [0, 0], [0, 1] ],
-                [ [0, 2], [1, 2], [1, 1], [1, 0], [0, 0], [1, 0] ],
+                [ [0, 2], [1, 2], [1, 1], [0, 1], [0, 0], [1, 0] ],
                 [ [0, 2], [0, 1], [0, 0], [1, 0], [1, 1], [1, 2] ],
                 [ [1, 0], [1, 1], [1, 2], [0, 2], [0, 1], [0, 0] ],
                 [ [1, 0], [0, 0], [0, 1], [1, 1], [1, 2], [0, 2] ],
@@ -597,11 +1296,921 @@ lazy_static!{
                 [ [1, 2], [2, 2], [2, 3], [3, 3], [4, 3], [4, 2], [3, 2], [3, 1], [4, 1], [4, 0], [3, 0], [2, 0], [2, 1], [1, 1], [1, 0], [0, 0], [0, 1], [0, 2], [0, 3], [1, 3] ],
                 [ [1, 3], [0, 3], [0, 2], [0, 1], [0, 0], [1, 0], [1, 1], [2, 1], [2, 0], [3, 0], [4, 0], [4, 1], [3, 1], [3, 2], [4, 2], [4, 3], [3, 3], [2, 3], [2, 2], [1, 2] ],
                 [ [3, 0], [4, 0], [4, 1], [4, 2], [4, 3], [3, 3], [3, 2], [2, 2], [2, 3], [1, 3], [0, 3], [0, 2], [1, 2], [1, 1], [0, 1], [0, 0], [1, 0], [2, 0], [2, 1], [3, 1] ],
-                [ [3, 1], [2, 1], [2, 0], [1, 0], [0, 0], [0, 1], [1, 1], [1, 2], [0, 2], [0, 3], [1, 3], [2, 3], [2, 2], [3, 2], [3, 3], [4, 3], [4, 4], [4, 1], [4, 0], [3, 0] ],
+                [ [3, 1], [2, 1], [2, 0], [1, 0], [0, 0], [0, 1], [1, 1], [1, 2], [0, 2], [0, 3], [1, 3], [2, 3], [2, 2], [3, 2], [3, 3], [4, 3], [4, 2], [4, 1], [4, 0], [3, 0] ],
                 [ [3, 2], [2, 2], [2, 3], [1, 3], [0, 3], [0, 2], [1, 2], [1, 1], [0, 1], [0, 0], [1, 0], [2, 0], [2, 1], [3, 1], [3, 0], [4, 0], [4, 1], [4, 2], [4, 3], [3, 3] ],
                 [ [3, 3], [4, 3], [4, 2], [4, 1], [4, 0], [3, 0], [3, 1], [2, 1], [2, 0], [1, 0], [0, 0], [0, 1], [1, 1], [1, 2], [0, 2], [0, 3], [1, 3], [2, 3], [2, 2], [3, 2] ]
             ]
         }
     ]
     "#).unwrap();
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::acceptablepairs::AcceptablePairs;
+    use crate::pairoptions::PairOptions;
+    use crate::schema;
+
+    #[test]
+    fn from_problem_and_order_valid() {
+        //Initialize a 3 by 2 grid problem and a vertex order solving it
+        let my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [1, 0]);
+        let vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [0, 1], [1, 1],
+            [2, 1], [2, 0], [1, 0]
+        ];
+
+        //The grid path should take its dimensions from the problem
+        let my_grid_path: GridPath = GridPath::from_problem_and_order(&my_grid_problem, vertex_order.clone()).unwrap();
+        assert_eq!(my_grid_path.n, 3);
+        assert_eq!(my_grid_path.m, 2);
+        assert_eq!(my_grid_path.vertex_order, vertex_order);
+    }
+
+    #[test]
+    fn from_problem_and_order_wrong_length() {
+        //Initialize a 3 by 2 grid problem and a vertex order missing a vertex
+        let my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [2, 0]);
+        let vertex_order: Vec<[usize; 2]> = vec![[0, 0], [0, 1], [1, 1], [2, 1], [2, 0]];
+
+        //The vertex order should be rejected for its length
+        assert_eq!(
+            GridPath::from_problem_and_order(&my_grid_problem, vertex_order).err(),
+            Some(GridPathError::WrongLength { expected: 6, actual: 5 })
+        );
+    }
+
+    #[test]
+    fn from_problem_and_order_out_of_bounds() {
+        //Initialize a 3 by 2 grid problem and a vertex order leaving the grid
+        let my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [1, 0]);
+        let vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [0, 1], [1, 1],
+            [2, 1], [3, 1], [1, 0]
+        ];
+
+        //The vertex order should be rejected at the out of bounds vertex
+        assert_eq!(
+            GridPath::from_problem_and_order(&my_grid_problem, vertex_order).err(),
+            Some(GridPathError::OutOfBounds([3, 1]))
+        );
+    }
+
+    #[test]
+    fn from_problem_and_order_repeated_vertex() {
+        //Initialize a 3 by 2 grid problem and a vertex order which revisits
+        //a vertex in place of another
+        let my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [1, 0]);
+        let vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [0, 1], [1, 1],
+            [2, 1], [1, 1], [1, 0]
+        ];
+
+        //The vertex order should be rejected at the repeated vertex
+        assert_eq!(
+            GridPath::from_problem_and_order(&my_grid_problem, vertex_order).err(),
+            Some(GridPathError::RepeatedVertex([1, 1]))
+        );
+    }
+
+    #[test]
+    fn from_problem_and_order_non_adjacent() {
+        //Initialize a 3 by 2 grid problem and a vertex order which jumps
+        //between non-adjacent vertices
+        let my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [2, 0]);
+        let vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [0, 1], [1, 0],
+            [1, 1], [2, 1], [2, 0]
+        ];
+
+        //The vertex order should be rejected at the jump
+        assert_eq!(
+            GridPath::from_problem_and_order(&my_grid_problem, vertex_order).err(),
+            Some(GridPathError::NonAdjacent([0, 1], [1, 0]))
+        );
+    }
+
+    #[test]
+    fn from_problem_and_order_wrong_endpoints() {
+        //Initialize a 3 by 2 grid problem and a valid path which runs
+        //between different endpoints than those of the problem
+        let my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [1, 0]);
+        let vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [1, 0], [2, 0],
+            [2, 1], [1, 1], [0, 1]
+        ];
+
+        //The vertex order should be rejected for its endpoints
+        assert_eq!(
+            GridPath::from_problem_and_order(&my_grid_problem, vertex_order).err(),
+            Some(GridPathError::WrongEndpoints { expected: ([0, 0], [1, 0]), actual: ([0, 0], [0, 1]) })
+        );
+    }
+
+    #[test]
+    fn to_game_of_life_rle_small() {
+        //Initialize the 3 by 2 path from the Display example
+        let my_vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [0, 1], [1, 1],
+            [2, 1], [2, 0], [1, 0]
+        ];
+        let my_grid_path: GridPath = GridPath::new(3, 2, my_vertex_order);
+
+        //The pattern should trace the path with live cells
+        assert_eq!(
+            my_grid_path.to_game_of_life_rle(),
+            "x = 5, y = 3, rule = B3/S23\n5o$o3bo$ob3o!\n"
+        );
+    }
+
+    #[test]
+    fn to_game_of_life_rle_line_length() {
+        //Initialize a long 1 by 40 path, which has a live cell in every
+        //row and so encodes to many short tokens
+        let my_vertex_order: Vec<[usize; 2]> = (0..40).map(|y| [0, y]).collect();
+        let my_grid_path: GridPath = GridPath::new(1, 40, my_vertex_order);
+        let rle: String = my_grid_path.to_game_of_life_rle();
+
+        //No line should exceed 70 characters and the pattern should
+        //contain a live cell per row
+        assert!(rle.lines().all(|line| line.len() <= 70));
+        assert_eq!(rle.matches('o').count(), 79);
+    }
+
+    #[test]
+    fn direction_entropy_straight() {
+        //A straight path steps in only one direction, as does a single vertex
+        let my_grid_path: GridPath = GridPath::new(1, 4, vec![[0, 0], [0, 1], [0, 2], [0, 3]]);
+        assert_eq!(my_grid_path.direction_entropy(), 0.0);
+        let my_grid_path: GridPath = GridPath::new(1, 1, vec![[0, 0]]);
+        assert_eq!(my_grid_path.direction_entropy(), 0.0);
+    }
+
+    #[test]
+    fn direction_entropy_mixed() {
+        //A path stepping right, up and left once each has entropy ln(3)
+        let my_grid_path: GridPath = GridPath::new(2, 2, vec![[0, 0], [1, 0], [1, 1], [0, 1]]);
+        assert!((my_grid_path.direction_entropy() - 3.0_f64.ln()).abs() < 1e-12);
+
+        //A spiral stepping right 3 times, up once, and left and down twice
+        //each falls short of the maximum entropy ln(4)
+        let my_grid_path: GridPath = GridPath::new(3, 3, vec![
+            [1, 1], [2, 1], [2, 2], [1, 2], [0, 2],
+            [0, 1], [0, 0], [1, 0], [2, 0]
+        ]);
+        let expected: f64 = -(0.375 * 0.375_f64.ln()) - (0.125 * 0.125_f64.ln()) - (0.5 * 0.25_f64.ln());
+        assert!((my_grid_path.direction_entropy() - expected).abs() < 1e-12);
+        assert!(my_grid_path.direction_entropy() < 4.0_f64.ln());
+    }
+
+    #[test]
+    fn validate_hamiltonian() {
+        //A Hamiltonian path should satisfy every mode
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1],
+            [2, 1], [2, 0], [1, 0]
+        ]);
+        let report: ValidationReport = my_grid_path.validate(ValidationMode::Hamiltonian);
+        assert_eq!(report.coverage, 1.0);
+        assert_eq!(report.is_ok(), true);
+        assert_eq!(report.is_ok_for(ValidationMode::SimplePath), true);
+        assert_eq!(report.is_ok_for(ValidationMode::Walk), true);
+    }
+
+    #[test]
+    fn validate_simple_path() {
+        //A path over part of the grid is simple but not Hamiltonian
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![[0, 0], [1, 0], [1, 1]]);
+        let report: ValidationReport = my_grid_path.validate(ValidationMode::SimplePath);
+        assert_eq!(report.coverage, 0.5);
+        assert_eq!(report.is_ok(), true);
+        assert_eq!(report.is_ok_for(ValidationMode::Hamiltonian), false);
+        assert_eq!(report.is_ok_for(ValidationMode::Walk), true);
+    }
+
+    #[test]
+    fn validate_walk() {
+        //A walk which doubles back is not a simple path
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [1, 0], [0, 0], [0, 1], [1, 1], [1, 0]
+        ]);
+        let report: ValidationReport = my_grid_path.validate(ValidationMode::Walk);
+        assert_eq!(report.coverage, 4.0 / 6.0);
+        assert_eq!(report.repeated_vertices, vec![[0, 0], [1, 0]]);
+        assert_eq!(report.is_ok(), true);
+        assert_eq!(report.is_ok_for(ValidationMode::SimplePath), false);
+        assert_eq!(report.is_ok_for(ValidationMode::Hamiltonian), false);
+    }
+
+    #[test]
+    fn validate_adjacency_violation() {
+        //A vertex order which jumps is not even a walk
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 0], [1, 1], [2, 1], [2, 0]
+        ]);
+        let report: ValidationReport = my_grid_path.validate(ValidationMode::Walk);
+        assert_eq!(report.coverage, 1.0);
+        assert_eq!(report.first_adjacency_violation, Some(([0, 1], [1, 0])));
+        assert_eq!(report.is_ok(), false);
+        assert_eq!(report.is_ok_for(ValidationMode::Hamiltonian), false);
+    }
+
+    #[test]
+    fn validate_out_of_bounds_in_range_index() {
+        //A row of vertices running off the right of a 3 by 2 grid has the
+        //same cell indices as the whole grid, but is not a path over it
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [1, 0], [2, 0], [3, 0], [4, 0], [5, 0]
+        ]);
+        let report: ValidationReport = my_grid_path.validate(ValidationMode::Hamiltonian);
+        assert_eq!(report.coverage, 0.5);
+        assert_eq!(report.out_of_bounds_vertices, vec![[3, 0], [4, 0], [5, 0]]);
+        assert_eq!(report.is_ok(), false);
+        assert_eq!(report.is_ok_for(ValidationMode::SimplePath), false);
+        assert_eq!(report.is_ok_for(ValidationMode::Walk), false);
+    }
+
+    #[test]
+    fn validate_out_of_bounds_out_of_range_index() {
+        //A vertex far outside the grid is reported rather than panicking
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![[9, 9]]);
+        let report: ValidationReport = my_grid_path.validate(ValidationMode::Walk);
+        assert_eq!(report.coverage, 0.0);
+        assert_eq!(report.out_of_bounds_vertices, vec![[9, 9]]);
+        assert_eq!(report.is_ok(), false);
+        assert_eq!(report.is_ok_for(ValidationMode::SimplePath), false);
+        assert_eq!(report.is_ok_for(ValidationMode::Hamiltonian), false);
+    }
+
+    #[test]
+    fn vertex_at_step() {
+        //The path starts at step 0 and ends at step 5
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        assert_eq!(my_grid_path.vertex_at_step(0), Some([0, 0]));
+        assert_eq!(my_grid_path.vertex_at_step(3), Some([2, 1]));
+        assert_eq!(my_grid_path.vertex_at_step(5), Some([1, 0]));
+        assert_eq!(my_grid_path.vertex_at_step(6), None);
+    }
+
+    #[test]
+    fn into_vertex_order() {
+        //The vertex order given should be returned unchanged
+        let vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ];
+        let my_grid_path: GridPath = GridPath::new(3, 2, vertex_order.clone());
+        assert_eq!(my_grid_path.into_vertex_order(), vertex_order);
+    }
+
+    #[test]
+    fn to_json_follows_schema() {
+        //Every solution of the acceptable problems on a few small grids
+        //should serialize to a valid solution document
+        for (width, height) in [(1, 4), (3, 2), (5, 4), (7, 6)] {
+            for (start, end) in AcceptablePairs::new(width, height, PairOptions::default()) {
+                let my_grid_path: GridPath = GridProblem::new(width, height, start, end).solve().unwrap();
+                assert_eq!(schema::validate_solution_json(&my_grid_path.to_json()), Ok(()));
+            }
+        }
+
+        //The document should give back the path it was written from
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [1, 0], [2, 0], [2, 1]
+        ]);
+        assert_eq!(
+            my_grid_path.to_json(),
+            r#"{"format_version":1,"problem":{"format_version":1,"width":3,"height":2,"start":[0,0],"end":[2,1]},"path":[[0,0],[0,1],[1,1],[1,0],[2,0],[2,1]]}"#
+        );
+    }
+
+    #[test]
+    fn to_ascii_colored_matches_display() {
+        //Draw the 3 by 2 path shown for Display
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        let colored: String = my_grid_path.to_ascii_colored();
+
+        //Stripping the escape codes should give back the plain drawing
+        let mut stripped: String = String::from("");
+        let mut is_in_escape: bool = false;
+        for c in colored.chars() {
+            match c {
+                '\x1b' => is_in_escape = true,
+                'm' if is_in_escape => is_in_escape = false,
+                _ if is_in_escape => {},
+                _ => stripped.push(c)
+            }
+        }
+        assert_eq!(stripped, format!("{}", my_grid_path));
+
+        //The start is bold blue, the end bold red and the edges green
+        let lines: Vec<&str> = colored.lines().collect();
+        assert_eq!(lines[2].starts_with("\x1b[1;38;2;0;0;255mo\x1b[0m"), true);
+        assert_eq!(lines[2].contains("\x1b[1;38;2;255;0;0mo\x1b[0m"), true);
+        assert_eq!(lines[0].contains("\x1b[32m---\x1b[0m"), true);
+        assert_eq!(lines[1], "\x1b[32m|\x1b[0m       \x1b[32m|\x1b[0m");
+
+        //The vertices between are shaded without bold
+        assert_eq!(lines[0].contains("\x1b[38;2;51;0;204mo\x1b[0m"), true);
+    }
+
+    #[test]
+    fn to_html_cells() {
+        //Draw the 3 by 2 path shown for Display
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [1, 0], [2, 0], [2, 1]
+        ]);
+        let html: String = my_grid_path.to_html();
+        assert_eq!(html.starts_with("<!DOCTYPE html>\n<html>"), true);
+        assert_eq!(html.ends_with("</html>\n"), true);
+
+        //Nothing should be loaded from outside the document
+        assert_eq!(html.contains("src="), false);
+        assert_eq!(html.contains("href="), false);
+
+        //The top row is drawn first, with the first step green, the last
+        //step red and walls wherever the path does not cross
+        let rows: Vec<&str> = html.lines().filter(|line| line.starts_with("<tr>")).collect();
+        assert_eq!(rows, vec![
+            concat!(
+                "<tr><td class=\"t l\" style=\"background-color: hsl(96, 75%, 60%);\">1</td>",
+                "<td class=\"t r\" style=\"background-color: hsl(72, 75%, 60%);\">2</td>",
+                "<td class=\"t r l\" style=\"background-color: hsl(0, 75%, 60%);\">5</td></tr>"
+            ),
+            concat!(
+                "<tr><td class=\"r b l\" style=\"background-color: hsl(120, 75%, 60%);\">0</td>",
+                "<td class=\"b l\" style=\"background-color: hsl(48, 75%, 60%);\">3</td>",
+                "<td class=\"r b\" style=\"background-color: hsl(24, 75%, 60%);\">4</td></tr>"
+            )
+        ]);
+
+        //A single vertex is both the first and the last step
+        let my_grid_path: GridPath = GridPath::new(1, 1, vec![[0, 0]]);
+        assert_eq!(my_grid_path.to_html().contains("class=\"t r b l\" style=\"background-color: hsl(120, 75%, 60%);\">0</td>"), true);
+    }
+
+    #[test]
+    fn render_default_matches_display() {
+        //The default render options draw the path as its display does
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        assert_eq!(my_grid_path.render(&RenderOptions::default()), format!("{}", my_grid_path));
+        assert_eq!(my_grid_path.render(&RenderOptions::default()), "o---o---o\n|       |\no   o---o");
+    }
+
+    #[test]
+    fn render_custom_glyphs() {
+        //Render a 3 by 2 path with documentation-style glyphs
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        let options: RenderOptions = RenderOptions {
+            glyphs: GlyphSet {
+                node: "·",
+                horizontal_edge: "═",
+                vertical_edge: "║",
+                start: "S",
+                end: "E",
+                highlight: "#",
+                blank: " "
+            },
+            highlight: vec![[2, 0]]
+        };
+        let rendered: String = my_grid_path.render(&options);
+        assert_eq!(rendered, "·═══·═══·\n║       ║\nS   E═══#");
+
+        //Every line is the same number of columns wide
+        let widths: Vec<usize> = rendered.lines().map(GlyphSet::display_width).collect();
+        assert_eq!(widths, vec![9, 9, 9]);
+    }
+
+    #[test]
+    fn render_wide_glyphs_stay_aligned() {
+        //Render a 2 by 2 path whose vertex glyphs are two columns wide
+        let my_grid_path: GridPath = GridPath::new(2, 2, vec![
+            [0, 0], [0, 1], [1, 1], [1, 0]
+        ]);
+        let options: RenderOptions = RenderOptions {
+            glyphs: GlyphSet {
+                node: "()",
+                horizontal_edge: "─",
+                vertical_edge: "│",
+                start: "[]",
+                end: "<>",
+                highlight: "**",
+                blank: " "
+            },
+            highlight: Vec::new()
+        };
+        let rendered: String = my_grid_path.render(&options);
+        assert_eq!(rendered, "()──────()\n│       │\n[]      <>");
+
+        //The vertical edges sit under the left column of each vertex, with
+        //the padding after the last one trimmed
+        let widths: Vec<usize> = rendered.lines().map(GlyphSet::display_width).collect();
+        assert_eq!(widths, vec![10, 9, 10]);
+        assert_eq!(GlyphSet::UNICODE.get_cell_width(), 1);
+        assert_eq!(options.glyphs.get_cell_width(), 2);
+    }
+
+    #[test]
+    fn num_turns_at_vertex() {
+        //Initialize the 3 by 2 path shown for Display
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+
+        //The path turns at the corners it passes through, goes straight
+        //through (1, 1) and starts and ends at (0, 0) and (1, 0)
+        assert_eq!(my_grid_path.num_turns_at_vertex([0, 1]), 1);
+        assert_eq!(my_grid_path.num_turns_at_vertex([2, 1]), 1);
+        assert_eq!(my_grid_path.num_turns_at_vertex([2, 0]), 1);
+        assert_eq!(my_grid_path.num_turns_at_vertex([1, 1]), 0);
+        assert_eq!(my_grid_path.num_turns_at_vertex([0, 0]), 0);
+        assert_eq!(my_grid_path.num_turns_at_vertex([1, 0]), 0);
+
+        //A vertex off the grid is never visited
+        assert_eq!(my_grid_path.num_turns_at_vertex([5, 5]), 0);
+    }
+
+    #[test]
+    fn bend_points() {
+        //The 3 by 2 path bends at three of its corners
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        assert_eq!(my_grid_path.bend_points(), vec![[0, 1], [2, 1], [2, 0]]);
+
+        //A straight path and a single vertex have no bend points
+        let my_grid_path: GridPath = GridPath::new(4, 1, vec![[0, 0], [1, 0], [2, 0], [3, 0]]);
+        assert_eq!(my_grid_path.bend_points(), Vec::<[usize; 2]>::new());
+        let my_grid_path: GridPath = GridPath::new(1, 1, vec![[0, 0]]);
+        assert_eq!(my_grid_path.bend_points(), Vec::<[usize; 2]>::new());
+    }
+
+    #[test]
+    fn straight_through_vertices() {
+        //The 3 by 2 path goes straight through only (1, 1)
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        assert_eq!(my_grid_path.straight_through_vertices(), vec![[1, 1]]);
+
+        //Every interior vertex of a solved path is either a bend point
+        //or a straight through vertex, and never both
+        let mut my_grid_problem: GridProblem = GridProblem::new(7, 6, [0, 0], [6, 5]);
+        let my_grid_path: GridPath = my_grid_problem.solve().unwrap();
+        let bend_points: Vec<[usize; 2]> = my_grid_path.bend_points();
+        let straight_through_vertices: Vec<[usize; 2]> = my_grid_path.straight_through_vertices();
+        assert_eq!(bend_points.len() + straight_through_vertices.len(), 7 * 6 - 2);
+        for v in my_grid_path.vertex_order[1..(7 * 6 - 1)].iter() {
+            assert_eq!(bend_points.contains(v) != straight_through_vertices.contains(v), true);
+        }
+    }
+
+    #[test]
+    fn screen_coords_round_trip() {
+        //Initialize a path over a 3 by 2 grid, which is not square so
+        //that mixing up the axes changes the coordinates
+        let vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ];
+        let my_grid_path: GridPath = GridPath::new(3, 2, vertex_order.clone());
+
+        //The bottom row becomes the last screen row
+        let screen_coords: Vec<[usize; 2]> = my_grid_path.to_screen_coords();
+        assert_eq!(screen_coords, vec![
+            [0, 1], [0, 0], [1, 0], [2, 0], [2, 1], [1, 1]
+        ]);
+        assert_eq!(GridPath::from_screen_coords(3, 2, screen_coords).unwrap().vertex_order, vertex_order);
+
+        //A vertex below the bottom screen row is out of bounds rather than
+        //wrapping around
+        assert_eq!(
+            GridPath::from_screen_coords(3, 2, vec![[0, 1], [0, 2]]).map(|path| path.into_vertex_order()),
+            Err(GridPathError::OutOfBounds([0, 2]))
+        );
+        assert_eq!(
+            GridPath::from_screen_coords(3, 2, vec![[3, 0]]).map(|path| path.into_vertex_order()),
+            Err(GridPathError::OutOfBounds([3, 0]))
+        );
+    }
+
+    #[test]
+    fn row_col_round_trip() {
+        //Initialize a path over a 2 by 3 grid
+        let vertex_order: Vec<[usize; 2]> = vec![
+            [0, 0], [1, 0], [1, 1], [0, 1], [0, 2], [1, 2]
+        ];
+        let my_grid_path: GridPath = GridPath::new(2, 3, vertex_order.clone());
+
+        //Each vertex becomes its row then its column
+        let row_col: Vec<[usize; 2]> = my_grid_path.to_row_col();
+        assert_eq!(row_col, vec![
+            [0, 0], [0, 1], [1, 1], [1, 0], [2, 0], [2, 1]
+        ]);
+        assert_eq!(GridPath::from_row_col(2, 3, row_col).vertex_order, vertex_order);
+    }
+
+    #[test]
+    fn common_prefix_and_suffix_length() {
+        //Initialize two paths over a 3 by 2 grid which agree on their
+        //first two and last two vertices only
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [1, 0], [2, 0], [2, 1], [1, 1], [0, 1]
+        ]);
+        let other_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [1, 0], [1, 1], [2, 1], [2, 0]
+        ]);
+        assert_eq!(my_grid_path.common_prefix_length(&other_grid_path), 2);
+        assert_eq!(my_grid_path.common_suffix_length(&other_grid_path), 0);
+
+        //A path shares its whole length with itself
+        assert_eq!(my_grid_path.common_prefix_length(&my_grid_path), 6);
+        assert_eq!(my_grid_path.common_suffix_length(&my_grid_path), 6);
+
+        //The suffix is compared from the end of each path, even when
+        //the paths differ in length
+        let tail_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [1, 0], [1, 1], [0, 1]
+        ]);
+        assert_eq!(my_grid_path.common_suffix_length(&tail_grid_path), 2);
+        assert_eq!(tail_grid_path.common_suffix_length(&my_grid_path), 2);
+        assert_eq!(tail_grid_path.common_prefix_length(&my_grid_path), 0);
+    }
+
+    #[test]
+    fn path_segments_of_boustrophedon() {
+        //A boustrophedon path has one segment per row
+        let (n, m): (usize, usize) = (5, 4);
+        let vertex_order: Vec<[usize; 2]> = gridcore::serpentine(n, m);
+        let my_grid_path: GridPath = GridPath::new(n, m, vertex_order.clone());
+        let segments: Vec<Vec<[usize; 2]>> = my_grid_path.path_segments();
+        assert_eq!(segments.len(), m);
+        for (y, segment) in segments.iter().enumerate() {
+            assert_eq!(segment.len(), n);
+            assert_eq!(segment.iter().all(|v| v[1] == y), true);
+        }
+
+        //The segments hold every vertex once in order
+        assert_eq!(segments.concat(), vertex_order);
+    }
+
+    #[test]
+    fn path_segments_at_turns() {
+        //A new segment begins after each turn, so a staircase has
+        //segments of one vertex after its first
+        let my_grid_path: GridPath = GridPath::new(3, 3, vec![
+            [0, 0], [1, 0], [1, 1], [2, 1], [2, 2]
+        ]);
+        assert_eq!(my_grid_path.path_segments(), vec![
+            vec![[0, 0], [1, 0]], vec![[1, 1], [2, 1]], vec![[2, 2]]
+        ]);
+
+        //A single vertex is one segment and an empty path has none
+        assert_eq!(GridPath::new(1, 1, vec![[0, 0]]).path_segments(), vec![vec![[0, 0]]]);
+        assert_eq!(GridPath::new(1, 1, vec![]).path_segments().len(), 0);
+    }
+
+    #[test]
+    fn from_segments_round_trip() {
+        //The segments of a solved path merge back into the same path
+        let mut my_grid_problem: GridProblem = GridProblem::new(7, 6, [0, 0], [6, 5]);
+        let my_grid_path: GridPath = my_grid_problem.solve().unwrap();
+        let merged: GridPath = GridPath::from_segments(7, 6, my_grid_path.path_segments()).unwrap();
+        assert_eq!(merged.vertex_order, my_grid_path.vertex_order);
+
+        //Segments sharing the vertex at which they meet merge the same way
+        let shared: GridPath = GridPath::from_segments(3, 2, vec![
+            vec![[0, 0], [1, 0], [2, 0]], vec![[2, 0], [2, 1]], vec![[2, 1], [1, 1], [0, 1]]
+        ]).unwrap();
+        assert_eq!(shared.vertex_order, vec![[0, 0], [1, 0], [2, 0], [2, 1], [1, 1], [0, 1]]);
+    }
+
+    #[test]
+    fn from_segments_errors() {
+        //A segment which turns is rejected
+        assert_eq!(
+            GridPath::from_segments(2, 2, vec![vec![[0, 0], [1, 0], [1, 1]], vec![[0, 1]]]).err(),
+            Some(GridPathError::NonCollinearSegment(0))
+        );
+
+        //An empty segment is rejected
+        assert_eq!(
+            GridPath::from_segments(2, 1, vec![vec![[0, 0]], vec![], vec![[1, 0]]]).err(),
+            Some(GridPathError::EmptySegment(1))
+        );
+
+        //Segments which do not meet are rejected
+        assert_eq!(
+            GridPath::from_segments(3, 2, vec![vec![[0, 0], [1, 0], [2, 0]], vec![[0, 1], [1, 1], [2, 1]]]).err(),
+            Some(GridPathError::NonAdjacent([2, 0], [0, 1]))
+        );
+
+        //Segments which do not cover the grid are rejected
+        assert_eq!(
+            GridPath::from_segments(3, 2, vec![vec![[0, 0], [1, 0], [2, 0]]]).err(),
+            Some(GridPathError::WrongLength { expected: 6, actual: 3 })
+        );
+    }
+
+    #[test]
+    fn debug_small_path() {
+        let my_grid_path: GridPath = GridPath::new(2, 2, vec![[0, 0], [1, 0], [1, 1], [0, 1]]);
+        let fingerprint: String = format!("{:#018x}", my_grid_path.fingerprint());
+
+        //A short path lists every vertex
+        assert_eq!(
+            format!("{:?}", my_grid_path),
+            format!("GridPath {{ dimensions: 2x2, len: 4, vertex_order: [[0, 0], [1, 0], [1, 1], [0, 1]], fingerprint: {} }}", fingerprint)
+        );
+
+        //The alternate form adds the turns and bounding box
+        let pretty: String = format!("{:#?}", my_grid_path);
+        assert_eq!(pretty.starts_with("GridPath {\n    dimensions: 2x2,\n    len: 4,\n"), true);
+        assert_eq!(pretty.ends_with(&format!(
+            "    fingerprint: {},\n    num_turns: 2,\n    bounding_box: Some(\n        Rect {{\n            min: [\n                0,\n                0,\n            ],\n            max: [\n                1,\n                1,\n            ],\n        }},\n    ),\n}}",
+            fingerprint
+        )), true);
+    }
+
+    #[test]
+    fn debug_large_path() {
+        //Generate a serpentine over a 1000 by 1000 grid
+        let n: usize = 1000;
+        let vertex_order: Vec<[usize; 2]> = gridcore::serpentine(n, n);
+        let my_grid_path: GridPath = GridPath::new(n, n, vertex_order);
+
+        //Only the first and last 5 vertices are shown
+        let debug: String = format!("{:?}", my_grid_path);
+        assert_eq!(debug, format!(
+            "GridPath {{ dimensions: 1000x1000, len: 1000000, head: [[0, 0], [1, 0], [2, 0], [3, 0], [4, 0]], tail: [[4, 999], [3, 999], [2, 999], [1, 999], [0, 999]], fingerprint: {:#018x} }}",
+            my_grid_path.fingerprint()
+        ));
+        assert_eq!(debug.len() < 300, true);
+
+        //The alternate form stays bounded too
+        let pretty: String = format!("{:#?}", my_grid_path);
+        assert_eq!(pretty.contains("num_turns: 1998,"), true);
+        assert_eq!(pretty.len() < 1000, true);
+    }
+
+    #[test]
+    fn fingerprint_distinguishes_paths() {
+        let my_grid_path: GridPath = GridPath::new(2, 2, vec![[0, 0], [1, 0], [1, 1], [0, 1]]);
+        let same_grid_path: GridPath = GridPath::new(2, 2, vec![[0, 0], [1, 0], [1, 1], [0, 1]]);
+        let reversed_grid_path: GridPath = GridPath::new(2, 2, vec![[0, 1], [1, 1], [1, 0], [0, 0]]);
+        assert_eq!(my_grid_path.fingerprint(), same_grid_path.fingerprint());
+        assert_eq!(my_grid_path.fingerprint() == reversed_grid_path.fingerprint(), false);
+    }
+
+    #[test]
+    fn rle_of_boustrophedon() {
+        //A boustrophedon path encodes to two runs per row
+        let (n, m): (usize, usize) = (6, 4);
+        let vertex_order: Vec<[usize; 2]> = gridcore::serpentine(n, m);
+        let my_grid_path: GridPath = GridPath::new(n, m, vertex_order.clone());
+        assert_eq!(my_grid_path.to_rle(), "5R1U5L1U5R1U5L");
+        assert_eq!(GridPath::from_rle([0, 0], "5R1U5L1U5R1U5L", n, m).unwrap().vertex_order, vertex_order);
+
+        //A single vertex has no runs
+        assert_eq!(GridPath::new(1, 1, vec![[0, 0]]).to_rle(), "");
+        assert_eq!(GridPath::from_rle([0, 0], "", 1, 1).unwrap().vertex_order, vec![[0, 0]]);
+    }
+
+    #[test]
+    fn rle_round_trip() {
+        //Solved paths round trip through their encoding
+        for (n, m, start, end) in [(7, 6, [0, 0], [6, 5]), (9, 9, [4, 4], [8, 8]), (4, 5, [1, 2], [3, 3])] {
+            let mut my_grid_problem: GridProblem = GridProblem::new(n, m, start, end);
+            let my_grid_path: GridPath = my_grid_problem.solve().unwrap();
+            let decoded: GridPath = GridPath::from_rle(start, &my_grid_path.to_rle(), n, m).unwrap();
+            assert_eq!(decoded.vertex_order, my_grid_path.vertex_order);
+        }
+    }
+
+    #[test]
+    fn rle_errors() {
+        //Malformed runs
+        assert_eq!(GridPath::from_rle([0, 0], "R1U", 2, 2).err(), Some(PathParseError::InvalidRun(String::from("R"))));
+        assert_eq!(GridPath::from_rle([0, 0], "0R", 2, 2).err(), Some(PathParseError::InvalidRun(String::from("0R"))));
+        assert_eq!(GridPath::from_rle([0, 0], "1X", 2, 2).err(), Some(PathParseError::InvalidRun(String::from("1X"))));
+        assert_eq!(GridPath::from_rle([0, 0], "1R1U1", 2, 2).err(), Some(PathParseError::InvalidRun(String::from("1"))));
+
+        //Runs which leave the grid
+        assert_eq!(GridPath::from_rle([0, 0], "2R", 2, 1).err(), Some(PathParseError::RunOutOfBounds(String::from("2R"))));
+        assert_eq!(GridPath::from_rle([0, 0], "1D", 2, 2).err(), Some(PathParseError::RunOutOfBounds(String::from("1D"))));
+
+        //Runs which do not form a Hamiltonian path
+        assert_eq!(
+            GridPath::from_rle([0, 0], "1R", 2, 2).err(),
+            Some(PathParseError::InvalidPath(GridPathError::WrongLength { expected: 4, actual: 2 }))
+        );
+    }
+
+    #[test]
+    fn reoriented_matrix() {
+        //Initialize a path over a 3 by 2 grid and reorient it to matrix
+        //coordinates by hand
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [1, 0], [2, 0], [2, 1]
+        ]);
+        let matrix_path: GridPath = my_grid_path.reoriented(Convention::MATRIX);
+        assert_eq!((matrix_path.n, matrix_path.m), (2, 3));
+        assert_eq!(matrix_path.vertex_order, vec![
+            [1, 0], [0, 0], [0, 1], [1, 1], [1, 2], [0, 2]
+        ]);
+
+        //It round trips back to the crate's convention
+        let round_trip: GridPath = GridPath::from_convention(3, 2, Convention::MATRIX, matrix_path.into_vertex_order());
+        assert_eq!(round_trip.vertex_order, my_grid_path.vertex_order);
+        assert_eq!(my_grid_path.reoriented(Convention::CARTESIAN).vertex_order, my_grid_path.vertex_order);
+    }
+
+    #[test]
+    fn reoriented_agrees_with_screen_and_row_col() {
+        //The screen and row column coordinates are particular conventions
+        let mut my_grid_problem: GridProblem = GridProblem::new(5, 4, [0, 0], [4, 1]);
+        let my_grid_path: GridPath = my_grid_problem.solve().unwrap();
+        let screen: Convention = Convention { origin: Corner::TopLeft, axis_order: AxisOrder::ColumnRow };
+        let row_col: Convention = Convention { origin: Corner::BottomLeft, axis_order: AxisOrder::RowColumn };
+        assert_eq!(my_grid_path.reoriented(screen).vertex_order, my_grid_path.to_screen_coords());
+        assert_eq!(my_grid_path.reoriented(row_col).vertex_order, my_grid_path.to_row_col());
+
+        //The reoriented path is still a Hamiltonian path over its grid
+        let matrix_path: GridPath = my_grid_path.reoriented(Convention::MATRIX);
+        assert_eq!(GridPath::validate_vertex_order(4, 5, &matrix_path.vertex_order), Ok(()));
+    }
+
+    #[test]
+    fn extend_up_then_down() {
+        //Initialize a 3 by 3 prime path with edges on both the upper and
+        //lower boundaries
+        let mut my_grid_path: GridPath = GridPath::new(3, 3, vec![
+            [0, 0], [1, 0], [2, 0], [2, 1], [1, 1], [0, 1], [0, 2], [1, 2], [2, 2]
+        ]);
+
+        //Extending up adds a height-2 strip above the path
+        my_grid_path.extend_up();
+        assert_eq!((my_grid_path.n, my_grid_path.m), (3, 5));
+        assert_eq!(GridPath::validate_vertex_order(3, 5, &my_grid_path.vertex_order), Ok(()));
+
+        //Extending down adds a height-2 strip below, shifting the path up
+        my_grid_path.extend_down();
+        assert_eq!((my_grid_path.n, my_grid_path.m), (3, 7));
+        assert_eq!(GridPath::validate_vertex_order(3, 7, &my_grid_path.vertex_order), Ok(()));
+
+        //Both endpoints are untouched by the strip above and shifted up by
+        //the strip below
+        assert_eq!(my_grid_path.vertex_order.first(), Some(&[0, 2]));
+        assert_eq!(my_grid_path.vertex_order.last(), Some(&[2, 4]));
+    }
+
+    #[test]
+    fn display_exact_ascii() {
+        //Pin the example given in the Display documentation
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        assert_eq!(format!("{}", my_grid_path), "o---o---o\n|       |\no   o---o");
+    }
+
+    #[test]
+    fn is_prime_agrees_with_get_prime() {
+        //For every grid of up to 5 by 5 and every pair of its vertices, a
+        //prime solution is found exactly when one is said to exist, and it
+        //runs from the start to the end
+        for n in 1..=5 {
+            for m in 1..=5 {
+                for start in GridGraph::cells_with_dimensions(n, m) {
+                    for end in GridGraph::cells_with_dimensions(n, m) {
+                        let prime: Option<GridPath> = GridPath::get_prime(n, m, start, end);
+                        assert_eq!(GridPath::is_prime(n, m, start, end), prime.is_some());
+                        if let Some(path) = prime {
+                            assert_eq!((path.n, path.m), (n, m));
+                            assert_eq!(path.vertex_order.first(), Some(&start));
+                            assert_eq!(path.vertex_order.last(), Some(&end));
+                            assert_eq!(GridPath::validate_vertex_order(n, m, &path.vertex_order), Ok(()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn prime_json_parses() {
+        //Force the lazy initialization, which panics on malformed JSON
+        let prime_solution_json_ref = &*PRIME_SOLUTION_JSON;
+        assert_eq!(prime_solution_json_ref.is_array(), true);
+        assert_eq!(prime_solution_json_ref.is_empty(), false);
+    }
+
+    #[test]
+    fn prime_problems_file_matches_inline_table() {
+        //The standalone copy of the prime table must not drift from the one
+        //the solver actually uses
+        let prime_problems_file: JsonValue = json::parse(include_str!("prime-problems.json")).unwrap();
+        assert_eq!(prime_problems_file, *PRIME_SOLUTION_JSON);
+    }
+
+    #[test]
+    fn get_graph_holds_path_edges() {
+        //The petgraph graph of a path over a 3 by 2 grid has every vertex
+        //but only the 5 edges of the path
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        let graph: &Graph<String, String, Undirected> = my_grid_path.get_graph();
+        assert_eq!(graph.node_count(), 6);
+        assert_eq!(graph.edge_count(), 5);
+        let node = |v: [usize; 2]| NodeIndexable::from_index(graph, GridGraph::cell_index_with_width(3, v));
+        assert_eq!(graph.contains_edge(node([0, 0]), node([0, 1])), true);
+        assert_eq!(graph.contains_edge(node([0, 0]), node([1, 0])), false);
+    }
+
+    #[test]
+    fn shared_boundary_edges() {
+        //Initialize the 3 by 2 path shown for Display
+        let my_grid_path: GridPath = GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]
+        ]);
+        assert_eq!(my_grid_path.shared_boundary_edges(GridExtension::Up), vec![([0, 1], [1, 1]), ([1, 1], [2, 1])]);
+        assert_eq!(my_grid_path.shared_boundary_edges(GridExtension::Down), vec![([2, 0], [1, 0])]);
+        assert_eq!(my_grid_path.shared_boundary_edges(GridExtension::Left), vec![([0, 0], [0, 1])]);
+        assert_eq!(my_grid_path.shared_boundary_edges(GridExtension::Right), vec![([2, 1], [2, 0])]);
+
+        //A path running up and down the columns of a 3 by 3 grid has a
+        //single edge on each of its upper and lower boundaries
+        let my_grid_path: GridPath = GridPath::new(3, 3, vec![
+            [0, 0], [0, 1], [0, 2], [1, 2], [1, 1], [1, 0], [2, 0], [2, 1], [2, 2]
+        ]);
+        assert_eq!(my_grid_path.shared_boundary_edges(GridExtension::Left).len(), 2);
+        assert_eq!(my_grid_path.shared_boundary_edges(GridExtension::Up), vec![([0, 2], [1, 2])]);
+        assert_eq!(my_grid_path.shared_boundary_edges(GridExtension::Down), vec![([1, 0], [2, 0])]);
+    }
+
+    #[test]
+    fn from_petgraph_round_trip() {
+        //Build a petgraph path graph from a path over a 3 by 2 grid, adding
+        //its nodes in row-major order rather than path order
+        let vertex_order: Vec<[usize; 2]> = vec![[0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]];
+        let mut graph: Graph<[usize; 2], (), Undirected> = Graph::new_undirected();
+        let nodes: Vec<NodeIndex> = GridGraph::cells_with_dimensions(3, 2).map(|v| graph.add_node(v)).collect();
+        let node = |v: [usize; 2]| nodes[GridGraph::cell_index_with_width(3, v)];
+        for edge in vertex_order.windows(2) {
+            graph.add_edge(node(edge[0]), node(edge[1]), ());
+        }
+
+        //The path is walked from the first node of degree 1
+        let my_grid_path: GridPath = GridPath::from_petgraph(3, 2, &graph).unwrap();
+        assert_eq!(my_grid_path.vertex_order, vertex_order);
+
+        //A single vertex is a path without edges
+        let mut graph: Graph<[usize; 2], (), Undirected> = Graph::new_undirected();
+        graph.add_node([0, 0]);
+        assert_eq!(GridPath::from_petgraph(1, 1, &graph).unwrap().vertex_order, vec![[0, 0]]);
+    }
+
+    #[test]
+    fn from_petgraph_errors() {
+        //Close a path over a 2 by 2 grid into a cycle
+        let mut graph: Graph<[usize; 2], (), Undirected> = Graph::new_undirected();
+        let nodes: Vec<NodeIndex> = [[0, 0], [1, 0], [1, 1], [0, 1]].into_iter().map(|v| graph.add_node(v)).collect();
+        for i in 0..4 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+        }
+        assert_eq!(GridPath::from_petgraph(2, 2, &graph).unwrap_err(), GridPathError::WrongNumEndpoints(0));
+
+        //A path and a separate cycle over a 3 by 2 grid, whose degrees are
+        //all valid
+        let mut graph: Graph<[usize; 2], (), Undirected> = Graph::new_undirected();
+        let nodes: Vec<NodeIndex> = GridGraph::cells_with_dimensions(3, 2).map(|v| graph.add_node(v)).collect();
+        let node = |v: [usize; 2]| nodes[GridGraph::cell_index_with_width(3, v)];
+        graph.add_edge(node([0, 0]), node([0, 1]), ());
+        for (v, w) in [([1, 0], [2, 0]), ([2, 0], [2, 1]), ([2, 1], [1, 1]), ([1, 1], [1, 0])] {
+            graph.add_edge(node(v), node(w), ());
+        }
+        assert_eq!(GridPath::from_petgraph(3, 2, &graph).unwrap_err(), GridPathError::Disconnected { reached: 2, total: 6 });
+
+        //Branching, wrongly sized and non-adjacent graphs
+        let mut graph: Graph<[usize; 2], (), Undirected> = Graph::new_undirected();
+        let nodes: Vec<NodeIndex> = [[0, 0], [1, 0], [1, 1], [0, 1]].into_iter().map(|v| graph.add_node(v)).collect();
+        for i in 1..4 {
+            graph.add_edge(nodes[0], nodes[i], ());
+        }
+        assert_eq!(GridPath::from_petgraph(2, 2, &graph).unwrap_err(), GridPathError::InvalidDegree([0, 0], 3));
+        assert_eq!(GridPath::from_petgraph(3, 2, &graph).unwrap_err(), GridPathError::WrongLength { expected: 6, actual: 4 });
+        let mut graph: Graph<[usize; 2], (), Undirected> = Graph::new_undirected();
+        let nodes: Vec<NodeIndex> = [[0, 0], [1, 1], [1, 0], [0, 1]].into_iter().map(|v| graph.add_node(v)).collect();
+        for i in 0..3 {
+            graph.add_edge(nodes[i], nodes[i + 1], ());
+        }
+        assert_eq!(GridPath::from_petgraph(2, 2, &graph).unwrap_err(), GridPathError::NonAdjacent([0, 0], [1, 1]));
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+use std::error::Error;
+
+/// # GridPathError enum
+///
+/// A `GridPathError` describes why a vertex order does not form a
+/// Hamiltonian path over an n by m grid graph.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridPathError {
+    WrongLength { expected: usize, actual: usize },
+    OutOfBounds([usize; 2]),
+    RepeatedVertex([usize; 2]),
+    NonAdjacent([usize; 2], [usize; 2]),
+    WrongEndpoints { expected: ([usize; 2], [usize; 2]), actual: ([usize; 2], [usize; 2]) },
+    EmptySegment(usize),
+    NonCollinearSegment(usize),
+    InvalidDegree([usize; 2], usize),
+    WrongNumEndpoints(usize),
+    Disconnected { reached: usize, total: usize }
+}
+
+impl fmt::Display for GridPathError {
+    /// Format a GridPathError as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridPathError::WrongLength { expected, actual } => write!(
+                f, "Vertex order has {} vertices, expected {}",
+                actual, expected
+            ),
+            GridPathError::OutOfBounds(v) => write!(
+                f, "Vertex out of bounds: ({}, {})",
+                v[0], v[1]
+            ),
+            GridPathError::RepeatedVertex(v) => write!(
+                f, "Vertex visited more than once: ({}, {})",
+                v[0], v[1]
+            ),
+            GridPathError::NonAdjacent(v, w) => write!(
+                f, "Consecutive vertices are not adjacent: ({}, {}), ({}, {})",
+                v[0], v[1], w[0], w[1]
+            ),
+            GridPathError::WrongEndpoints { expected, actual } => write!(
+                f, "Path runs from ({}, {}) to ({}, {}), expected ({}, {}) to ({}, {})",
+                actual.0[0], actual.0[1], actual.1[0], actual.1[1],
+                expected.0[0], expected.0[1], expected.1[0], expected.1[1]
+            ),
+            GridPathError::EmptySegment(i) => write!(
+                f, "Segment {} has no vertices",
+                i
+            ),
+            GridPathError::NonCollinearSegment(i) => write!(
+                f, "Segment {} does not run in a straight line",
+                i
+            ),
+            GridPathError::InvalidDegree(v, degree) => write!(
+                f, "Vertex ({}, {}) has degree {}, expected 1 or 2",
+                v[0], v[1], degree
+            ),
+            GridPathError::WrongNumEndpoints(num_endpoints) => write!(
+                f, "Path graph has {} vertices of degree 1, expected 2",
+                num_endpoints
+            ),
+            GridPathError::Disconnected { reached, total } => write!(
+                f, "Path graph is disconnected, only {} of its {} vertices are reached from an end",
+                reached, total
+            )
+        }
+    }
+}
+
+impl Error for GridPathError {}
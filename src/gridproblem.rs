@@ -1,7 +1,103 @@
 use std::process;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::buildinfo;
 use crate::gridgraph::GridGraph;
 use crate::gridpath::GridPath;
+use crate::gridpatherror::GridPathError;
+use crate::colorrelation::ColorRelation;
 use crate::gridextension::GridExtension;
+use crate::griderror::GridError;
+use crate::parity::Parity;
+use crate::pathparseerror::PathParseError;
+use crate::solveestimate::SolveEstimate;
+use crate::solvestats::SolveStats;
+use crate::solveerror::SolveError;
+use crate::solveoptions::SolveOptions;
+use crate::gridrng::GridRng;
+use crate::progressevent::ProgressEvent;
+use crate::rect::Rect;
+use crate::solveobserver::SolveObserver;
+use crate::streamingvalidator::StreamingValidator;
+use crate::validate;
+
+/// The states reached while sweeping across a 3 wide grid, mapped to the
+/// previous state and whether edges were taken to the right and above
+type SweepStates = BTreeMap<[usize; 4], ([usize; 4], bool, bool)>;
+
+/// The state threaded through the recursive calls of a solve
+struct SolveContext<'a> {
+    rng: Option<&'a mut GridRng>,
+    observer: Option<&'a mut dyn SolveObserver>,
+    cells_completed: usize,
+    cells_total: usize,
+    depth: usize,
+    paranoid: bool,
+    violation: Option<SolveError>,
+    num_split_candidates: usize,
+    num_split_probes: usize
+}
+
+/// Reconstructs the grid problem it borrows when dropped, so that a solve
+/// unwinding out of a stripped problem leaves it as it was given
+struct StripGuard<'a> {
+    problem: &'a mut GridProblem
+}
+
+impl Drop for StripGuard<'_> {
+    fn drop(&mut self) {
+        //The error is ignored as the problem is restored either way
+        let _ = self.problem.reconstruct();
+    }
+}
+
+impl<'a> SolveContext<'a> {
+    /// Initialize the context of a solve covering the given number of
+    /// vertices, without a random number generator or observer and with
+    /// nothing completed or checked yet
+    fn new(cells_total: usize) -> SolveContext<'a> {
+        SolveContext {
+            rng: None,
+            observer: None,
+            cells_completed: 0,
+            cells_total: cells_total,
+            depth: 0,
+            paranoid: false,
+            violation: None,
+            num_split_candidates: 0,
+            num_split_probes: 0
+        }
+    }
+
+    /// Record an error which stopped the solve, keeping only the first so
+    /// that it can be returned once the recursive calls have unwound
+    fn record(&mut self, error: SolveError) {
+        if self.violation.is_none() {
+            self.violation = Some(error);
+        }
+    }
+
+    /// In paranoid mode, check that an intermediate path of the given
+    /// stage of solving a width by height sub-problem is a Hamiltonian
+    /// path over its grid, between the given endpoints if any, noting the
+    /// first which is not
+    fn check(&mut self, path: &GridPath, endpoints: Option<([usize; 2], [usize; 2])>, width: usize, height: usize, stage: impl FnOnce() -> String) {
+        if !self.paranoid || self.violation.is_some() {
+            return;
+        }
+        if let Err(e) = path.check_hamiltonian(endpoints) {
+            self.violation = Some(SolveError::InvalidIntermediate {
+                stage: stage(),
+                width: width,
+                height: height,
+                error: e
+            });
+        }
+    }
+}
 
 /// # GridProblem struct
 ///
@@ -17,24 +113,34 @@ pub struct GridProblem {
     grid_graph: GridGraph,
     extensions: Vec<GridExtension>,
     start_coords: [usize; 2],
-    end_coords: [usize; 2]
+    end_coords: [usize; 2],
+    original_width: usize,
+    original_height: usize,
+    original_start_coords: [usize; 2],
+    original_end_coords: [usize; 2]
 }
 
 impl GridProblem {
     /// Initialize a `GridProblem` given grid dimensions and
     /// start and end vertex coordinates.
     pub fn new(width: usize, height: usize, start_coords: [usize; 2], end_coords: [usize; 2]) -> GridProblem {
-        //Sanity check the grid graph coordinates against the given
-        //start and end vertex coordinates
-        if start_coords[0] >= width || end_coords[0] >= width ||
-           start_coords[1] >= height || end_coords[1] >= height {
-            eprintln!(
-                "Vertex coordinates out of bounds of {} x {}: ({}, {}), ({}, {})",
-                width, height, start_coords[0], start_coords[1],
-                end_coords[0], end_coords[1]
-            );
-            process::exit(1);
+        //Sanity check the grid dimensions and the start and end
+        //vertex coordinates
+        match GridProblem::try_new(width, height, start_coords, end_coords) {
+            Ok(x) => x,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
         }
+    }
+
+    /// Initialize a `GridProblem` given grid dimensions and start and
+    /// end vertex coordinates, or get a `GridError` describing why they
+    /// do not form a grid problem.
+    pub fn try_new(width: usize, height: usize, start_coords: [usize; 2], end_coords: [usize; 2]) -> Result<GridProblem, GridError> {
+        //Check the grid dimensions and the start and end vertex coordinates
+        validate::validate_problem(width, height, start_coords, end_coords)?;
 
         //Initialize a new grid graph
         let grid_graph: GridGraph = GridGraph::new(width, height);
@@ -43,16 +149,137 @@ impl GridProblem {
         let grid_extensions: Vec<GridExtension> = Vec::new();
 
         //Initialize the grid problem
-        GridProblem {
+        Ok(GridProblem {
             grid_graph: grid_graph,
             extensions: grid_extensions,
             start_coords: start_coords,
-            end_coords: end_coords
+            end_coords: end_coords,
+            original_width: width,
+            original_height: height,
+            original_start_coords: start_coords,
+            original_end_coords: end_coords
+        })
+    }
+
+    /// Initialize a `GridProblem` whose start and end vertices lie next
+    /// to each other on one edge of the grid, so that a path covering
+    /// the grid exits where it entered.  The start vertex is the vertex
+    /// at the given position along the edge, counted from the origin,
+    /// and the end vertex is the next vertex along the edge, or the
+    /// previous one if the start vertex is at the far end of the edge.
+    ///
+    /// If the grid has an odd number of vertices then adjacent vertices
+    /// are never color compatible, so instead:
+    /// - if the vertex at the given position is of the minority color,
+    ///   the start vertex moves one vertex along the edge, and
+    /// - the end vertex is two vertices along the edge from the start
+    ///   vertex, or two back if there is no room.
+    pub fn entry_exit_on_edge(width: usize, height: usize, edge: GridExtension, position: usize) -> Result<GridProblem, GridError> {
+        //Check the grid dimensions, then determine the length of the edge
+        //and the coordinates of the vertex at a position along it
+        validate::validate_dimensions(width, height)?;
+        let length: usize = match edge {
+            GridExtension::Right | GridExtension::Left => height,
+            GridExtension::Up | GridExtension::Down => width
+        };
+        let coords_at = |k: usize| match edge {
+            GridExtension::Right => [width - 1, k],
+            GridExtension::Up    => [k, height - 1],
+            GridExtension::Left  => [0, k],
+            GridExtension::Down  => [k, 0]
+        };
+        if position >= length {
+            return Err(GridError::PositionOutOfBounds { position: position, length: length });
+        }
+
+        //Determine how far apart the endpoints must be along the edge and
+        //check that the edge can hold them
+        let is_odd: bool = ((width * height) & 1) == 1;
+        let gap: usize = if is_odd { 2 } else { 1 };
+        if length <= gap {
+            return Err(GridError::EdgeTooShort { length: length });
+        }
+
+        //Choose the start position, moving one vertex along the edge if
+        //the grid is odd and the vertex at the position is of the minority
+        //color, then place the end position after it or before it
+        let mut start_position: usize = position;
+        if is_odd && (coords_at(start_position)[0] + coords_at(start_position)[1]) & 1 == 1 {
+            start_position = if start_position + 1 < length { start_position + 1 } else { start_position - 1 };
+        }
+        let end_position: usize = if start_position + gap < length { start_position + gap } else { start_position - gap };
+
+        //Check that the resulting problem is acceptable
+        let grid_problem: GridProblem = GridProblem::new(width, height, coords_at(start_position), coords_at(end_position));
+        if !grid_problem.is_acceptable() {
+            return Err(GridError::NotAcceptable {
+                start_coords: coords_at(start_position),
+                end_coords: coords_at(end_position)
+            });
         }
+        Ok(grid_problem)
+    }
+
+    /// Get the width of the grid problem's grid graph
+    pub fn get_width(&self) -> usize {
+        self.grid_graph.get_width()
+    }
+
+    /// Get the height of the grid problem's grid graph
+    pub fn get_height(&self) -> usize {
+        self.grid_graph.get_height()
+    }
+
+    /// Get the start vertex coordinates of the grid problem
+    pub fn get_start_coords(&self) -> [usize; 2] {
+        self.start_coords
+    }
+
+    /// Get the end vertex coordinates of the grid problem
+    pub fn get_end_coords(&self) -> [usize; 2] {
+        self.end_coords
+    }
+
+    /// Get a reference to the grid graph of the grid problem, which is
+    /// smaller than the original grid once the problem has been stripped
+    pub fn get_grid_graph(&self) -> &GridGraph {
+        &self.grid_graph
+    }
+
+    /// Consume the grid problem and get its grid graph, the extensions
+    /// stripped from it so far, and its start and end vertex coordinates
+    pub fn into_parts(self) -> (GridGraph, Vec<GridExtension>, [usize; 2], [usize; 2]) {
+        (self.grid_graph, self.extensions, self.start_coords, self.end_coords)
+    }
+
+    /// Get the color of the start vertex, the parity of its coordinates' sum
+    pub fn start_color(&self) -> Parity {
+        Parity::color_of(self.start_coords)
+    }
+
+    /// Get the color of the end vertex, the parity of its coordinates' sum
+    pub fn end_color(&self) -> Parity {
+        Parity::color_of(self.end_coords)
+    }
+
+    /// Get the parity of the number of vertices in the grid graph
+    pub fn grid_parity(&self) -> Parity {
+        Parity::of(self.grid_graph.get_width() * self.grid_graph.get_height())
+    }
+
+    /// Get the relation the colors of the endpoints must satisfy for the
+    /// problem to be color compatible
+    pub fn required_relation(&self) -> ColorRelation {
+        ColorRelation::for_grid_parity(self.grid_parity())
     }
 
-    /// Check if the grid problem is acceptable
+    /// Check if the grid problem is acceptable.  A problem whose start and
+    /// end coincide is acceptable only on a 1 by 1 grid, where the path is
+    /// the single vertex.
     pub fn is_acceptable(&self) -> bool {
+        if self.start_coords == self.end_coords {
+            return self.grid_graph.get_width() * self.grid_graph.get_height() == 1;
+        }
         let are_color_compatible: bool = self.grid_graph.are_color_compatible(self.start_coords, self.end_coords);
         let is_forbidden: bool = self.grid_graph.is_forbidden(self.start_coords, self.end_coords);
         if are_color_compatible && !is_forbidden {
@@ -61,6 +288,215 @@ impl GridProblem {
         return false;
     }
 
+    /// Check if the problem over a width by height grid between the given
+    /// endpoints is acceptable, as in `is_acceptable`
+    fn is_acceptable_with_dimensions(width: usize, height: usize, start_coords: [usize; 2], end_coords: [usize; 2]) -> bool {
+        (start_coords != end_coords || width * height == 1) &&
+            GridGraph::are_color_compatible_with_dimensions(width, height, start_coords, end_coords) &&
+            !GridGraph::is_forbidden_with_dimensions(width, height, start_coords, end_coords)
+    }
+
+    /// Suggest acceptable alternatives to the grid problem's endpoints.
+    /// Each suggestion is a (start, end) pair whose total Manhattan
+    /// distance from the original endpoints is at most `max_distance`,
+    /// given along with that distance.  Suggestions are sorted by
+    /// distance, then row-major by start and then by end vertex.
+    pub fn suggest_endpoints(&self, max_distance: usize) -> Vec<([usize; 2], [usize; 2], usize)> {
+        GridProblem::suggest_endpoints_with_dimensions(
+            self.grid_graph.get_width(),
+            self.grid_graph.get_height(),
+            self.start_coords,
+            self.end_coords,
+            max_distance
+        )
+    }
+
+    /// Suggest acceptable endpoints near the given endpoints of a width by
+    /// height grid, as in `suggest_endpoints`
+    fn suggest_endpoints_with_dimensions(width: usize, height: usize, start_coords: [usize; 2], end_coords: [usize; 2], max_distance: usize) -> Vec<([usize; 2], [usize; 2], usize)> {
+        //Get the vertices within the given distance of a vertex, row by row,
+        //along with their distances from it
+        let nearby = |v_coords: [usize; 2], distance: usize| -> Vec<([usize; 2], usize)> {
+            let mut vertices: Vec<([usize; 2], usize)> = Vec::new();
+            for y in v_coords[1].saturating_sub(distance)..=v_coords[1].saturating_add(distance).min(height - 1) {
+                for x in v_coords[0].saturating_sub(distance)..=v_coords[0].saturating_add(distance).min(width - 1) {
+                    let d: usize = x.abs_diff(v_coords[0]) + y.abs_diff(v_coords[1]);
+                    if d <= distance {
+                        vertices.push(([x, y], d));
+                    }
+                }
+            }
+            vertices
+        };
+
+        //Collect every acceptable pair within the distance of the endpoints
+        let mut suggestions: Vec<([usize; 2], [usize; 2], usize)> = Vec::new();
+        for (start, start_distance) in nearby(start_coords, max_distance) {
+            for (end, end_distance) in nearby(end_coords, max_distance - start_distance) {
+                if GridProblem::is_acceptable_with_dimensions(width, height, start, end) {
+                    suggestions.push((start, end, start_distance + end_distance));
+                }
+            }
+        }
+
+        //Sort by distance, keeping the row-major order within each distance
+        suggestions.sort_by_key(|suggestion| suggestion.2);
+        suggestions
+    }
+
+    /// Get the acceptable pair of endpoints of a width by height grid
+    /// nearest the approximate endpoints given, such as cells clicked in
+    /// a UI.  Approximate endpoints outside the grid are first moved onto
+    /// its nearest vertex.  The pair returned minimizes the total Manhattan
+    /// displacement from the approximate endpoints, with ties broken as in
+    /// `suggest_endpoints`, row-major by start and then by end vertex.
+    /// Every grid with at least one vertex has such a pair, so an error is
+    /// only returned for grids with invalid dimensions.
+    pub fn snap_endpoints(width: usize, height: usize, approx_start: [usize; 2], approx_end: [usize; 2]) -> Result<([usize; 2], [usize; 2]), GridError> {
+        validate::validate_dimensions(width, height)?;
+        let clamp = |v_coords: [usize; 2]| -> [usize; 2] {
+            [v_coords[0].min(width - 1), v_coords[1].min(height - 1)]
+        };
+        let start_coords: [usize; 2] = clamp(approx_start);
+        let end_coords: [usize; 2] = clamp(approx_end);
+
+        let displacement = |start: [usize; 2], end: [usize; 2]| -> usize {
+            start[0].abs_diff(start_coords[0]) + start[1].abs_diff(start_coords[1]) +
+                end[0].abs_diff(end_coords[0]) + end[1].abs_diff(end_coords[1])
+        };
+
+        //The only acceptable endpoints of a 1 wide grid are its two ends,
+        //so take them in whichever order is nearer
+        if width == 1 || height == 1 {
+            let far_coords: [usize; 2] = [width - 1, height - 1];
+            if displacement(far_coords, [0, 0]) < displacement([0, 0], far_coords) {
+                return Ok((far_coords, [0, 0]));
+            }
+            return Ok(([0, 0], far_coords));
+        }
+
+        //Otherwise scan the pairs by their total displacement, each start
+        //and end vertex at exactly its share of it, and take the least
+        //pair row-major by start and then by end at the first displacement
+        //with any, up to the largest total displacement within the grid
+        let row_major = |pair: ([usize; 2], [usize; 2])| -> [usize; 4] {
+            [pair.0[1], pair.0[0], pair.1[1], pair.1[0]]
+        };
+        for distance in 0..=(2 * (width + height - 2)) {
+            let mut nearest: Option<([usize; 2], [usize; 2])> = None;
+            for start_distance in 0..=distance {
+                for start in GridProblem::vertices_at_distance(width, height, start_coords, start_distance) {
+                    for end in GridProblem::vertices_at_distance(width, height, end_coords, distance - start_distance) {
+                        let is_nearer: bool = match nearest {
+                            Some(pair) => row_major((start, end)) < row_major(pair),
+                            None => true
+                        };
+                        if is_nearer && GridProblem::is_acceptable_with_dimensions(width, height, start, end) {
+                            nearest = Some((start, end));
+                        }
+                    }
+                }
+            }
+            if let Some(pair) = nearest {
+                return Ok(pair);
+            }
+        }
+
+        //The scan covers every pair of vertices, and every grid has an
+        //acceptable pair: the two ends of a serpentine path
+        unreachable!("No acceptable endpoints found on the {}x{} grid", width, height);
+    }
+
+    /// Get the vertices of a width by height grid at exactly the given
+    /// Manhattan distance from a vertex, row by row
+    fn vertices_at_distance(width: usize, height: usize, v_coords: [usize; 2], distance: usize) -> Vec<[usize; 2]> {
+        let mut vertices: Vec<[usize; 2]> = Vec::new();
+        for y in v_coords[1].saturating_sub(distance)..=v_coords[1].saturating_add(distance).min(height - 1) {
+            let remaining: usize = distance - y.abs_diff(v_coords[1]);
+            if remaining <= v_coords[0] {
+                vertices.push([v_coords[0] - remaining, y]);
+            }
+            if remaining > 0 && v_coords[0] + remaining < width {
+                vertices.push([v_coords[0] + remaining, y]);
+            }
+        }
+        vertices
+    }
+
+    /// Convert the grid problem to a JSON object in the problem format of
+    /// the embedded schema, with the keys `format_version`, `width`,
+    /// `height`, `start` and `end`
+    pub fn to_json_value(&self) -> json::JsonValue {
+        json::object!{
+            format_version: crate::FORMAT_VERSION,
+            width: self.grid_graph.get_width(),
+            height: self.grid_graph.get_height(),
+            start: self.start_coords.to_vec(),
+            end: self.end_coords.to_vec()
+        }
+    }
+
+    /// Convert the grid problem to a single-line JSON object, as in
+    /// `to_json_value`
+    pub fn to_json(&self) -> String {
+        json::stringify(self.to_json_value())
+    }
+
+    /// Serialize the grid problem along with the options it was solved
+    /// with, the outcome and stats of that solve, and the build info of
+    /// the library into a single-line JSON support bundle to attach to a
+    /// bug report.  The bundle has the keys `format_version`, `build`,
+    /// `problem`, `options`, `outcome` and `stats`, the last being null
+    /// when no stats were recorded.
+    pub fn support_bundle(&self, options: &SolveOptions, outcome: &Result<Option<GridPath>, SolveError>, stats: Option<&SolveStats>) -> String {
+        let outcome_json: json::JsonValue = match outcome {
+            Ok(Some(path)) => json::object!{
+                status: "solved",
+                error: json::JsonValue::Null,
+                path: path.get_vertex_order().iter().map(|v| v.to_vec()).collect::<Vec<Vec<usize>>>()
+            },
+            Ok(None) => json::object!{
+                status: "no_solution",
+                error: json::JsonValue::Null,
+                path: json::JsonValue::Null
+            },
+            Err(e) => json::object!{
+                status: "error",
+                error: format!("{}", e),
+                path: json::JsonValue::Null
+            }
+        };
+        let stats_json: json::JsonValue = match stats {
+            Some(x) => x.to_json_value(),
+            None => json::JsonValue::Null
+        };
+        let mut problem_json: json::JsonValue = self.to_json_value();
+        problem_json["is_acceptable"] = self.is_acceptable().into();
+        json::stringify(json::object!{
+            format_version: crate::FORMAT_VERSION,
+            build: buildinfo::build_info().to_json_value(),
+            problem: problem_json,
+            options: json::object!{
+                max_bruteforce_cells: options.max_bruteforce_cells,
+                max_bruteforce_nodes_expanded: options.max_bruteforce_nodes_expanded,
+                paranoid: options.paranoid
+            },
+            outcome: outcome_json,
+            stats: stats_json
+        })
+    }
+
+    /// Initialize a new `GridProblem` over the same grid graph with
+    /// the start and end vertex coordinates exchanged
+    pub fn swap_start_end(&self) -> GridProblem {
+        GridProblem::new(
+            self.grid_graph.get_width(),
+            self.grid_graph.get_height(),
+            self.end_coords,
+            self.start_coords
+        )
+    }
+
     /// Strip the grid problem to the right if it can be stripped
     fn strip_right(&mut self) -> bool {
         //Check if either the start vertex or the end vertex is less than
@@ -221,304 +657,368 @@ impl GridProblem {
         return false;
     }
 
-    /// Check if the grid problem can be split horizontally
-    pub fn can_be_split_horizontally(&self) -> bool {
-        //Check if the start and end vertex share a y coordinate, if so
-        //then return false
-        if self.start_coords[1] == self.end_coords[1] {
-            return false;
+    /// Estimate the work needed to solve the grid problem by replaying
+    /// the strips the solver would take on the problem's dimensions and
+    /// endpoints alone, without constructing any grid graphs
+    pub fn estimate(&self) -> SolveEstimate {
+        //Initialize an estimate with no strips or splits
+        let mut width: usize = self.grid_graph.get_width();
+        let mut height: usize = self.grid_graph.get_height();
+        let mut estimate: SolveEstimate = SolveEstimate {
+            num_cells: width * height,
+            num_strips_right: 0,
+            num_strips_up: 0,
+            num_strips_left: 0,
+            num_strips_down: 0,
+            is_split_likely: false
+        };
+
+        //If the problem is not acceptable or will be solved directly then
+        //no strips or splits will be performed
+        if !self.is_acceptable() || width == 2 || height == 2 || width == 3 || height == 3 {
+            return estimate;
         }
 
-        //If they do not share a y coordinate, then loop through the
-        //vertices of the grid graph starting at the lesser y coordinate
-        //of the start and end vertices and looping until we reach one
-        //less than the greater y coordinate of the two
-        let is_start_coords_below: bool = self.start_coords[1] < self.end_coords[1];
-        let outer_range_start = if is_start_coords_below { self.start_coords[1] } else { self.end_coords[1] };
-        let outer_range_end = if is_start_coords_below { self.end_coords[1] } else { self.start_coords[1] };
-        let outer_range = outer_range_start..outer_range_end;
-        for i in outer_range {
-            for j in 0..self.grid_graph.get_width() {
-                //Continue if either the upper or lower vertices are either
-                //the start or end vertices
-                let lower_vertex_coords: [usize; 2] = [j, i];
-                let upper_vertex_coords: [usize; 2] = [j, i+1];
-                if lower_vertex_coords == self.start_coords || upper_vertex_coords == self.start_coords ||
-                   lower_vertex_coords == self.end_coords || upper_vertex_coords == self.end_coords {
-                    continue;
-                }
+        //Order the endpoints as the solver does
+        let (mut start_coords, mut end_coords): ([usize; 2], [usize; 2]) = if self.end_coords < self.start_coords {
+            (self.end_coords, self.start_coords)
+        } else {
+            (self.start_coords, self.end_coords)
+        };
 
-                //Initialize two sub GridProblems with the upper vertex coords
-                //and the lower vertex coords inserted as new start/end vertices
-                let lower_sub_problem: GridProblem = if is_start_coords_below {
-                    GridProblem::new(
-                        self.grid_graph.get_width(),
-                        upper_vertex_coords[1],
-                        self.start_coords,
-                        lower_vertex_coords
-                    )
-                } else {
-                    GridProblem::new(
-                        self.grid_graph.get_width(),
-                        upper_vertex_coords[1],
-                        lower_vertex_coords,
-                        self.end_coords
-                    )
-                };
-                let upper_sub_problem: GridProblem = if is_start_coords_below {
-                    GridProblem::new(
-                        self.grid_graph.get_width(),
-                        self.grid_graph.get_height() - upper_vertex_coords[1],
-                        [upper_vertex_coords[0], 0],
-                        [self.end_coords[0], self.end_coords[1] - upper_vertex_coords[1]]
-                    )
-                } else {
-                    GridProblem::new(
-                        self.grid_graph.get_width(),
-                        self.grid_graph.get_height() - upper_vertex_coords[1],
-                        [self.start_coords[0], self.start_coords[1] - upper_vertex_coords[1]],
-                        [upper_vertex_coords[0], 0]
-                    )
-                };
-                
-                //If the left and right sub problems are both acceptable then
-                //return true, otherwise continue
-                if lower_sub_problem.is_acceptable() && upper_sub_problem.is_acceptable() {
-                    return true;
-                }
+        //Replay the strips in the same order as GridProblem::strip.  Color
+        //compatibility is preserved by stripping, so a stripped problem is
+        //acceptable exactly when it is not forbidden.
+        loop {
+            let max_coords: [usize; 2] = [start_coords[0].max(end_coords[0]), start_coords[1].max(end_coords[1])];
+            let min_coords: [usize; 2] = [start_coords[0].min(end_coords[0]), start_coords[1].min(end_coords[1])];
+            if width - max_coords[0] > 2 &&
+               !GridGraph::is_forbidden_with_dimensions(width - 2, height, start_coords, end_coords) {
+                width -= 2;
+                estimate.num_strips_right += 1;
+            } else if height - max_coords[1] > 2 &&
+                      !GridGraph::is_forbidden_with_dimensions(width, height - 2, start_coords, end_coords) {
+                height -= 2;
+                estimate.num_strips_up += 1;
+            } else if min_coords[0] >= 2 &&
+                      !GridGraph::is_forbidden_with_dimensions(width - 2, height, [start_coords[0] - 2, start_coords[1]], [end_coords[0] - 2, end_coords[1]]) {
+                width -= 2;
+                start_coords[0] -= 2;
+                end_coords[0] -= 2;
+                estimate.num_strips_left += 1;
+            } else if min_coords[1] >= 2 &&
+                      !GridGraph::is_forbidden_with_dimensions(width, height - 2, [start_coords[0], start_coords[1] - 2], [end_coords[0], end_coords[1] - 2]) {
+                height -= 2;
+                start_coords[1] -= 2;
+                end_coords[1] -= 2;
+                estimate.num_strips_down += 1;
+            } else {
+                break;
             }
         }
 
-        //If no split is found such that both sub problems are acceptable, return false
-        false
+        //If the stripped problem is neither prime nor solved directly then
+        //it is likely to be split into sub-problems
+        estimate.is_split_likely = width > 3 && height > 3 &&
+                                   !GridPath::is_prime(width, height, start_coords, end_coords);
+        estimate
     }
 
-    /// Check if the grid problem can be split vertically
-    pub fn can_be_split_vertically(&self) -> bool {
-        //Check if the start and end vertex share an x coordinate, if so
-        //then return false
-        if self.start_coords[0] == self.end_coords[0] {
-            return false;
-        }
+    /// Get the number of strips the solver is expected to take before it
+    /// splits or solves the problem directly.  Strips taken from the
+    /// sub-problems of a split are not counted, so this is a lower bound.
+    pub fn num_strips_needed(&self) -> usize {
+        self.estimate().get_num_strips()
+    }
 
-        //If they do not share an x coordinate, then loop through the
-        //vertices of the grid graph starting at the lesser x coordinate
-        //of the start and end vertices and looping until we reach one
-        //less than the greater x coordinate of the two
-        let is_start_coords_left: bool = self.start_coords[0] < self.end_coords[0];
-        let outer_range_start = if is_start_coords_left { self.start_coords[0] } else { self.end_coords[0] };
-        let outer_range_end = if is_start_coords_left { self.end_coords[0] } else { self.start_coords[0] };
-        let outer_range = outer_range_start..outer_range_end;
-        for i in outer_range {
-            for j in 0..self.grid_graph.get_height() {
-                //Continue if either the left or right vertices are either
-                //the start or end vertices
-                let left_vertex_coords: [usize; 2] = [i, j];
-                let right_vertex_coords: [usize; 2] = [i+1, j];
-                if left_vertex_coords == self.start_coords || right_vertex_coords == self.start_coords ||
-                   left_vertex_coords == self.end_coords || right_vertex_coords == self.end_coords {
-                    continue;
-                }
+    /// Encode the grid problem as a SAT instance in DIMACS CNF format.
+    ///
+    /// For a grid of N vertices, variable `(v * N) + k + 1` is true when
+    /// the vertex with row-major index v is at position k of the path.
+    /// The clauses require that each vertex has exactly one position,
+    /// each position has exactly one vertex, each vertex is followed by
+    /// one of its neighbors, and the path runs from the start vertex to
+    /// the end vertex.
+    pub fn to_dimacs_cnf(&self) -> String {
+        //Initialize a closure for the variable placing a vertex at a position
+        let num_cells: usize = self.grid_graph.get_width() * self.grid_graph.get_height();
+        let var = |v: usize, k: usize| (v * num_cells) + k + 1;
+        let mut clauses: Vec<Vec<isize>> = Vec::new();
 
-                //Initialize two sub GridProblems with the left vertex coords
-                //and the right vertex coords inserted as new start/end vertices
-                let left_sub_problem: GridProblem = if is_start_coords_left {
-                    GridProblem::new(
-                        right_vertex_coords[0],
-                        self.grid_graph.get_height(),
-                        self.start_coords,
-                        left_vertex_coords
-                    )
-                } else {
-                    GridProblem::new(
-                        right_vertex_coords[0],
-                        self.grid_graph.get_height(),
-                        left_vertex_coords,
-                        self.end_coords
-                    )
-                };
-                let right_sub_problem: GridProblem = if is_start_coords_left {
-                    GridProblem::new(
-                        self.grid_graph.get_width() - right_vertex_coords[0],
-                        self.grid_graph.get_height(),
-                        [0, right_vertex_coords[1]],
-                        [self.end_coords[0] - right_vertex_coords[0], self.end_coords[1]]
-                    )
-                } else {
-                    GridProblem::new(
-                        self.grid_graph.get_width() - right_vertex_coords[0],
-                        self.grid_graph.get_height(),
-                        [self.start_coords[0] - right_vertex_coords[0], self.start_coords[1]],
-                        [0, right_vertex_coords[1]]
-                    )
-                };
-                
-                //If the left and right sub problems are both acceptable then
-                //return true, otherwise continue
-                if left_sub_problem.is_acceptable() && right_sub_problem.is_acceptable() {
-                    return true;
+        //Each vertex has exactly one position and each position has
+        //exactly one vertex
+        for a in 0..num_cells {
+            clauses.push((0..num_cells).map(|b| var(a, b) as isize).collect());
+            clauses.push((0..num_cells).map(|b| var(b, a) as isize).collect());
+            for b in 0..num_cells {
+                for c in (b + 1)..num_cells {
+                    clauses.push(vec![-(var(a, b) as isize), -(var(a, c) as isize)]);
+                    clauses.push(vec![-(var(b, a) as isize), -(var(c, a) as isize)]);
                 }
             }
         }
 
-        //If no split is found such that both sides are acceptable, return false
-        false
-    }
+        //Each vertex but the last is followed by one of its neighbors
+        for v_coords in self.grid_graph.cells() {
+            let v: usize = self.grid_graph.cell_index(v_coords);
+            let neighbors: Vec<[usize; 2]> = GridProblem::get_neighbors(
+                self.grid_graph.get_width(),
+                self.grid_graph.get_height(),
+                v_coords
+            );
+            for k in 0..(num_cells - 1) {
+                let mut clause: Vec<isize> = vec![-(var(v, k) as isize)];
+                clause.extend(neighbors.iter().map(|w| var(self.grid_graph.cell_index(*w), k + 1) as isize));
+                clauses.push(clause);
+            }
+        }
 
-    /// Split the grid problem horizontally and return the subproblems
-    pub fn split_horizontally(&self) -> Option<(GridProblem, GridProblem)> {
-        //Check if the start and end vertex share a y coordinate, if so
-        //then return None
-        if self.start_coords[1] == self.end_coords[1] {
-            return None;
+        //The path runs from the start vertex to the end vertex
+        clauses.push(vec![var(self.grid_graph.cell_index(self.start_coords), 0) as isize]);
+        clauses.push(vec![var(self.grid_graph.cell_index(self.end_coords), num_cells - 1) as isize]);
+
+        //Write the header followed by the clauses
+        let mut cnf: String = format!(
+            "c Hamiltonian path in a {} x {} grid graph from ({}, {}) to ({}, {})\np cnf {} {}\n",
+            self.grid_graph.get_width(), self.grid_graph.get_height(),
+            self.start_coords[0], self.start_coords[1],
+            self.end_coords[0], self.end_coords[1],
+            num_cells * num_cells, clauses.len()
+        );
+        for clause in clauses {
+            for literal in clause {
+                cnf += &format!("{} ", literal);
+            }
+            cnf += "0\n";
         }
+        cnf
+    }
 
-        //If they do not share a y coordinate, then loop through the
-        //vertices of the grid graph starting at the lesser y coordinate
-        //of the start and end vertices and looping until we reach one
-        //less than the greater y coordinate of the two
-        let is_start_coords_below: bool = self.start_coords[1] < self.end_coords[1];
-        let outer_range_start = if is_start_coords_below { self.start_coords[1] } else { self.end_coords[1] };
-        let outer_range_end = if is_start_coords_below { self.end_coords[1] } else { self.start_coords[1] };
-        let outer_range = outer_range_start..outer_range_end;
-        for i in outer_range {
-            for j in 0..self.grid_graph.get_width() {
-                //Continue if either the upper or lower vertices are either
-                //the start or end vertices
-                let lower_vertex_coords: [usize; 2] = [j, i];
-                let upper_vertex_coords: [usize; 2] = [j, i+1];
-                if lower_vertex_coords == self.start_coords || upper_vertex_coords == self.start_coords ||
-                   lower_vertex_coords == self.end_coords || upper_vertex_coords == self.end_coords {
-                    continue;
+    /// Read a `GridPath` over an n by m grid graph from the model output
+    /// by a SAT solver for the formula given by `to_dimacs_cnf`.  Both the
+    /// competition format, with `s` and `v` lines, and a bare list of
+    /// literals are accepted, and comment lines are ignored.
+    pub fn from_dimacs_model(n: usize, m: usize, model: &str) -> Result<GridPath, PathParseError> {
+        //Loop through the literals of the model, placing each vertex whose
+        //variable is true at its position in the path
+        let num_cells: usize = n * m;
+        let mut vertex_order: Vec<Option<[usize; 2]>> = vec![None; num_cells];
+        for line in model.lines().map(|line| line.trim()) {
+            if line.starts_with('c') || line.len() == 0 {
+                continue;
+            }
+            if line.starts_with('s') || line == "SAT" || line == "UNSAT" {
+                if line.contains("UNSAT") {
+                    return Err(PathParseError::Unsatisfiable);
                 }
-
-                //Initialize two sub GridProblems with the upper vertex coords
-                //and the lower vertex coords inserted as new start/end vertices
-                let lower_sub_problem: GridProblem = if is_start_coords_below {
-                    GridProblem::new(
-                        self.grid_graph.get_width(),
-                        upper_vertex_coords[1],
-                        self.start_coords,
-                        lower_vertex_coords
-                    )
-                } else {
-                    GridProblem::new(
-                        self.grid_graph.get_width(),
-                        upper_vertex_coords[1],
-                        lower_vertex_coords,
-                        self.end_coords
-                    )
-                };
-                let upper_sub_problem: GridProblem = if is_start_coords_below {
-                    GridProblem::new(
-                        self.grid_graph.get_width(),
-                        self.grid_graph.get_height() - upper_vertex_coords[1],
-                        [upper_vertex_coords[0], 0],
-                        [self.end_coords[0], self.end_coords[1] - upper_vertex_coords[1]]
-                    )
-                } else {
-                    GridProblem::new(
-                        self.grid_graph.get_width(),
-                        self.grid_graph.get_height() - upper_vertex_coords[1],
-                        [self.start_coords[0], self.start_coords[1] - upper_vertex_coords[1]],
-                        [upper_vertex_coords[0], 0]
-                    )
+                continue;
+            }
+            let literals: &str = line.strip_prefix('v').unwrap_or(line);
+            for literal in literals.split_whitespace() {
+                let literal_value: isize = match literal.parse::<isize>() {
+                    Ok(x) => x,
+                    Err(_) => return Err(PathParseError::InvalidLiteral(String::from(literal)))
                 };
-                
-                //If the left and right sub problems are both acceptable then
-                //return them, otherwise continue
-                if lower_sub_problem.is_acceptable() && upper_sub_problem.is_acceptable() {
-                    return Some((lower_sub_problem, upper_sub_problem));
+                if literal_value <= 0 {
+                    continue;
+                }
+                let var: usize = literal_value as usize;
+                if var > num_cells * num_cells {
+                    return Err(PathParseError::VariableOutOfRange(var));
                 }
+                let k: usize = (var - 1) % num_cells;
+                if vertex_order[k].is_some() {
+                    return Err(PathParseError::RepeatedPosition(k));
+                }
+                vertex_order[k] = Some(GridGraph::coords_of_with_width(n, (var - 1) / num_cells));
             }
         }
 
-        //If no split is found such that both sub problems are acceptable, return None
-        None
+        //Check that every position was filled and that the vertices form a path
+        let mut path: Vec<[usize; 2]> = Vec::new();
+        for (k, vertex) in vertex_order.into_iter().enumerate() {
+            match vertex {
+                Some(x) => path.push(x),
+                None => return Err(PathParseError::MissingPosition(k))
+            }
+        }
+        if let Err(e) = GridPath::validate_vertex_order(n, m, &path) {
+            return Err(PathParseError::InvalidPath(e));
+        }
+        Ok(GridPath::new(n, m, path))
+    }
+
+    /// Check if the grid problem can be split horizontally
+    pub fn can_be_split_horizontally(&self) -> bool {
+        self.split_horizontally().is_some()
+    }
+
+    /// Check if the grid problem can be split vertically
+    pub fn can_be_split_vertically(&self) -> bool {
+        self.split_vertically().is_some()
+    }
+
+    /// Split the grid problem horizontally and return the subproblems
+    pub fn split_horizontally(&self) -> Option<(GridProblem, GridProblem)> {
+        self.get_split_positions(true).find_map(|(i, j)| self.split_at(true, i, j))
     }
 
     /// Split the grid problem vertically and return the subproblems
     pub fn split_vertically(&self) -> Option<(GridProblem, GridProblem)> {
-        //Check if the start and end vertex share an x coordinate, if so
-        //then return None
-        if self.start_coords[0] == self.end_coords[0] {
-            return None;
+        self.get_split_positions(false).find_map(|(i, j)| self.split_at(false, i, j))
+    }
+
+    /// Iterate over the positions at which the grid problem may be split,
+    /// as pairs (i, j) of the row or column i after which the grid is cut
+    /// and the position j along the cut at which the path crosses it.
+    /// The cuts lie between the rows (or columns) of the start and end
+    /// vertices, so that the endpoints fall on opposite sides.
+    ///
+    /// Only the positions whose sub-problems are color compatible are
+    /// given, in the same order as a scan of every position.  Along each
+    /// cut these are every other position, or none at all.
+    fn get_split_positions(&self, is_horizontal: bool) -> impl Iterator<Item = (usize, usize)> {
+        let axis: usize = if is_horizontal { 1 } else { 0 };
+        let cut_length: usize = if is_horizontal { self.grid_graph.get_width() } else { self.grid_graph.get_height() };
+        let (range_start, range_end): (usize, usize) = self.get_split_range(is_horizontal);
+
+        //Get the parity of the sum of a vertex's coordinates
+        let parity = |v_coords: [usize; 2]| -> usize { (v_coords[0] + v_coords[1]) & 1 };
+
+        //The endpoint below (or left of) each cut lies in the near
+        //sub-problem and the other in the far one
+        let (near_parity, far_parity): (usize, usize) = if self.start_coords[axis] < self.end_coords[axis] {
+            (parity(self.start_coords), parity(self.end_coords))
+        } else {
+            (parity(self.end_coords), parity(self.start_coords))
+        };
+        let axis_length: usize = if is_horizontal { self.grid_graph.get_height() } else { self.grid_graph.get_width() };
+        (range_start..range_end).flat_map(move |i| {
+            //The vertex crossing into the near sub-problem at position j has
+            //parity i + j, as does its far neighbor once the far sub-problem
+            //is shifted to its own origin.  An even sub-problem needs its
+            //endpoints to differ in parity and an odd one needs both even, so
+            //each sub-problem fixes the parity of j or rules out the cut.
+            let near_is_odd: bool = (cut_length * (i + 1)) & 1 == 1;
+            let far_is_odd: bool = (cut_length * (axis_length - 1 - i)) & 1 == 1;
+            let far_local_parity: usize = (far_parity + i + 1) & 1;
+            let near_j_parity: Option<usize> = match near_is_odd {
+                true if near_parity == 0 => Some(i & 1),
+                true => None,
+                false => Some((near_parity + i + 1) & 1)
+            };
+            let far_j_parity: Option<usize> = match far_is_odd {
+                true if far_local_parity == 0 => Some(0),
+                true => None,
+                false => Some((far_local_parity + 1) & 1)
+            };
+            let first_j: usize = match (near_j_parity, far_j_parity) {
+                (Some(x), Some(y)) if x == y => x,
+                _ => cut_length
+            };
+            (first_j..cut_length).step_by(2).map(move |j| (i, j))
+        })
+    }
+
+    /// Get the range of rows (or columns) after which the grid problem may
+    /// be cut, from the lesser to the greater row (or column) of its endpoints
+    fn get_split_range(&self, is_horizontal: bool) -> (usize, usize) {
+        let axis: usize = if is_horizontal { 1 } else { 0 };
+        (self.start_coords[axis].min(self.end_coords[axis]), self.start_coords[axis].max(self.end_coords[axis]))
+    }
+
+    /// Get the number of positions a scan of every position along every cut
+    /// would pass up to and including the given one, or across every cut if
+    /// none is given
+    fn count_split_candidates(&self, is_horizontal: bool, position: Option<(usize, usize)>) -> usize {
+        let cut_length: usize = if is_horizontal { self.grid_graph.get_width() } else { self.grid_graph.get_height() };
+        let (range_start, range_end): (usize, usize) = self.get_split_range(is_horizontal);
+        match position {
+            Some((i, j)) => (i - range_start) * cut_length + j + 1,
+            None => (range_end - range_start) * cut_length
         }
+    }
 
-        //If they do not share an x coordinate, then loop through the
-        //vertices of the grid graph starting at the lesser x coordinate
-        //of the start and end vertices and looping until we reach one
-        //less than the greater x coordinate of the two
-        let is_start_coords_left: bool = self.start_coords[0] < self.end_coords[0];
-        let outer_range_start = if is_start_coords_left { self.start_coords[0] } else { self.end_coords[0] };
-        let outer_range_end = if is_start_coords_left { self.end_coords[0] } else { self.start_coords[0] };
-        let outer_range = outer_range_start..outer_range_end;
-        for i in outer_range {
-            for j in 0..self.grid_graph.get_height() {
-                //Continue if either the left or right vertices are either
-                //the start or end vertices
-                let left_vertex_coords: [usize; 2] = [i, j];
-                let right_vertex_coords: [usize; 2] = [i+1, j];
-                if left_vertex_coords == self.start_coords || right_vertex_coords == self.start_coords ||
-                   left_vertex_coords == self.end_coords || right_vertex_coords == self.end_coords {
-                    continue;
-                }
+    /// Split the grid problem at the first position found, as for
+    /// `split_horizontally` and `split_vertically`, counting the positions
+    /// probed and those a scan of every position would have passed
+    fn find_split(&self, is_horizontal: bool, context: &mut SolveContext) -> Option<(GridProblem, GridProblem)> {
+        let mut found: Option<(usize, usize)> = None;
+        let split: Option<(GridProblem, GridProblem)> = self.get_split_positions(is_horizontal).find_map(|(i, j)| {
+            context.num_split_probes += 1;
+            found = Some((i, j));
+            self.split_at(is_horizontal, i, j)
+        });
+        if split.is_none() {
+            found = None;
+        }
+        context.num_split_candidates += self.count_split_candidates(is_horizontal, found);
+        split
+    }
 
-                //Initialize two sub GridProblems with the left vertex coords
-                //and the right vertex coords inserted as new start/end vertices
-                let left_sub_problem: GridProblem = if is_start_coords_left {
-                    GridProblem::new(
-                        right_vertex_coords[0],
-                        self.grid_graph.get_height(),
-                        self.start_coords,
-                        left_vertex_coords
-                    )
-                } else {
-                    GridProblem::new(
-                        right_vertex_coords[0],
-                        self.grid_graph.get_height(),
-                        left_vertex_coords,
-                        self.end_coords
-                    )
-                };
-                let right_sub_problem: GridProblem = if is_start_coords_left {
-                    GridProblem::new(
-                        self.grid_graph.get_width() - right_vertex_coords[0],
-                        self.grid_graph.get_height(),
-                        [0, right_vertex_coords[1]],
-                        [self.end_coords[0] - right_vertex_coords[0], self.end_coords[1]]
-                    )
-                } else {
-                    GridProblem::new(
-                        self.grid_graph.get_width() - right_vertex_coords[0],
-                        self.grid_graph.get_height(),
-                        [self.start_coords[0] - right_vertex_coords[0], self.start_coords[1]],
-                        [0, right_vertex_coords[1]]
-                    )
-                };
-                
-                //If the left and right sub problems are both acceptable then
-                //return them, otherwise continue
-                if left_sub_problem.is_acceptable() && right_sub_problem.is_acceptable() {
-                    return Some((left_sub_problem, right_sub_problem));
-                }
-            }
+    /// Split the grid problem after row (or column) i, with the path
+    /// crossing from the vertex at position j along the cut to its
+    /// neighbor across it.  Return the sub-problem below (or left of)
+    /// the cut then the one above (or right of) it, each running in the
+    /// same direction as the grid problem, if both are acceptable.
+    fn split_at(&self, is_horizontal: bool, i: usize, j: usize) -> Option<(GridProblem, GridProblem)> {
+        //Get the vertices either side of the cut where the path crosses
+        //it, neither of which may be an endpoint
+        let (near_vertex_coords, far_vertex_coords): ([usize; 2], [usize; 2]) = if is_horizontal {
+            ([j, i], [j, i + 1])
+        } else {
+            ([i, j], [i + 1, j])
+        };
+        if near_vertex_coords == self.start_coords || far_vertex_coords == self.start_coords ||
+           near_vertex_coords == self.end_coords || far_vertex_coords == self.end_coords {
+            return None;
         }
 
-        //If no split is found such that both sides are acceptable, return None
+        //Cut the grid into the rectangles either side of the cut
+        let grid_rect: Rect = Rect::from_dimensions(self.grid_graph.get_width(), self.grid_graph.get_height())?;
+        let (near_rect, far_rect): (Rect, Rect) = if is_horizontal {
+            grid_rect.split_at_row(i)?
+        } else {
+            grid_rect.split_at_column(i)?
+        };
+
+        //Initialize the sub-problems over the rectangles, with the vertices
+        //either side of the cut inserted as new start/end vertices
+        let axis: usize = if is_horizontal { 1 } else { 0 };
+        let (near_sub_problem, far_sub_problem): (GridProblem, GridProblem) = if self.start_coords[axis] < self.end_coords[axis] {
+            (
+                GridProblem::from_rect(&near_rect, self.start_coords, near_vertex_coords),
+                GridProblem::from_rect(&far_rect, far_vertex_coords, self.end_coords)
+            )
+        } else {
+            (
+                GridProblem::from_rect(&near_rect, near_vertex_coords, self.end_coords),
+                GridProblem::from_rect(&far_rect, self.start_coords, far_vertex_coords)
+            )
+        };
+
+        //Return the sub-problems if both are acceptable
+        if near_sub_problem.is_acceptable() && far_sub_problem.is_acceptable() {
+            return Some((near_sub_problem, far_sub_problem));
+        }
         None
     }
 
+    /// Initialize a `GridProblem` over the vertices of a rectangle, given
+    /// start and end vertex coordinates in the rectangle's enclosing grid
+    fn from_rect(rect: &Rect, start_coords: [usize; 2], end_coords: [usize; 2]) -> GridProblem {
+        GridProblem::new(rect.get_width(), rect.get_height(), rect.to_local(start_coords), rect.to_local(end_coords))
+    }
+
     /// Reconstruct the original GridGraph and restore the original
     /// coordinates if the GridGraph was stripped during the solution
     /// of the GridProblem.  Clear the GridProblem's list of extensions
     /// in the process.
-    pub fn reconstruct(&mut self) {
-        //Check if any extensions exist, if not then exit early
-        if self.extensions.len() == 0_usize {
-            return;
-        }
-
+    ///
+    /// The problem is always restored to the dimensions and endpoints it
+    /// was initialized with, but if undoing the extensions does not give
+    /// them back then a `SolveError::ReconstructionMismatch` is returned.
+    pub fn reconstruct(&mut self) -> Result<(), SolveError> {
         //Initialize new GridGraph dimensions and new start and end
         //coordinates
         let mut new_width: usize = self.grid_graph.get_width();
@@ -546,127 +1046,861 @@ impl GridProblem {
             }
         }
 
-        //Initialize a new GridGraph using the new dimensions and update it
-        let new_grid_graph: GridGraph = GridGraph::new(new_width, new_height);
-        self.grid_graph = new_grid_graph;
-
-        //Update the start and end coords using the new coords
-        self.start_coords = new_start_coords;
-        self.end_coords = new_end_coords;
+        //Check the reconstruction against the original problem, then
+        //restore the original problem whether or not they agree
+        let is_consistent: bool = [new_width, new_height] == [self.original_width, self.original_height]
+            && (new_start_coords, new_end_coords) == (self.original_start_coords, self.original_end_coords);
+        if !is_consistent || self.extensions.len() > 0 {
+            self.grid_graph = GridGraph::new(self.original_width, self.original_height);
+            self.start_coords = self.original_start_coords;
+            self.end_coords = self.original_end_coords;
+        }
 
         //Clear the extensions
         self.extensions.clear();
+        if is_consistent {
+            return Ok(());
+        }
+        Err(SolveError::ReconstructionMismatch {
+            expected_dimensions: [self.original_width, self.original_height],
+            actual_dimensions: [new_width, new_height],
+            expected_endpoints: (self.original_start_coords, self.original_end_coords),
+            actual_endpoints: (new_start_coords, new_end_coords)
+        })
     }
 
-    /// Solve the grid problem by stripping and splitting it
-    /// into sub-problems
-    pub fn solve(&mut self) -> Option<GridPath> {
-        //If the problem is not acceptable, then there is no solution
-        if !self.is_acceptable() {
+    /// Push an extension onto the problem without stripping its grid
+    /// graph, corrupting it so that reconstruction can be tested
+    #[cfg(test)]
+    fn push_extension(&mut self, extension: GridExtension) {
+        self.extensions.push(extension);
+    }
+
+    /// Solve a grid problem whose width or height is 1 by running from
+    /// its start vertex at one end of the grid to its end vertex at the
+    /// other, returning None if the endpoints are not the two ends
+    fn solve_one_wide(&self) -> Option<GridPath> {
+        //Determine the length of the grid and the position of each
+        //endpoint along it
+        let width: usize = self.grid_graph.get_width();
+        let height: usize = self.grid_graph.get_height();
+        let is_width: bool = width == 1;
+        let length: usize = if is_width { height } else { width };
+        let start_position: usize = if is_width { self.start_coords[1] } else { self.start_coords[0] };
+        let end_position: usize = if is_width { self.end_coords[1] } else { self.end_coords[0] };
+
+        //Check that the endpoints are the two ends of the grid, which are
+        //the same vertex only if the grid is a single vertex
+        let is_forward: bool = start_position == 0 && end_position == length - 1;
+        let is_backward: bool = start_position == length - 1 && end_position == 0;
+        if !is_forward && !is_backward {
             return None;
         }
 
-        //Initialize mutable grid graph, solution path, & collection of extensions
-        let mut solution: Option<GridPath> = None;
-        
-        //Loop until solved
-        loop {
-            //Check if there is a solution path
-            let is_solution: bool = match solution {
-                Some(ref _x) => true,
-                None => false
-            };
+        //Run from the start vertex to the end vertex
+        let positions: Vec<usize> = if is_forward { (0..length).collect() } else { (0..length).rev().collect() };
+        let path: Vec<[usize; 2]> = positions.into_iter()
+            .map(|i| if is_width { [0, i] } else { [i, 0] })
+            .collect();
+        Some(GridPath::new(width, height, path))
+    }
 
-            //If there is a solution path then extend it as needed and return it
-            if is_solution {
-                //Unwrap the solution path and extend it if any strips were performed
-                let mut solution_path: GridPath = solution.unwrap();
-                solution_path.extend_many(&self.extensions);
+    /// Solve an acceptable grid problem whose width or height is 2 by
+    /// zig-zagging across the grid between the start and end vertices
+    fn solve_two_wide(&self) -> GridPath {
+        //Work with the grid laid out as two rows, transposing the
+        //coordinates if the grid is instead two columns wide
+        let width: usize = self.grid_graph.get_width();
+        let height: usize = self.grid_graph.get_height();
+        let is_width: bool = width == 2;
+        let length: usize = if is_width { height } else { width };
+        let to_rows = |v: [usize; 2]| if is_width { [v[1], v[0]] } else { v };
 
-                //Reconstruct the original GridProblem after having stripped it
-                self.reconstruct();
-                return Some(solution_path);
-            }
+        //Order the endpoints such that the start vertex is in the leftmost
+        //column, we reverse the path afterward if they were swapped
+        let is_reversed: bool = to_rows(self.start_coords)[0] > to_rows(self.end_coords)[0];
+        let (start, end): ([usize; 2], [usize; 2]) = if is_reversed {
+            (to_rows(self.end_coords), to_rows(self.start_coords))
+        } else {
+            (to_rows(self.start_coords), to_rows(self.end_coords))
+        };
 
-            //If there is no solution then first strip the problem as much as possible
-            loop {
-                if !self.strip() {
-                    break;
-                }
+        //Initialize a Vec<[usize; 2]> containing the path
+        let mut path: Vec<[usize; 2]> = Vec::new();
+        if start[0] == end[0] {
+            //If the endpoints share a column then that column must be on the
+            //boundary, so run along the start row away from it and return
+            //along the other row
+            let range = if start[0] == 0 { (0..length).collect::<Vec<_>>() } else { (0..length).rev().collect::<Vec<_>>() };
+            for i in range.iter() {
+                path.push([*i, start[1]]);
+            }
+            for i in range.iter().rev() {
+                path.push([*i, end[1]]);
+            }
+        } else {
+            //Cover the columns left of the start vertex by running along the
+            //start row to the boundary and returning along the other row
+            for i in (0..start[0] + 1).rev() {
+                path.push([i, start[1]]);
+            }
+            for i in 0..start[0] + 1 {
+                path.push([i, 1 - start[1]]);
             }
 
-            //Get the width and height of the grid graph
-            let width: usize = self.grid_graph.get_width();
-            let height: usize = self.grid_graph.get_height();
-
-            //After stripping is complete, check if the problem is prime.  If
-            //so then lookup its solution and continue.
-            if GridPath::is_prime(width, height, self.start_coords, self.end_coords) {
-                solution = GridPath::get_prime(width, height, self.start_coords, self.end_coords);
-                continue;
+            //Zig-zag through the columns between the start and end vertices,
+            //color compatibility guarantees we arrive opposite the end vertex
+            let mut row: usize = 1 - start[1];
+            for i in (start[0] + 1)..end[0] {
+                path.push([i, row]);
+                path.push([i, 1 - row]);
+                row = 1 - row;
             }
 
-            //If the GridProblem is not prime, break it into subproblems by splitting it
-            if self.can_be_split_horizontally() {
-                let (mut p_below, mut p_above): (GridProblem, GridProblem) = self.split_horizontally().unwrap();
-                let p_below_solution: GridPath = p_below.solve().unwrap();
-                let p_above_solution: GridPath = p_above.solve().unwrap();
-                let vertex_order: Vec<[usize; 2]> = if self.start_coords[1] < self.end_coords[1] {
-                    let mut tmp_vertex_order: Vec<[usize; 2]> = p_below_solution.vertex_order;
-                    tmp_vertex_order.extend(p_above_solution.get_up_shift_vertex_order(p_below.grid_graph.get_height()));
-                    tmp_vertex_order
-                } else {
-                    let mut tmp_vertex_order: Vec<[usize; 2]> = p_above_solution.get_up_shift_vertex_order(p_below.grid_graph.get_height());
-                    tmp_vertex_order.extend(p_below_solution.vertex_order);
-                    tmp_vertex_order
-                };
-                let solution_path = GridPath::new(
-                    p_below.grid_graph.get_width(),
-                    p_below.grid_graph.get_height() + p_above.grid_graph.get_height(),
-                    vertex_order
-                );
-                solution = Some(solution_path);
+            //Cover the remaining columns by running along the other row to the
+            //boundary and returning along the end row
+            for i in end[0]..length {
+                path.push([i, 1 - end[1]]);
+            }
+            for i in (end[0]..length).rev() {
+                path.push([i, end[1]]);
+            }
+        }
+
+        //Restore the original orientation of the path and return it
+        if is_reversed {
+            path.reverse();
+        }
+        GridPath::new(width, height, path.into_iter().map(to_rows).collect())
+    }
+
+    /// Solve an acceptable grid problem whose width or height is 3 by
+    /// sweeping across the grid one column at a time, tracking the path
+    /// fragments which cross from the swept columns into the rest
+    fn solve_three_wide(&self) -> GridPath {
+        //Work with the grid laid out as three rows, transposing the
+        //coordinates if the grid is instead three columns wide
+        let width: usize = self.grid_graph.get_width();
+        let height: usize = self.grid_graph.get_height();
+        let is_width: bool = width == 3;
+        let length: usize = if is_width { height } else { width };
+        let to_rows = |v: [usize; 2]| if is_width { [v[1], v[0]] } else { v };
+        let start: [usize; 2] = to_rows(self.start_coords);
+        let end: [usize; 2] = to_rows(self.end_coords);
+        let last: [usize; 2] = [length - 1, 2];
+
+        //Visit the vertices column by column, bottom to top.  A state holds,
+        //for each row, the label of the path fragment crossing the sweep
+        //line in that row, followed by the label of the fragment entering
+        //the current vertex from below (0 if there is none).  A fragment
+        //carries the same label at both of its loose ends, so a label which
+        //appears once belongs to a fragment running from the start or end
+        //vertex.  For each vertex we record how each state was reached.
+        let mut states: Vec<[usize; 4]> = vec![[0; 4]];
+        let mut history: Vec<SweepStates> = Vec::new();
+        for i in 0..length {
+            for j in 0..3 {
+                let vertex: [usize; 2] = [i, j];
+                let degree: usize = if vertex == start || vertex == end { 1 } else { 2 };
+                let mut next_states: SweepStates = BTreeMap::new();
+                for state in states.iter() {
+                    //Collect the fragments entering the vertex from the left and below
+                    let incoming: Vec<usize> = [state[j], state[3]].into_iter().filter(|label| *label != 0).collect();
+
+                    //Try each combination of edges leaving the vertex to the right and above
+                    for (right, up) in [(true, true), (true, false), (false, true), (false, false)] {
+                        if (right && i + 1 == length) || (up && j == 2) ||
+                           incoming.len() + (right as usize) + (up as usize) != degree {
+                            continue;
+                        }
+                        let mut next_state: [usize; 4] = *state;
+                        next_state[j] = 0;
+                        next_state[3] = 0;
+                        let mut is_complete: bool = false;
+                        match incoming[..] {
+                            //No fragment enters, so a new fragment begins here
+                            [] => {
+                                let label: usize = state.iter().max().unwrap() + 1;
+                                if right { next_state[j] = label; }
+                                if up { next_state[3] = label; }
+                            },
+                            //One fragment enters, so it either continues or ends here
+                            [label] => {
+                                if right { next_state[j] = label; }
+                                if up { next_state[3] = label; }
+                                if !right && !up {
+                                    is_complete = state.iter().filter(|l| **l == label).count() == 1;
+                                }
+                            },
+                            //Two fragments enter, so they are joined here unless they
+                            //are the same fragment, in which case we would form a cycle
+                            [left_label, down_label] => {
+                                if left_label == down_label {
+                                    continue;
+                                }
+                                is_complete = state.iter().filter(|l| **l == left_label).count() == 1 &&
+                                              state.iter().filter(|l| **l == down_label).count() == 1;
+                                for label in next_state.iter_mut() {
+                                    if *label == down_label {
+                                        *label = left_label;
+                                    }
+                                }
+                            },
+                            _ => continue
+                        }
+
+                        //The path may only be completed at the last vertex, once no
+                        //fragments remain crossing the sweep line
+                        if is_complete != (vertex == last) || (is_complete && next_state != [0; 4]) {
+                            continue;
+                        }
+
+                        //Relabel the fragments in order of appearance so equivalent
+                        //states coincide, then record how the state was reached
+                        let mut relabeled: Vec<usize> = Vec::new();
+                        for label in next_state.iter_mut() {
+                            if *label == 0 {
+                                continue;
+                            }
+                            match relabeled.iter().position(|l| l == label) {
+                                Some(x) => *label = x + 1,
+                                None => {
+                                    relabeled.push(*label);
+                                    *label = relabeled.len();
+                                }
+                            }
+                        }
+                        next_states.entry(next_state).or_insert((*state, right, up));
+                    }
+                }
+                states = next_states.keys().copied().collect();
+                history.push(next_states);
+            }
+        }
+
+        //If the sweep could not be completed then the problem has no solution,
+        //which should be unreachable for an acceptable problem
+        if !states.contains(&[0; 4]) {
+            eprintln!("Grid problem was acceptable but had no solution, could not be solved by sweeping.");
+            process::exit(1);
+        }
+
+        //Walk back through the recorded states to recover the edges of the path
+        let mut neighbors: Vec<Vec<[usize; 2]>> = vec![Vec::new(); 3 * length];
+        let mut state: [usize; 4] = [0; 4];
+        for (k, vertex_states) in history.iter().enumerate().rev() {
+            let vertex: [usize; 2] = [k / 3, k % 3];
+            let (previous_state, right, up): ([usize; 4], bool, bool) = vertex_states[&state];
+            if right {
+                neighbors[GridGraph::cell_index_with_width(length, vertex)].push([vertex[0] + 1, vertex[1]]);
+                neighbors[GridGraph::cell_index_with_width(length, [vertex[0] + 1, vertex[1]])].push(vertex);
+            }
+            if up {
+                neighbors[GridGraph::cell_index_with_width(length, vertex)].push([vertex[0], vertex[1] + 1]);
+                neighbors[GridGraph::cell_index_with_width(length, [vertex[0], vertex[1] + 1])].push(vertex);
+            }
+            state = previous_state;
+        }
+
+        //Follow the edges from the start vertex to the end vertex
+        let mut path: Vec<[usize; 2]> = vec![start];
+        while path.len() < 3 * length {
+            let vertex: [usize; 2] = path[path.len() - 1];
+            let next_vertex: [usize; 2] = *neighbors[GridGraph::cell_index_with_width(length, vertex)].iter()
+                .find(|v| path.len() < 2 || **v != path[path.len() - 2])
+                .unwrap();
+            path.push(next_vertex);
+        }
+
+        //Restore the original orientation of the path and return it
+        GridPath::new(width, height, path.into_iter().map(to_rows).collect())
+    }
+
+    /// Solve the grid problem by an exhaustive depth-first search over
+    /// its paths.  This takes exponential time and is meant as a reference
+    /// for checking the solver against on small grids.
+    pub fn solve_brute_force(&self) -> Option<GridPath> {
+        //Search without any limits, so that the search cannot fail
+        let options: SolveOptions = SolveOptions {
+            max_bruteforce_cells: usize::MAX,
+            max_bruteforce_nodes_expanded: usize::MAX,
+            paranoid: false
+        };
+        self.solve_brute_force_with_options(&options).unwrap()
+    }
+
+    /// Solve the grid problem by an exhaustive depth-first search over
+    /// its paths, giving up with a `SolveError` if the grid or the search
+    /// exceeds the limits in the given `SolveOptions`
+    pub fn solve_brute_force_with_options(&self, options: &SolveOptions) -> Result<Option<GridPath>, SolveError> {
+        //Check that the grid is small enough to be searched
+        let width: usize = self.grid_graph.get_width();
+        let height: usize = self.grid_graph.get_height();
+        if width * height > options.max_bruteforce_cells {
+            return Err(SolveError::TooManyCells { limit: options.max_bruteforce_cells, actual: width * height });
+        }
+
+        //Initialize the search at the start vertex
+        let mut visited: Vec<bool> = vec![false; width * height];
+        let mut path: Vec<[usize; 2]> = vec![self.start_coords];
+        let mut nodes_expanded: usize = 0;
+        visited[self.grid_graph.cell_index(self.start_coords)] = true;
+
+        //Search for a path and return it if one is found
+        let cancelled: AtomicBool = AtomicBool::new(false);
+        if GridProblem::search_brute_force(
+            width, height, self.end_coords, &mut visited, &mut path,
+            options.max_bruteforce_nodes_expanded, &mut nodes_expanded, &cancelled
+        )? {
+            return Ok(Some(GridPath::new(width, height, path)));
+        }
+        Ok(None)
+    }
+
+    /// Solve the grid problem by an exhaustive depth-first search, as
+    /// `solve_brute_force` does, searching each first step from the start
+    /// vertex on its own thread.  The first thread to find a path cancels
+    /// the others, so which path is returned may differ between runs.
+    pub fn solve_parallel_brute_force(&self) -> Option<GridPath> {
+        let width: usize = self.grid_graph.get_width();
+        let height: usize = self.grid_graph.get_height();
+
+        //A single vertex grid has no first steps to distribute
+        if width * height == 1 {
+            return self.solve_brute_force();
+        }
+
+        //Search each first step on a scoped thread, recording the first
+        //path found and signalling the other threads to stop
+        let cancelled: AtomicBool = AtomicBool::new(false);
+        let found: Mutex<Option<Vec<[usize; 2]>>> = Mutex::new(None);
+        thread::scope(|scope| {
+            for first_step in GridProblem::get_neighbors(width, height, self.start_coords) {
+                let cancelled: &AtomicBool = &cancelled;
+                let found: &Mutex<Option<Vec<[usize; 2]>>> = &found;
+                scope.spawn(move || {
+                    let mut visited: Vec<bool> = vec![false; width * height];
+                    let mut path: Vec<[usize; 2]> = vec![self.start_coords, first_step];
+                    let mut nodes_expanded: usize = 0;
+                    visited[self.grid_graph.cell_index(self.start_coords)] = true;
+                    visited[self.grid_graph.cell_index(first_step)] = true;
+                    if let Ok(true) = GridProblem::search_brute_force(
+                        width, height, self.end_coords, &mut visited, &mut path,
+                        usize::MAX, &mut nodes_expanded, cancelled
+                    ) {
+                        let mut found = found.lock().unwrap();
+                        if found.is_none() {
+                            *found = Some(path);
+                        }
+                        cancelled.store(true, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+
+        //Return the path found, if any
+        found.into_inner().unwrap().map(|path| GridPath::new(width, height, path))
+    }
+
+    /// Complete the given prefix of a path from the start vertex into a
+    /// Hamiltonian path to the end vertex.  The returned path begins with
+    /// the prefix verbatim.
+    ///
+    /// The remainder is found by brute force, so if more vertices remain
+    /// than the default `SolveOptions` allow then `SolveError::Unsupported`
+    /// is returned.  A prefix which is not a simple path from the start
+    /// vertex gives `SolveError::InvalidPrefix`, and one which cannot be
+    /// completed gives `SolveError::Infeasible`.
+    pub fn complete(&self, prefix: &GridPath) -> Result<GridPath, SolveError> {
+        self.complete_with_options(prefix.get_vertex_order(), &SolveOptions::default())
+    }
+
+    /// Solve the grid problem starting from a partial path drawn from the
+    /// start vertex, completing it by backtracking search.  The solution
+    /// begins with the partial path.  None is returned if the partial path
+    /// is not a simple path from the start vertex, cannot be completed, or
+    /// leaves more vertices than the brute force search accepts by default.
+    pub fn solve_with_hint(&mut self, partial: Vec<[usize; 2]>) -> Option<GridPath> {
+        self.complete_with_options(&partial, &SolveOptions::default()).ok()
+    }
+
+    /// Complete the given prefix of a path from the start vertex by brute
+    /// force within the limits of the given `SolveOptions`
+    fn complete_with_options(&self, prefix: &[[usize; 2]], options: &SolveOptions) -> Result<GridPath, SolveError> {
+        let width: usize = self.grid_graph.get_width();
+        let height: usize = self.grid_graph.get_height();
+
+        //Check that the prefix is a simple path within the grid which
+        //starts at the start vertex, treating an empty prefix as the
+        //start vertex alone
+        let prefix: Vec<[usize; 2]> = if prefix.is_empty() { vec![self.start_coords] } else { prefix.to_vec() };
+        let mut validator: StreamingValidator = StreamingValidator::new(width, height);
+        for vertex in prefix.iter() {
+            validator.push(*vertex).map_err(SolveError::InvalidPrefix)?;
+        }
+        if prefix[0] != self.start_coords {
+            return Err(SolveError::InvalidPrefix(GridPathError::WrongEndpoints {
+                expected: (self.start_coords, self.end_coords),
+                actual: (prefix[0], prefix[prefix.len() - 1])
+            }));
+        }
+
+        //Check that the problem has a solution at all, and that the rest
+        //of the path is small enough to be searched
+        if !self.is_acceptable() {
+            return Err(SolveError::Infeasible);
+        }
+        let num_remaining: usize = (width * height) - prefix.len();
+        if num_remaining > options.max_bruteforce_cells {
+            return Err(SolveError::Unsupported { num_remaining: num_remaining, limit: options.max_bruteforce_cells });
+        }
+
+        //Search for the rest of the path from the end of the prefix
+        let mut visited: Vec<bool> = vec![false; width * height];
+        for vertex in prefix.iter() {
+            visited[self.grid_graph.cell_index(*vertex)] = true;
+        }
+        let mut path: Vec<[usize; 2]> = prefix;
+        let mut nodes_expanded: usize = 0;
+        let cancelled: AtomicBool = AtomicBool::new(false);
+        if GridProblem::search_brute_force(
+            width, height, self.end_coords, &mut visited, &mut path,
+            options.max_bruteforce_nodes_expanded, &mut nodes_expanded, &cancelled
+        )? {
+            return Ok(GridPath::new(width, height, path));
+        }
+        Err(SolveError::Infeasible)
+    }
+
+    /// Get the coordinates of the vertices adjacent to the given vertex
+    /// in a width by height grid
+    fn get_neighbors(width: usize, height: usize, v_coords: [usize; 2]) -> Vec<[usize; 2]> {
+        let mut neighbors: Vec<[usize; 2]> = Vec::new();
+        if v_coords[0] + 1 < width {
+            neighbors.push([v_coords[0] + 1, v_coords[1]]);
+        }
+        if v_coords[1] + 1 < height {
+            neighbors.push([v_coords[0], v_coords[1] + 1]);
+        }
+        if v_coords[0] > 0 {
+            neighbors.push([v_coords[0] - 1, v_coords[1]]);
+        }
+        if v_coords[1] > 0 {
+            neighbors.push([v_coords[0], v_coords[1] - 1]);
+        }
+        neighbors
+    }
+
+    /// Extend the given partial path depth-first until it visits every
+    /// vertex and finishes at the end vertex, returning whether it did.
+    /// Give up if more than the given number of nodes are expanded, and
+    /// stop without a path once the cancelled flag is set.
+    #[allow(clippy::too_many_arguments)]
+    fn search_brute_force(width: usize, height: usize, end_coords: [usize; 2], visited: &mut Vec<bool>, path: &mut Vec<[usize; 2]>, max_nodes_expanded: usize, nodes_expanded: &mut usize, cancelled: &AtomicBool) -> Result<bool, SolveError> {
+        //Stop if another search has already found a path
+        if cancelled.load(Ordering::Relaxed) {
+            return Ok(false);
+        }
+
+        //Count this node against the search budget
+        *nodes_expanded += 1;
+        if *nodes_expanded > max_nodes_expanded {
+            return Err(SolveError::BudgetExceeded { limit: max_nodes_expanded });
+        }
+
+        //Check if the path is complete or has reached the end vertex early
+        let head: [usize; 2] = path[path.len() - 1];
+        if path.len() == width * height {
+            return Ok(head == end_coords);
+        }
+        if head == end_coords {
+            return Ok(false);
+        }
+
+        //Prune the search if some unvisited vertex can no longer be passed
+        //through, or the end vertex can no longer be reached
+        for (i, v_coords) in GridGraph::cells_with_dimensions(width, height).enumerate() {
+            if visited[i] {
                 continue;
             }
-            if self.can_be_split_vertically() {
-                let (mut p_left, mut p_right): (GridProblem, GridProblem) = self.split_vertically().unwrap();
-                let p_left_solution: GridPath = p_left.solve().unwrap();
-                let p_right_solution: GridPath = p_right.solve().unwrap();
-                let vertex_order: Vec<[usize; 2]> = if self.start_coords[0] < self.end_coords[0] {
-                    let mut tmp_vertex_order: Vec<[usize; 2]> = p_left_solution.vertex_order;
-                    tmp_vertex_order.extend(p_right_solution.get_right_shift_vertex_order(p_left.grid_graph.get_width()));
-                    tmp_vertex_order
-                } else {
-                    let mut tmp_vertex_order: Vec<[usize; 2]> = p_right_solution.get_right_shift_vertex_order(p_left.grid_graph.get_width());
-                    tmp_vertex_order.extend(p_left_solution.vertex_order);
-                    tmp_vertex_order
-                };
-                let solution_path = GridPath::new(
-                    p_left.grid_graph.get_width() + p_right.grid_graph.get_width(),
-                    p_left.grid_graph.get_height(),
-                    vertex_order
-                );
-                solution = Some(solution_path);
+            let free: usize = GridProblem::get_neighbors(width, height, v_coords).iter()
+                .filter(|w| !visited[GridGraph::cell_index_with_width(width, **w)] || **w == head)
+                .count();
+            if free < (if v_coords == end_coords { 1 } else { 2 }) {
+                return Ok(false);
+            }
+        }
+
+        //Prune the search if the unvisited vertices are not all reachable
+        //from the head of the path
+        let mut reached: Vec<bool> = visited.clone();
+        let mut stack: Vec<[usize; 2]> = vec![head];
+        let mut reached_count: usize = path.len();
+        while let Some(v_coords) = stack.pop() {
+            for w in GridProblem::get_neighbors(width, height, v_coords) {
+                if !reached[GridGraph::cell_index_with_width(width, w)] {
+                    reached[GridGraph::cell_index_with_width(width, w)] = true;
+                    reached_count += 1;
+                    stack.push(w);
+                }
+            }
+        }
+        if reached_count != width * height {
+            return Ok(false);
+        }
+
+        //Try extending the path to each unvisited neighbor of its head
+        for w in GridProblem::get_neighbors(width, height, head) {
+            if visited[GridGraph::cell_index_with_width(width, w)] {
                 continue;
             }
+            visited[GridGraph::cell_index_with_width(width, w)] = true;
+            path.push(w);
+            if GridProblem::search_brute_force(width, height, end_coords, visited, path, max_nodes_expanded, nodes_expanded, cancelled)? {
+                return Ok(true);
+            }
+            path.pop();
+            visited[GridGraph::cell_index_with_width(width, w)] = false;
+        }
+        Ok(false)
+    }
 
-            //Check if either of the dimensions of the grid graph is 1, if so then solve it
-            //and set the solution path
-            if width == 1 || height == 1 {
-                let is_width: bool = width == 1;
-                let path: Vec<[usize; 2]> = {
-                    let mut path_vec: Vec<[usize; 2]> = Vec::new();
-                    let bound: usize = if is_width { height } else { width };
-                    let range = if is_width && self.start_coords[1] != 0 { (0..bound).rev().collect::<Vec<_>>() }
-                                else if !is_width && self.start_coords[0] != 0 { (0..bound).rev().collect::<Vec<_>>() }
-                                else { (0..bound).collect::<Vec<_>>() };
-                    for i in range {
-                        let vertex_coords: [usize; 2] = if is_width { [0, i] } else { [i, 0] };
-                        path_vec.push(vertex_coords);
-                    }
-                    path_vec
-                };
-                solution = Some(GridPath::new(width, height, path));
+    /// Solve the two sub-problems of a split of the grid problem and join
+    /// their solutions.  The sub-problems are given below then above for a
+    /// horizontal split, or left then right for a vertical split.  None is
+    /// returned if solving either sub-problem recorded an error.
+    fn join_split(&self, mut p_first: GridProblem, mut p_second: GridProblem, is_horizontal: bool, context: &mut SolveContext) -> Option<GridPath> {
+        //Solve the sub-problems one level deeper than this problem
+        context.depth += 1;
+        let p_first_solution: Option<GridPath> = p_first.solve_in_context(context);
+        let p_second_solution: Option<GridPath> = match p_first_solution {
+            Some(_) => p_second.solve_in_context(context),
+            None => None
+        };
+        context.depth -= 1;
+        let (p_first_solution, p_second_solution): (GridPath, GridPath) = (p_first_solution?, p_second_solution?);
+
+        //Join the solutions in the direction of the grid problem
+        if is_horizontal {
+            let vertex_order: Vec<[usize; 2]> = if self.start_coords[1] < self.end_coords[1] {
+                let mut tmp_vertex_order: Vec<[usize; 2]> = p_first_solution.into_vertex_order();
+                tmp_vertex_order.extend(p_second_solution.get_up_shift_vertex_order(p_first.grid_graph.get_height()));
+                tmp_vertex_order
+            } else {
+                let mut tmp_vertex_order: Vec<[usize; 2]> = p_second_solution.get_up_shift_vertex_order(p_first.grid_graph.get_height());
+                tmp_vertex_order.extend(p_first_solution.into_vertex_order());
+                tmp_vertex_order
+            };
+            let joined: GridPath = GridPath::new(
+                p_first.grid_graph.get_width(),
+                p_first.grid_graph.get_height() + p_second.grid_graph.get_height(),
+                vertex_order
+            );
+            context.check(&joined, Some((self.start_coords, self.end_coords)), self.grid_graph.get_width(), self.grid_graph.get_height(), || String::from("horizontal split"));
+            return Some(joined);
+        }
+        let vertex_order: Vec<[usize; 2]> = if self.start_coords[0] < self.end_coords[0] {
+            let mut tmp_vertex_order: Vec<[usize; 2]> = p_first_solution.into_vertex_order();
+            tmp_vertex_order.extend(p_second_solution.get_right_shift_vertex_order(p_first.grid_graph.get_width()));
+            tmp_vertex_order
+        } else {
+            let mut tmp_vertex_order: Vec<[usize; 2]> = p_second_solution.get_right_shift_vertex_order(p_first.grid_graph.get_width());
+            tmp_vertex_order.extend(p_first_solution.into_vertex_order());
+            tmp_vertex_order
+        };
+        let joined: GridPath = GridPath::new(
+            p_first.grid_graph.get_width() + p_second.grid_graph.get_width(),
+            p_first.grid_graph.get_height(),
+            vertex_order
+        );
+        context.check(&joined, Some((self.start_coords, self.end_coords)), self.grid_graph.get_width(), self.grid_graph.get_height(), || String::from("vertical split"));
+        Some(joined)
+    }
+
+    /// Split the grid problem at a position drawn at random from those at
+    /// which it can be split, given as for `join_split` with whether the
+    /// split is horizontal.  Positions are drawn without replacement until
+    /// one splits, so only the sub-problems of that split are built, and
+    /// each split is equally likely.  The positions probed, and those a
+    /// scan of every position would probe, are added to the given counts.
+    fn split_at_random(&self, rng: &mut GridRng, num_candidates: &mut usize, num_probes: &mut usize) -> Option<(GridProblem, GridProblem, bool)> {
+        let mut positions: Vec<(bool, usize, usize)> = Vec::new();
+        for is_horizontal in [true, false] {
+            *num_candidates += self.count_split_candidates(is_horizontal, None);
+            positions.extend(self.get_split_positions(is_horizontal).map(|(i, j)| (is_horizontal, i, j)));
+        }
+        while positions.len() > 0 {
+            let (is_horizontal, i, j): (bool, usize, usize) = positions.swap_remove(rng.next_below(positions.len()));
+            *num_probes += 1;
+            if let Some((p_first, p_second)) = self.split_at(is_horizontal, i, j) {
+                return Some((p_first, p_second, is_horizontal));
+            }
+        }
+        None
+    }
+
+    /// Solve the grid problem by stripping and splitting it
+    /// into sub-problems
+    pub fn solve(&mut self) -> Option<GridPath> {
+        self.solve_in_context(&mut SolveContext::new(self.grid_graph.get_width() * self.grid_graph.get_height()))
+    }
+
+    /// Solve the grid problem as in `solve`.  If the options are paranoid
+    /// then every intermediate path is checked, from the prime solutions
+    /// looked up to the path after each single extension, and the first
+    /// which is not a valid path is reported as an `InvalidIntermediate`
+    /// error naming the stage and sub-problem which produced it.  Whether
+    /// or not the options are paranoid, an internal error which stopped the
+    /// solve, such as a 1 wide sub-problem whose endpoints are not its two
+    /// ends, is returned rather than a solution.
+    pub fn solve_with_options(&mut self, options: &SolveOptions) -> Result<Option<GridPath>, SolveError> {
+        let mut context: SolveContext = SolveContext {
+            paranoid: options.paranoid,
+            ..SolveContext::new(self.grid_graph.get_width() * self.grid_graph.get_height())
+        };
+        let solution: Option<GridPath> = self.solve_in_context(&mut context);
+        match context.violation {
+            Some(e) => Err(e),
+            None => Ok(solution)
+        }
+    }
+
+    /// Solve the grid problem as in `solve`, also returning the wall time
+    /// taken, the number of split positions probed and, when built with the
+    /// `stats-alloc` feature, the peak number of additional bytes allocated
+    /// during the solve
+    pub fn solve_with_stats(&mut self) -> (Option<GridPath>, SolveStats) {
+        #[cfg(feature = "stats-alloc")]
+        let base_bytes: usize = {
+            crate::countingallocator::ALLOCATOR.reset_peak();
+            crate::countingallocator::ALLOCATOR.get_current()
+        };
+
+        let mut context: SolveContext = SolveContext::new(self.grid_graph.get_width() * self.grid_graph.get_height());
+        let start_time: Instant = Instant::now();
+        let solution: Option<GridPath> = self.solve_in_context(&mut context);
+        let wall_time: Duration = start_time.elapsed();
+
+        #[cfg(feature = "stats-alloc")]
+        let peak_bytes_allocated: Option<usize> = Some(
+            crate::countingallocator::ALLOCATOR.get_peak().saturating_sub(base_bytes)
+        );
+        #[cfg(not(feature = "stats-alloc"))]
+        let peak_bytes_allocated: Option<usize> = None;
+
+        let stats: SolveStats = SolveStats {
+            num_cells: self.grid_graph.get_width() * self.grid_graph.get_height(),
+            wall_time: wall_time,
+            peak_bytes_allocated: peak_bytes_allocated,
+            num_split_candidates: context.num_split_candidates,
+            num_split_probes: context.num_split_probes
+        };
+        (solution, stats)
+    }
+
+    /// Sample a random solution to the grid problem by splitting it at
+    /// randomly chosen points wherever it can be split, so that different
+    /// states of the given random number generator yield different paths
+    pub fn sample_solution(&mut self, rng: &mut GridRng) -> Option<GridPath> {
+        self.solve_in_context(&mut SolveContext {
+            rng: Some(rng),
+            ..SolveContext::new(self.grid_graph.get_width() * self.grid_graph.get_height())
+        })
+    }
+
+    /// Solve the grid problem with every random choice drawn from a
+    /// `GridRng` seeded with the given seed, as in `sample_solution`, so
+    /// that the same seed always yields the same path for a problem
+    pub fn solve_with_seed(&mut self, seed: u64) -> Option<GridPath> {
+        self.sample_solution(&mut GridRng::new(seed))
+    }
+
+    /// Solve the grid problem, notifying the given observer each time
+    /// one of its sub-problems is completed
+    pub fn solve_with_observer(&mut self, observer: &mut dyn SolveObserver) -> Option<GridPath> {
+        self.solve_in_context(&mut SolveContext {
+            observer: Some(observer),
+            ..SolveContext::new(self.grid_graph.get_width() * self.grid_graph.get_height())
+        })
+    }
+
+    /// Check a path solving this problem as it currently stands, before any
+    /// extensions, if the context is paranoid
+    fn check_in_context(&self, solution: Option<&GridPath>, context: &mut SolveContext, stage: &str) {
+        if let Some(path) = solution {
+            let endpoints: ([usize; 2], [usize; 2]) = (self.start_coords, self.end_coords);
+            context.check(path, Some(endpoints), self.grid_graph.get_width(), self.grid_graph.get_height(), || String::from(stage));
+        }
+    }
+
+    /// Undo the strips taken off the grid problem by extending the given
+    /// path over them, in paranoid mode checking the path after each single
+    /// extension against the dimensions of the grid it was extended to
+    fn extend_in_context(&self, path: &mut GridPath, context: &mut SolveContext) {
+        if !context.paranoid {
+            path.extend_many(&self.extensions);
+            return;
+        }
+        for (i, direction) in self.extensions.iter().rev().enumerate() {
+            path.extend(*direction);
+            let (width, height): (usize, usize) = (path.get_width(), path.get_height());
+            context.check(path, None, width, height, || format!("extension #{} ({:?})", i + 1, direction));
+        }
+    }
+
+    /// Solve the grid problem within the given context, splitting it at
+    /// random if the context has a random number generator and otherwise
+    /// at the first point found
+    fn solve_in_context(&mut self, context: &mut SolveContext) -> Option<GridPath> {
+        //If the problem is not acceptable, then there is no solution
+        if !self.is_acceptable() {
+            return None;
+        }
+
+        //Always solve from the lesser of the two endpoints so that the
+        //swapped problem yields exactly the reverse of this solution
+        if self.end_coords < self.start_coords {
+            let swapped_solution: GridPath = self.swap_start_end().solve_in_context(context)?;
+            let mut vertex_order: Vec<[usize; 2]> = swapped_solution.into_vertex_order();
+            vertex_order.reverse();
+            let solution_path: GridPath = GridPath::new(
+                self.grid_graph.get_width(),
+                self.grid_graph.get_height(),
+                vertex_order
+            );
+            context.check(&solution_path, Some((self.start_coords, self.end_coords)), self.grid_graph.get_width(), self.grid_graph.get_height(), || String::from("reversal of the swapped solution"));
+            return Some(solution_path);
+        }
+
+        //Solve the problem through a guard, so that it is reconstructed
+        //even if the solve unwinds while the problem is stripped
+        let guard: StripGuard = StripGuard { problem: self };
+        guard.problem.solve_stripping(context)
+    }
+
+    /// Solve an acceptable grid problem whose start vertex is the lesser
+    /// of its endpoints within the given context, stripping it as needed
+    /// and reconstructing it once solved
+    fn solve_stripping(&mut self, context: &mut SolveContext) -> Option<GridPath> {
+        //Note the number of vertices in the problem, and initialize the
+        //number of them which are handed off to sub-problems
+        let num_cells: usize = self.grid_graph.get_width() * self.grid_graph.get_height();
+        let mut num_split_cells: usize = 0;
+
+        //Initialize mutable grid graph, solution path, & collection of extensions
+        let mut solution: Option<GridPath> = None;
+        
+        //Loop until solved
+        loop {
+            //Check if there is a solution path
+            let is_solution: bool = match solution {
+                Some(ref _x) => true,
+                None => false
+            };
+
+            //If there is a solution path then extend it as needed and return it
+            if is_solution {
+                //Unwrap the solution path and extend it if any strips were performed
+                let mut solution_path: GridPath = solution.unwrap();
+                self.extend_in_context(&mut solution_path, context);
+
+                //Reconstruct the original GridProblem after having stripped it,
+                //recording a mismatch as an error which stops the solve
+                if let Err(e) = self.reconstruct() {
+                    context.record(e);
+                    return None;
+                }
+                context.check(&solution_path, Some((self.start_coords, self.end_coords)), self.grid_graph.get_width(), self.grid_graph.get_height(), || String::from("reconstruction"));
+
+                //Report the vertices covered by this problem and not by its
+                //sub-problems as completed
+                context.cells_completed += num_cells - num_split_cells;
+                if let Some(observer) = context.observer.as_deref_mut() {
+                    observer.on_progress(ProgressEvent {
+                        cells_completed: context.cells_completed,
+                        cells_total: context.cells_total,
+                        depth: context.depth
+                    });
+                }
+                return Some(solution_path);
+            }
+
+            //If sampling a random solution then split the problem at random
+            //wherever it can be split
+            if let Some(ref mut rng) = context.rng {
+                if let Some((p_first, p_second, is_horizontal)) = self.split_at_random(rng, &mut context.num_split_candidates, &mut context.num_split_probes) {
+                    num_split_cells = num_cells;
+                    solution = Some(self.join_split(p_first, p_second, is_horizontal, context)?);
+                    continue;
+                }
+            }
+
+            //If either dimension of the grid graph is 1 then the path simply
+            //runs from one end of the grid to the other
+            if self.grid_graph.get_width() == 1 || self.grid_graph.get_height() == 1 {
+                solution = self.solve_one_wide();
+                if solution.is_none() {
+                    context.record(SolveError::OneWideEndpoints {
+                        width: self.grid_graph.get_width(),
+                        height: self.grid_graph.get_height(),
+                        endpoints: (self.start_coords, self.end_coords)
+                    });
+                    return None;
+                }
+                self.check_in_context(solution.as_ref(), context, "one wide solve");
+                continue;
+            }
+
+            //If either dimension of the grid graph is 2 then solve it directly
+            //rather than stripping it down to a prime problem
+            if self.grid_graph.get_width() == 2 || self.grid_graph.get_height() == 2 {
+                solution = Some(self.solve_two_wide());
+                self.check_in_context(solution.as_ref(), context, "two wide solve");
+                continue;
+            }
+
+            //Likewise if either dimension of the grid graph is 3 then solve it
+            //directly by sweeping across it
+            if self.grid_graph.get_width() == 3 || self.grid_graph.get_height() == 3 {
+                solution = Some(self.solve_three_wide());
+                self.check_in_context(solution.as_ref(), context, "three wide solve");
+                continue;
+            }
+
+            //If there is no solution then first strip the problem as much as possible,
+            //and if it was stripped then check again whether it can be solved directly
+            let mut is_stripped: bool = false;
+            loop {
+                if !self.strip() {
+                    break;
+                }
+                is_stripped = true;
+            }
+            if is_stripped {
+                continue;
+            }
+
+            //Get the width and height of the grid graph
+            let width: usize = self.grid_graph.get_width();
+            let height: usize = self.grid_graph.get_height();
+
+            //After stripping is complete, check if the problem is prime.  If
+            //so then lookup its solution and continue.
+            if GridPath::is_prime(width, height, self.start_coords, self.end_coords) {
+                solution = GridPath::get_prime(width, height, self.start_coords, self.end_coords);
+                self.check_in_context(solution.as_ref(), context, "prime lookup");
+                continue;
+            }
+
+            //If the GridProblem is not prime, break it into subproblems by splitting it
+            if let Some((p_below, p_above)) = self.find_split(true, context) {
+                num_split_cells = width * height;
+                solution = Some(self.join_split(p_below, p_above, true, context)?);
+                continue;
+            }
+            if let Some((p_left, p_right)) = self.find_split(false, context) {
+                num_split_cells = width * height;
+                solution = Some(self.join_split(p_left, p_right, false, context)?);
                 continue;
             }
 
@@ -675,4 +1909,1477 @@ impl GridProblem {
             process::exit(1);
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::acceptablepairs::AcceptablePairs;
+    use crate::glyphset::GlyphSet;
+    use crate::pairoptions::PairOptions;
+    use crate::renderoptions::RenderOptions;
+    use crate::schema;
+    use crate::solveobserver::ChannelObserver;
+
+    /// Check that a vertex order visits every vertex of a width by height
+    /// grid exactly once, stepping between adjacent vertices, and runs
+    /// from the given start vertex to the given end vertex
+    fn is_hamiltonian_path(width: usize, height: usize, start_coords: [usize; 2], end_coords: [usize; 2], vertex_order: &[[usize; 2]]) -> bool {
+        //The path must have one vertex per grid vertex and the given endpoints
+        if vertex_order.len() != width * height ||
+           vertex_order.first() != Some(&start_coords) || vertex_order.last() != Some(&end_coords) {
+            return false;
+        }
+
+        //Each vertex must be in bounds and visited only once
+        let mut visited: Vec<bool> = vec![false; width * height];
+        for vertex in vertex_order.iter() {
+            if vertex[0] >= width || vertex[1] >= height || visited[GridGraph::cell_index_with_width(width, *vertex)] {
+                return false;
+            }
+            visited[GridGraph::cell_index_with_width(width, *vertex)] = true;
+        }
+
+        //Each consecutive pair of vertices must be adjacent
+        vertex_order.windows(2).all(|pair| pair[0][0].abs_diff(pair[1][0]) + pair[0][1].abs_diff(pair[1][1]) == 1)
+    }
+
+    #[test]
+    fn solve_with_stats_hundred() {
+        let mut problem: GridProblem = GridProblem::new(100, 100, [0, 0], [99, 0]);
+        let (solution, stats) = problem.solve_with_stats();
+
+        //Assert the solution is found and the throughput is positive
+        assert_eq!(solution.is_some(), true);
+        assert_eq!(stats.num_cells, 10000);
+        assert_eq!(stats.get_cells_per_second() > 0.0, true);
+
+        //Assert the peak is only measured with the counting allocator
+        assert_eq!(stats.peak_bytes_allocated.is_some(), cfg!(feature = "stats-alloc"));
+        let parsed: json::JsonValue = json::parse(&stats.to_json()).unwrap();
+        assert_eq!(parsed["cells_per_second"].as_f64().unwrap() > 0.0, true);
+    }
+
+    #[test]
+    fn solve_with_stats_split_probes() {
+        //A problem with far apart endpoints is split many times, and most
+        //positions along each cut are skipped without being probed
+        let mut problem: GridProblem = GridProblem::new(100, 100, [1, 0], [99, 99]);
+        let (solution, stats) = problem.solve_with_stats();
+        assert_eq!(solution.is_some(), true);
+        assert_eq!(stats.num_split_probes > 0, true);
+        assert_eq!(stats.num_split_probes * 2 <= stats.num_split_candidates, true);
+    }
+
+    #[test]
+    fn get_grid_graph_after_strip() {
+        //The grid graph should shrink as the problem is stripped
+        let mut my_grid_problem: GridProblem = GridProblem::new(7, 6, [0, 0], [1, 0]);
+        assert_eq!(my_grid_problem.get_grid_graph().get_width(), 7);
+        assert_eq!(my_grid_problem.get_grid_graph().get_height(), 6);
+        assert_eq!(my_grid_problem.strip(), true);
+        let stripped_width: usize = my_grid_problem.get_grid_graph().get_width();
+        let stripped_height: usize = my_grid_problem.get_grid_graph().get_height();
+        assert_eq!(stripped_width * stripped_height < 7 * 6, true);
+        assert_eq!(stripped_width, my_grid_problem.get_width());
+        assert_eq!(stripped_height, my_grid_problem.get_height());
+
+        //Its methods can be used on the current state of the problem
+        assert_eq!(my_grid_problem.get_grid_graph().is_corner_vertex(my_grid_problem.get_start_coords()), true);
+    }
+
+    #[test]
+    fn into_parts_after_strip() {
+        //A fresh problem has no extensions
+        let my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [1, 0]);
+        let (grid_graph, extensions, start_coords, end_coords) = my_grid_problem.into_parts();
+        assert_eq!((grid_graph.get_width(), grid_graph.get_height()), (3, 2));
+        assert_eq!(extensions, Vec::new());
+        assert_eq!((start_coords, end_coords), ([0, 0], [1, 0]));
+
+        //A stripped problem gives up its stripped grid graph and the
+        //extensions taken from it
+        let mut my_grid_problem: GridProblem = GridProblem::new(7, 6, [0, 0], [1, 0]);
+        my_grid_problem.strip();
+        let (width, height): (usize, usize) = (my_grid_problem.get_width(), my_grid_problem.get_height());
+        let (grid_graph, extensions, _, _) = my_grid_problem.into_parts();
+        assert_eq!((grid_graph.get_width(), grid_graph.get_height()), (width, height));
+        assert_eq!(extensions.len() > 0, true);
+    }
+
+    #[test]
+    fn suggest_endpoints_even_grid() {
+        //On a 4 by 4 grid the endpoints (0, 0) and (1, 1) are the same
+        //color, so moving either one by a single vertex fixes the problem
+        let my_grid_problem: GridProblem = GridProblem::new(4, 4, [0, 0], [1, 1]);
+        assert_eq!(my_grid_problem.is_acceptable(), false);
+        let suggestions: Vec<([usize; 2], [usize; 2], usize)> = my_grid_problem.suggest_endpoints(1);
+        assert_eq!(suggestions, vec![
+            ([0, 0], [1, 0], 1), ([0, 0], [0, 1], 1), ([0, 0], [2, 1], 1), ([0, 0], [1, 2], 1),
+            ([1, 0], [1, 1], 1), ([0, 1], [1, 1], 1)
+        ]);
+
+        //Suggestions further away come after the single vertex moves, and
+        //every suggestion is an acceptable problem
+        let suggestions: Vec<([usize; 2], [usize; 2], usize)> = my_grid_problem.suggest_endpoints(3);
+        assert_eq!(suggestions[..6].iter().all(|suggestion| suggestion.2 == 1), true);
+        assert_eq!(suggestions.windows(2).all(|pair| pair[0].2 <= pair[1].2), true);
+        for (start, end, distance) in suggestions {
+            assert_eq!(GridProblem::new(4, 4, start, end).is_acceptable(), true);
+            assert_eq!(distance, start[0].abs_diff(0) + start[1].abs_diff(0) + end[0].abs_diff(1) + end[1].abs_diff(1));
+        }
+    }
+
+    #[test]
+    fn suggest_endpoints_acceptable() {
+        //An acceptable problem suggests its own endpoints first
+        let my_grid_problem: GridProblem = GridProblem::new(5, 4, [0, 0], [4, 3]);
+        assert_eq!(my_grid_problem.suggest_endpoints(2)[0], ([0, 0], [4, 3], 0));
+
+        //No suggestions are made within a distance of 0 otherwise
+        let my_grid_problem: GridProblem = GridProblem::new(5, 4, [0, 0], [1, 1]);
+        assert_eq!(my_grid_problem.suggest_endpoints(0), Vec::new());
+
+        //A distance beyond the grid suggests every acceptable pair, without
+        //overflowing
+        assert_eq!(my_grid_problem.suggest_endpoints(usize::MAX), my_grid_problem.suggest_endpoints(14));
+        assert_eq!(my_grid_problem.suggest_endpoints(usize::MAX).len(), AcceptablePairs::new(5, 4, PairOptions::default()).count());
+    }
+
+    /// The dimensions and endpoints of a sub-problem
+    type SubProblemShape = (usize, usize, [usize; 2], [usize; 2]);
+
+    /// Split a grid problem using the width and height arithmetic which
+    /// the splits used before they were expressed with rectangles
+    fn reference_split(p: &GridProblem, is_horizontal: bool) -> Option<(SubProblemShape, SubProblemShape)> {
+        let (width, height): (usize, usize) = (p.grid_graph.get_width(), p.grid_graph.get_height());
+        let (start, end): ([usize; 2], [usize; 2]) = (p.start_coords, p.end_coords);
+        let axis: usize = if is_horizontal { 1 } else { 0 };
+        let is_start_near: bool = start[axis] < end[axis];
+        for i in start[axis].min(end[axis])..start[axis].max(end[axis]) {
+            for j in 0..(if is_horizontal { width } else { height }) {
+                let near: [usize; 2] = if is_horizontal { [j, i] } else { [i, j] };
+                let far: [usize; 2] = if is_horizontal { [j, i + 1] } else { [i + 1, j] };
+                if near == start || far == start || near == end || far == end {
+                    continue;
+                }
+                let (near_shape, far_shape): (SubProblemShape, SubProblemShape) = if is_horizontal {
+                    (
+                        if is_start_near { (width, far[1], start, near) } else { (width, far[1], near, end) },
+                        if is_start_near {
+                            (width, height - far[1], [far[0], 0], [end[0], end[1] - far[1]])
+                        } else {
+                            (width, height - far[1], [start[0], start[1] - far[1]], [far[0], 0])
+                        }
+                    )
+                } else {
+                    (
+                        if is_start_near { (far[0], height, start, near) } else { (far[0], height, near, end) },
+                        if is_start_near {
+                            (width - far[0], height, [0, far[1]], [end[0] - far[0], end[1]])
+                        } else {
+                            (width - far[0], height, [start[0] - far[0], start[1]], [0, far[1]])
+                        }
+                    )
+                };
+                let is_acceptable = |shape: SubProblemShape| GridProblem::new(shape.0, shape.1, shape.2, shape.3).is_acceptable();
+                if is_acceptable(near_shape) && is_acceptable(far_shape) {
+                    return Some((near_shape, far_shape));
+                }
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn split_positions_skip_only_failing_splits() {
+        //The positions skipped along each cut should be exactly those a scan
+        //of every position finds no split at
+        for width in 1..7 {
+            for height in 1..7 {
+                for (start, end) in AcceptablePairs::new(width, height, PairOptions::default()) {
+                    let my_grid_problem: GridProblem = GridProblem::new(width, height, start, end);
+                    for is_horizontal in [true, false] {
+                        let cut_length: usize = if is_horizontal { width } else { height };
+                        let (range_start, range_end): (usize, usize) = my_grid_problem.get_split_range(is_horizontal);
+                        let splitting: Vec<(usize, usize)> = (range_start..range_end)
+                            .flat_map(|i| (0..cut_length).map(move |j| (i, j)))
+                            .filter(|(i, j)| my_grid_problem.split_at(is_horizontal, *i, *j).is_some())
+                            .collect();
+                        let positions: Vec<(usize, usize)> = my_grid_problem.get_split_positions(is_horizontal).collect();
+                        assert_eq!(positions.iter().filter(|(i, j)| my_grid_problem.split_at(is_horizontal, *i, *j).is_some()).copied().collect::<Vec<(usize, usize)>>(), splitting);
+
+                        //The positions given should be in scan order
+                        assert_eq!(positions.windows(2).all(|pair| pair[0] < pair[1]), true);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn splits_match_reference() {
+        //Every problem on grids up to 6 by 6 should split into sub-problems
+        //of the same dimensions and endpoints as before
+        let shape_of = |p: &GridProblem| (p.grid_graph.get_width(), p.grid_graph.get_height(), p.start_coords, p.end_coords);
+        for width in 1..7 {
+            for height in 1..7 {
+                for (start, end) in AcceptablePairs::new(width, height, PairOptions::default()) {
+                    let my_grid_problem: GridProblem = GridProblem::new(width, height, start, end);
+                    for is_horizontal in [true, false] {
+                        let split: Option<(GridProblem, GridProblem)> = if is_horizontal {
+                            my_grid_problem.split_horizontally()
+                        } else {
+                            my_grid_problem.split_vertically()
+                        };
+                        assert_eq!(
+                            split.map(|(p_first, p_second)| (shape_of(&p_first), shape_of(&p_second))),
+                            reference_split(&my_grid_problem, is_horizontal)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn complete_prefixes() {
+        //Prefixes of several lengths on a 5 by 5 grid should be completed
+        //into Hamiltonian paths which begin with them
+        let my_grid_problem: GridProblem = GridProblem::new(5, 5, [0, 0], [4, 4]);
+        let prefixes: Vec<Vec<[usize; 2]>> = vec![
+            vec![[0, 0]],
+            vec![[0, 0], [1, 0], [2, 0], [3, 0], [4, 0]],
+            vec![
+                [0, 0], [0, 1], [0, 2], [0, 3], [0, 4], [1, 4],
+                [1, 3], [1, 2], [1, 1], [1, 0], [2, 0], [3, 0]
+            ],
+            my_grid_problem.solve_brute_force().unwrap().into_vertex_order()
+        ];
+        for prefix in prefixes {
+            let solution: GridPath = my_grid_problem.complete(&GridPath::new(5, 5, prefix.clone())).unwrap();
+            assert!(is_hamiltonian_path(5, 5, [0, 0], [4, 4], solution.get_vertex_order()));
+            assert_eq!(solution.get_vertex_order()[..prefix.len()], prefix[..]);
+        }
+
+        //An empty prefix is completed from the start vertex
+        let solution: GridPath = my_grid_problem.complete(&GridPath::new(5, 5, Vec::new())).unwrap();
+        assert!(is_hamiltonian_path(5, 5, [0, 0], [4, 4], solution.get_vertex_order()));
+    }
+
+    #[test]
+    fn complete_impossible_prefix() {
+        //A prefix which walks past the top left corner cuts it off
+        let my_grid_problem: GridProblem = GridProblem::new(5, 5, [0, 0], [4, 4]);
+        let prefix: GridPath = GridPath::new(5, 5, vec![[0, 0], [0, 1], [0, 2], [0, 3], [1, 3], [1, 4]]);
+        assert_eq!(my_grid_problem.complete(&prefix).err(), Some(SolveError::Infeasible));
+
+        //A prefix which reaches the end vertex early cannot be completed
+        let my_grid_problem: GridProblem = GridProblem::new(5, 5, [0, 0], [2, 0]);
+        let prefix: GridPath = GridPath::new(5, 5, vec![[0, 0], [1, 0], [2, 0]]);
+        assert_eq!(my_grid_problem.complete(&prefix).err(), Some(SolveError::Infeasible));
+    }
+
+    #[test]
+    fn complete_invalid_prefix() {
+        //The prefix should start at the start vertex
+        let my_grid_problem: GridProblem = GridProblem::new(5, 5, [0, 0], [4, 4]);
+        let prefix: GridPath = GridPath::new(5, 5, vec![[1, 0], [2, 0]]);
+        assert_eq!(
+            my_grid_problem.complete(&prefix).err(),
+            Some(SolveError::InvalidPrefix(GridPathError::WrongEndpoints { expected: ([0, 0], [4, 4]), actual: ([1, 0], [2, 0]) }))
+        );
+
+        //The prefix should be a simple path
+        let prefix: GridPath = GridPath::new(5, 5, vec![[0, 0], [1, 0], [0, 0]]);
+        assert_eq!(my_grid_problem.complete(&prefix).err(), Some(SolveError::InvalidPrefix(GridPathError::RepeatedVertex([0, 0]))));
+        let prefix: GridPath = GridPath::new(5, 5, vec![[0, 0], [1, 1]]);
+        assert_eq!(my_grid_problem.complete(&prefix).err(), Some(SolveError::InvalidPrefix(GridPathError::NonAdjacent([0, 0], [1, 1]))));
+
+        //Too large a remainder is not supported
+        let my_grid_problem: GridProblem = GridProblem::new(7, 7, [0, 0], [6, 6]);
+        let prefix: GridPath = GridPath::new(7, 7, vec![[0, 0], [1, 0]]);
+        assert_eq!(my_grid_problem.complete(&prefix).err(), Some(SolveError::Unsupported { num_remaining: 47, limit: 42 }));
+    }
+
+    #[test]
+    fn solve_with_hint() {
+        //A valid hint should begin the solution
+        let mut my_grid_problem: GridProblem = GridProblem::new(5, 4, [0, 0], [4, 3]);
+        let hint: Vec<[usize; 2]> = vec![[0, 0], [0, 1], [0, 2], [0, 3], [1, 3]];
+        let solution: GridPath = my_grid_problem.solve_with_hint(hint.clone()).unwrap();
+        assert!(is_hamiltonian_path(5, 4, [0, 0], [4, 3], solution.get_vertex_order()));
+        assert_eq!(solution.get_vertex_order()[..hint.len()], hint[..]);
+
+        //Hints which are out of bounds, not from the start vertex, or
+        //which cut off part of the grid give no solution
+        assert!(my_grid_problem.solve_with_hint(vec![[0, 0], [0, 1], [0, 2], [0, 3], [0, 4]]).is_none());
+        assert!(my_grid_problem.solve_with_hint(vec![[1, 0], [2, 0]]).is_none());
+        assert!(my_grid_problem.solve_with_hint(vec![[0, 0], [1, 0], [2, 0], [3, 0], [3, 1], [4, 1]]).is_none());
+    }
+
+    #[test]
+    fn swap_start_end_coords() {
+        //Initialize a grid problem and swap its start and end vertices
+        let my_grid_problem: GridProblem = GridProblem::new(7, 5, [1, 0], [4, 3]);
+        let swapped_grid_problem: GridProblem = my_grid_problem.swap_start_end();
+
+        //The swapped problem should share the dimensions of the original
+        //and have its start and end coordinates exchanged
+        assert_eq!(swapped_grid_problem.grid_graph.get_width(), 7);
+        assert_eq!(swapped_grid_problem.grid_graph.get_height(), 5);
+        assert_eq!(swapped_grid_problem.start_coords, [4, 3]);
+        assert_eq!(swapped_grid_problem.end_coords, [1, 0]);
+    }
+
+    #[test]
+    fn swap_start_end_reverses_solution() {
+        //Initialize a handful of acceptable grid problems of varying shape
+        let grid_problems: Vec<GridProblem> = vec![
+            GridProblem::new(2, 2, [0, 0], [1, 0]),
+            GridProblem::new(3, 3, [0, 0], [2, 2]),
+            GridProblem::new(7, 7, [0, 0], [6, 6]),
+            GridProblem::new(6, 8, [5, 6], [0, 0]),
+            GridProblem::new(1, 6, [0, 5], [0, 0])
+        ];
+
+        //The solution of each swapped problem should be the reverse of
+        //the solution of the original problem
+        for mut my_grid_problem in grid_problems {
+            let mut swapped_grid_problem: GridProblem = my_grid_problem.swap_start_end();
+            let mut reversed_vertex_order: Vec<[usize; 2]> = my_grid_problem.solve().unwrap().into_vertex_order();
+            reversed_vertex_order.reverse();
+            assert_eq!(
+                swapped_grid_problem.solve().unwrap().into_vertex_order(),
+                reversed_vertex_order
+            );
+        }
+    }
+
+    #[test]
+    fn solve_two_wide_exhaustive() {
+        //Loop through every 2 by n and n by 2 grid graph up to n = 12
+        for length in 1..13 {
+            for (width, height) in [(2, length), (length, 2)] {
+                //Loop through every pair of distinct start and end vertices
+                for start_index in 0..(width * height) {
+                    for end_index in 0..(width * height) {
+                        if start_index == end_index {
+                            continue;
+                        }
+                        let start_coords: [usize; 2] = GridGraph::coords_of_with_width(width, start_index);
+                        let end_coords: [usize; 2] = GridGraph::coords_of_with_width(width, end_index);
+
+                        //Every acceptable problem should yield a valid path
+                        let mut my_grid_problem: GridProblem = GridProblem::new(width, height, start_coords, end_coords);
+                        if !my_grid_problem.is_acceptable() {
+                            continue;
+                        }
+                        let solution: GridPath = my_grid_problem.solve().unwrap();
+                        assert!(
+                            is_hamiltonian_path(width, height, start_coords, end_coords, solution.get_vertex_order()),
+                            "Invalid path on {} x {} from {:?} to {:?}", width, height, start_coords, end_coords
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_three_wide_exhaustive() {
+        //Loop through every 3 by n and n by 3 grid graph up to n = 12
+        for length in 1..13 {
+            for (width, height) in [(3, length), (length, 3)] {
+                //Loop through every pair of distinct start and end vertices
+                for start_index in 0..(width * height) {
+                    for end_index in 0..(width * height) {
+                        if start_index == end_index {
+                            continue;
+                        }
+                        let start_coords: [usize; 2] = GridGraph::coords_of_with_width(width, start_index);
+                        let end_coords: [usize; 2] = GridGraph::coords_of_with_width(width, end_index);
+
+                        //On smaller grids the problem should be acceptable exactly
+                        //when the brute force search finds a path
+                        let mut my_grid_problem: GridProblem = GridProblem::new(width, height, start_coords, end_coords);
+                        if length <= 7 {
+                            assert_eq!(
+                                my_grid_problem.is_acceptable(),
+                                my_grid_problem.solve_brute_force().is_some(),
+                                "Acceptability disagrees with brute force on {} x {} from {:?} to {:?}", width, height, start_coords, end_coords
+                            );
+                        }
+
+                        //Every acceptable problem should yield a valid path
+                        if !my_grid_problem.is_acceptable() {
+                            continue;
+                        }
+                        let solution: GridPath = my_grid_problem.solve().unwrap();
+                        assert!(
+                            is_hamiltonian_path(width, height, start_coords, end_coords, solution.get_vertex_order()),
+                            "Invalid path on {} x {} from {:?} to {:?}", width, height, start_coords, end_coords
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_brute_force_valid() {
+        //Initialize an acceptable grid problem and solve it by brute force
+        let my_grid_problem: GridProblem = GridProblem::new(5, 4, [0, 0], [4, 3]);
+        let solution: GridPath = my_grid_problem.solve_brute_force().unwrap();
+
+        //The solution should be a valid path between the endpoints
+        assert!(is_hamiltonian_path(5, 4, [0, 0], [4, 3], solution.get_vertex_order()));
+    }
+
+    #[test]
+    fn solve_brute_force_forbidden() {
+        //Initialize a color compatible but forbidden grid problem, which
+        //should have no solution
+        let my_grid_problem: GridProblem = GridProblem::new(2, 6, [0, 2], [1, 2]);
+        assert!(my_grid_problem.solve_brute_force().is_none());
+    }
+
+    #[test]
+    fn solve_parallel_brute_force_agrees() {
+        //The parallel search should find a path exactly when the serial
+        //search does, for every pair of distinct endpoints on a 4 by 4 grid
+        for start in GridGraph::cells_with_dimensions(4, 4) {
+            for end in GridGraph::cells_with_dimensions(4, 4).filter(|end| *end != start) {
+                let my_grid_problem: GridProblem = GridProblem::new(4, 4, start, end);
+                let serial: Option<GridPath> = my_grid_problem.solve_brute_force();
+                let parallel: Option<GridPath> = my_grid_problem.solve_parallel_brute_force();
+                assert_eq!(serial.is_some(), parallel.is_some());
+                if let Some(solution) = parallel {
+                    assert!(is_hamiltonian_path(4, 4, start, end, solution.get_vertex_order()));
+                }
+            }
+        }
+
+        //A single vertex grid is its own path
+        let my_grid_problem: GridProblem = GridProblem::new(1, 1, [0, 0], [0, 0]);
+        assert_eq!(my_grid_problem.solve_parallel_brute_force().unwrap().into_vertex_order(), vec![[0, 0]]);
+    }
+
+    #[test]
+    fn solve_parallel_brute_force_valid() {
+        //Solve a 5 by 5 problem with the parallel search
+        let my_grid_problem: GridProblem = GridProblem::new(5, 5, [0, 0], [4, 4]);
+        let solution: GridPath = my_grid_problem.solve_parallel_brute_force().unwrap();
+        assert!(is_hamiltonian_path(5, 5, [0, 0], [4, 4], solution.get_vertex_order()));
+
+        //A color incompatible problem has no path on any thread
+        let my_grid_problem: GridProblem = GridProblem::new(5, 5, [0, 0], [1, 0]);
+        assert!(my_grid_problem.solve_parallel_brute_force().is_none());
+    }
+
+    /// Check that the solver and the brute force search agree on whether
+    /// each problem on an n by m grid has a solution, and that whenever
+    /// they do both solutions are valid
+    fn stress_test_solver_consistency(n: usize, m: usize) {
+        //Loop through every pair of distinct start and end vertices
+        for start_index in 0..(n * m) {
+            for end_index in 0..(n * m) {
+                if start_index == end_index {
+                    continue;
+                }
+                let start_coords: [usize; 2] = GridGraph::coords_of_with_width(n, start_index);
+                let end_coords: [usize; 2] = GridGraph::coords_of_with_width(n, end_index);
+
+                //Solve the problem both ways and check they agree
+                let mut my_grid_problem: GridProblem = GridProblem::new(n, m, start_coords, end_coords);
+                let solution: Option<GridPath> = my_grid_problem.solve();
+                let brute_force_solution: Option<GridPath> = my_grid_problem.solve_brute_force();
+                assert_eq!(
+                    solution.is_some(),
+                    brute_force_solution.is_some(),
+                    "Solvers disagree on {} x {} from {:?} to {:?}", n, m, start_coords, end_coords
+                );
+
+                //Check that both solutions are valid
+                if let (Some(solution), Some(brute_force_solution)) = (solution, brute_force_solution) {
+                    assert!(
+                        GridPath::from_problem_and_order(&my_grid_problem, solution.into_vertex_order()).is_ok(),
+                        "Invalid solution on {} x {} from {:?} to {:?}", n, m, start_coords, end_coords
+                    );
+                    assert!(
+                        GridPath::from_problem_and_order(&my_grid_problem, brute_force_solution.into_vertex_order()).is_ok(),
+                        "Invalid brute force solution on {} x {} from {:?} to {:?}", n, m, start_coords, end_coords
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solver_consistency_small_grids() {
+        //Stress test the solver on every grid graph up to 4 by 4
+        for n in 1..5 {
+            for m in 1..5 {
+                stress_test_solver_consistency(n, m);
+            }
+        }
+    }
+
+    #[test]
+    fn num_strips_needed_counts_strips() {
+        //A problem with both endpoints in the bottom left corner is
+        //stripped from the right and above
+        let my_grid_problem: GridProblem = GridProblem::new(10, 9, [0, 0], [1, 0]);
+        let mut stripped_grid_problem: GridProblem = GridProblem::new(10, 9, [0, 0], [1, 0]);
+        let mut num_strips: usize = 0;
+        while stripped_grid_problem.strip() {
+            num_strips += 1;
+        }
+        assert_eq!(my_grid_problem.num_strips_needed(), num_strips);
+        assert_eq!(num_strips > 0, true);
+
+        //No strips are needed for an unacceptable or directly solved problem
+        assert_eq!(GridProblem::new(10, 9, [0, 0], [1, 1]).num_strips_needed(), 0);
+        assert_eq!(GridProblem::new(3, 9, [0, 0], [2, 8]).num_strips_needed(), 0);
+    }
+
+    #[test]
+    fn solve_paranoid_sweep() {
+        let options: SolveOptions = SolveOptions {
+            paranoid: true,
+            ..SolveOptions::default()
+        };
+
+        //Loop through every problem on grid graphs from 1 by 1 to 7 by 7
+        for width in 1..8 {
+            for height in 1..8 {
+                for start_coords in GridGraph::cells_with_dimensions(width, height) {
+                    for end_coords in GridGraph::cells_with_dimensions(width, height) {
+                        if start_coords == end_coords {
+                            continue;
+                        }
+
+                        //Paranoid mode should find no invalid intermediate and
+                        //give the same solution as an ordinary solve
+                        let mut my_grid_problem: GridProblem = GridProblem::new(width, height, start_coords, end_coords);
+                        let solution: Option<GridPath> = my_grid_problem.solve_with_options(&options).unwrap();
+                        let expected: Option<GridPath> = GridProblem::new(width, height, start_coords, end_coords).solve();
+                        assert_eq!(
+                            solution.map(|path| path.into_vertex_order()),
+                            expected.map(|path| path.into_vertex_order())
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn paranoid_extension_reports_extended_dimensions() {
+        //A broken 3 by 2 path extended up then right is reported against
+        //the 3 by 4 grid of its first extension, not the 3 by 2 grid it
+        //started on
+        let mut my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [1, 1]);
+        my_grid_problem.push_extension(GridExtension::Right);
+        my_grid_problem.push_extension(GridExtension::Up);
+        let mut broken: GridPath = GridPath::new(3, 2, vec![[0, 0], [1, 0], [2, 0], [2, 1], [0, 1], [1, 1]]);
+        let mut context: SolveContext = SolveContext {
+            paranoid: true,
+            ..SolveContext::new(6)
+        };
+        my_grid_problem.extend_in_context(&mut broken, &mut context);
+        assert_eq!((broken.get_width(), broken.get_height()), (5, 4));
+        assert_eq!(
+            matches!(
+                context.violation,
+                Some(SolveError::InvalidIntermediate { ref stage, width: 3, height: 4, .. }) if stage == "extension #1 (Up)"
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn paranoid_check_reports_first_violation() {
+        let mut context: SolveContext = SolveContext {
+            paranoid: true,
+            ..SolveContext::new(6)
+        };
+
+        //A path which skips a vertex is reported with its stage
+        let broken: GridPath = GridPath::new(3, 2, vec![[0, 0], [1, 0], [2, 0], [2, 1], [0, 1], [1, 1]]);
+        context.check(&broken, None, 5, 9, || String::from("extension #3 (Right)"));
+        let expected: SolveError = SolveError::InvalidIntermediate {
+            stage: String::from("extension #3 (Right)"),
+            width: 5,
+            height: 9,
+            error: GridPathError::NonAdjacent([2, 1], [0, 1])
+        };
+        assert_eq!(
+            format!("{}", expected),
+            "The extension #3 (Right) on the 5x9 sub-problem produced an invalid path: Consecutive vertices are not adjacent: (2, 1), (0, 1)"
+        );
+        assert_eq!(context.violation, Some(expected));
+
+        //Only the first violation is kept
+        let wrong_endpoints: GridPath = GridPath::new(3, 2, vec![[0, 0], [1, 0], [2, 0], [2, 1], [1, 1], [0, 1]]);
+        context.check(&wrong_endpoints, Some(([0, 0], [2, 1])), 3, 2, || String::from("prime lookup"));
+        assert_eq!(matches!(context.violation, Some(SolveError::InvalidIntermediate { width: 5, .. })), true);
+
+        //Nothing is checked outside paranoid mode
+        context.paranoid = false;
+        context.violation = None;
+        context.check(&broken, None, 3, 2, || String::from("prime lookup"));
+        assert_eq!(context.violation, None);
+    }
+
+    #[test]
+    fn solve_single_vertex() {
+        //The only problem on a 1 by 1 grid is acceptable and its path is
+        //the single vertex
+        let mut my_grid_problem: GridProblem = GridProblem::new(1, 1, [0, 0], [0, 0]);
+        assert_eq!(my_grid_problem.is_acceptable(), true);
+        assert_eq!(my_grid_problem.solve().unwrap().into_vertex_order(), vec![[0, 0]]);
+
+        //The single vertex is a valid path for the problem
+        let my_grid_problem: GridProblem = GridProblem::new(1, 1, [0, 0], [0, 0]);
+        assert_eq!(GridPath::from_problem_and_order(&my_grid_problem, vec![[0, 0]]).is_ok(), true);
+    }
+
+    #[test]
+    fn color_introspection() {
+        //On an even grid the endpoints must differ in color
+        let my_grid_problem: GridProblem = GridProblem::new(4, 3, [0, 0], [2, 1]);
+        assert_eq!(my_grid_problem.grid_parity(), Parity::Even);
+        assert_eq!(my_grid_problem.start_color(), Parity::Even);
+        assert_eq!(my_grid_problem.end_color(), Parity::Odd);
+        assert_eq!(my_grid_problem.required_relation(), ColorRelation::Different);
+
+        //On an odd grid both endpoints must have the even color
+        let my_grid_problem: GridProblem = GridProblem::new(5, 3, [1, 0], [4, 2]);
+        assert_eq!(my_grid_problem.grid_parity(), Parity::Odd);
+        assert_eq!(my_grid_problem.required_relation(), ColorRelation::BothEven);
+        assert_eq!(
+            format!("{}", my_grid_problem.required_relation()),
+            "both endpoints must have the even color"
+        );
+
+        //The relation should agree with color compatibility for every pair
+        //of endpoints on several odd and even grids
+        for (width, height) in [(1, 4), (3, 3), (4, 3), (5, 5), (6, 4)] {
+            for start_coords in GridGraph::cells_with_dimensions(width, height) {
+                for end_coords in GridGraph::cells_with_dimensions(width, height).filter(|end| *end != start_coords) {
+                    let my_grid_problem: GridProblem = GridProblem::new(width, height, start_coords, end_coords);
+                    assert_eq!(
+                        my_grid_problem.required_relation().is_satisfied_by(my_grid_problem.start_color(), my_grid_problem.end_color()),
+                        my_grid_problem.grid_graph.are_color_compatible(start_coords, end_coords)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_two_wide_forbidden() {
+        //Two non-corner vertices sharing a row of a 2 wide grid are joined
+        //by a nonboundary edge, so the problem is forbidden
+        let mut my_grid_problem: GridProblem = GridProblem::new(2, 4, [0, 2], [1, 2]);
+        assert_eq!(my_grid_problem.grid_graph.is_forbidden([0, 2], [1, 2]), true);
+        assert_eq!(my_grid_problem.solve().is_none(), true);
+
+        //Moving them to the bottom row makes the problem acceptable
+        let mut my_grid_problem: GridProblem = GridProblem::new(2, 4, [0, 0], [1, 0]);
+        let solution: GridPath = my_grid_problem.solve().unwrap();
+        assert_eq!(is_hamiltonian_path(2, 4, [0, 0], [1, 0], solution.get_vertex_order()), true);
+    }
+
+    #[test]
+    fn reconstruct_restores_dimensions() {
+        //Strip a problem with endpoints near its middle as far as it goes,
+        //so that strips are taken from several sides
+        let mut my_grid_problem: GridProblem = GridProblem::new(10, 6, [4, 2], [5, 2]);
+        while my_grid_problem.strip() {}
+        assert_eq!(
+            my_grid_problem.get_grid_graph().get_width() < 10 || my_grid_problem.get_grid_graph().get_height() < 6,
+            true
+        );
+        assert_eq!(my_grid_problem.extensions.contains(&GridExtension::Left), true);
+
+        //Reconstructing should restore the original grid and endpoints
+        assert_eq!(my_grid_problem.reconstruct(), Ok(()));
+        assert_eq!(my_grid_problem.get_grid_graph().get_width(), 10);
+        assert_eq!(my_grid_problem.get_grid_graph().get_height(), 6);
+        assert_eq!(my_grid_problem.get_start_coords(), [4, 2]);
+        assert_eq!(my_grid_problem.get_end_coords(), [5, 2]);
+        assert_eq!(my_grid_problem.extensions.len(), 0);
+    }
+
+    #[test]
+    fn reconstruct_mismatch() {
+        //An extension pushed without stripping the grid graph cannot be
+        //undone back to the original problem
+        let mut my_grid_problem: GridProblem = GridProblem::new(7, 6, [0, 0], [1, 0]);
+        my_grid_problem.strip();
+        my_grid_problem.push_extension(GridExtension::Left);
+        let error: SolveError = my_grid_problem.reconstruct().unwrap_err();
+        assert_eq!(error, SolveError::ReconstructionMismatch {
+            expected_dimensions: [7, 6],
+            actual_dimensions: [9, 6],
+            expected_endpoints: ([0, 0], [1, 0]),
+            actual_endpoints: ([2, 0], [3, 0])
+        });
+        assert_eq!(
+            format!("{}", error),
+            "Reconstruction gave a 9x6 grid from (2, 0) to (3, 0), expected a 7x6 grid from (0, 0) to (1, 0)"
+        );
+
+        //The original problem should be restored regardless
+        assert_eq!((my_grid_problem.get_width(), my_grid_problem.get_height()), (7, 6));
+        assert_eq!(my_grid_problem.get_start_coords(), [0, 0]);
+        assert_eq!(my_grid_problem.get_end_coords(), [1, 0]);
+        assert_eq!(my_grid_problem.reconstruct(), Ok(()));
+    }
+
+    #[test]
+    fn solve_reconstruct_mismatch_error() {
+        //A solve of a corrupted problem returns the mismatch as an error,
+        //without paranoid checks, and leaves the problem as it was given
+        let mut my_grid_problem: GridProblem = GridProblem::new(1, 5, [0, 0], [0, 4]);
+        my_grid_problem.push_extension(GridExtension::Left);
+        assert_eq!(
+            my_grid_problem.solve_with_options(&SolveOptions::default()).map(|solution| solution.is_some()),
+            Err(SolveError::ReconstructionMismatch {
+                expected_dimensions: [1, 5],
+                actual_dimensions: [3, 5],
+                expected_endpoints: ([0, 0], [0, 4]),
+                actual_endpoints: ([2, 0], [2, 4])
+            })
+        );
+        assert_eq!((my_grid_problem.get_width(), my_grid_problem.get_height()), (1, 5));
+        assert_eq!(my_grid_problem.get_start_coords(), [0, 0]);
+        assert_eq!(my_grid_problem.get_end_coords(), [0, 4]);
+    }
+
+    #[test]
+    fn solve_unwind_reconstructs() {
+        //Panic on the first progress event, which a problem that is stripped
+        //and then split reports from a sub-problem while still stripped
+        struct PanickingObserver;
+        impl SolveObserver for PanickingObserver {
+            fn on_progress(&mut self, _event: ProgressEvent) {
+                panic!("observer failed");
+            }
+        }
+        let mut my_grid_problem: GridProblem = (1..144)
+            .map(|end_index| GridProblem::new(12, 12, [0, 0], GridGraph::coords_of_with_width(12, end_index)))
+            .find(|grid_problem| grid_problem.estimate().get_num_strips() > 0 && grid_problem.estimate().is_split_likely)
+            .unwrap();
+        let end_coords: [usize; 2] = my_grid_problem.get_end_coords();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            my_grid_problem.solve_with_observer(&mut PanickingObserver)
+        }));
+        assert_eq!(result.is_err(), true);
+
+        //The problem should have been reconstructed as the solve unwound
+        assert_eq!((my_grid_problem.get_width(), my_grid_problem.get_height()), (12, 12));
+        assert_eq!(my_grid_problem.get_start_coords(), [0, 0]);
+        assert_eq!(my_grid_problem.get_end_coords(), end_coords);
+        assert_eq!(my_grid_problem.extensions.len(), 0);
+    }
+
+    #[test]
+    fn thin_grids_end_to_end() {
+        for len in 1..51 {
+            //Check each of the 1 wide and 1 tall grids, for which the only
+            //acceptable problems run from one end to the other
+            for (width, height) in [(len, 1), (1, len)] {
+                let far_corner: [usize; 2] = [width - 1, height - 1];
+                let mut my_grid_problem: GridProblem = GridProblem::new(width, height, [0, 0], far_corner);
+                let solution: GridPath = my_grid_problem.solve().unwrap();
+                assert_eq!(is_hamiltonian_path(width, height, [0, 0], far_corner, solution.get_vertex_order()), true);
+
+                //The reversed problem is solved by the reversed path
+                let mut reversed: Vec<[usize; 2]> = solution.get_vertex_order().to_vec();
+                reversed.reverse();
+                let mut my_grid_problem: GridProblem = GridProblem::new(width, height, far_corner, [0, 0]);
+                assert_eq!(my_grid_problem.solve().unwrap().into_vertex_order(), reversed);
+
+                //A problem with an endpoint away from the ends is forbidden
+                if len > 2 {
+                    let middle: [usize; 2] = [(width - 1) / 2, (height - 1) / 2];
+                    let mut my_grid_problem: GridProblem = GridProblem::new(width, height, [0, 0], middle);
+                    assert_eq!(my_grid_problem.solve().is_none(), true);
+                }
+
+                //The render is a single row or column with no trailing newline,
+                //the start glyph taking precedence over the end glyph when
+                //they are drawn at the same vertex
+                for glyphs in [GlyphSet::ASCII, GlyphSet::UNICODE] {
+                    let options: RenderOptions = RenderOptions { glyphs: glyphs, ..RenderOptions::default() };
+                    let separator: String = if height == 1 {
+                        glyphs.horizontal_edge.repeat(3)
+                    } else {
+                        format!("\n{}\n", glyphs.vertical_edge)
+                    };
+                    let mut nodes: Vec<&str> = (0..len)
+                        .map(|i| if i == 0 { glyphs.start } else if i + 1 == len { glyphs.end } else { glyphs.node })
+                        .collect();
+                    if height > 1 {
+                        //The top row is drawn first, so a column is drawn from its end
+                        nodes.reverse();
+                    }
+                    assert_eq!(solution.render(&options), nodes.join(&separator));
+                }
+
+                //The directions are a single run, and the path is one segment
+                let rle: String = solution.to_rle();
+                if len > 1 {
+                    assert_eq!(rle, format!("{}{}", len - 1, if height == 1 { 'R' } else { 'U' }));
+                } else {
+                    assert_eq!(rle, "");
+                }
+                assert_eq!(GridPath::from_rle([0, 0], &rle, width, height).unwrap().into_vertex_order(), solution.get_vertex_order());
+                assert_eq!(solution.path_segments().len(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn split_needs_endpoints_apart() {
+        //With the endpoints on the same row no horizontal cut separates
+        //them, so the problem cannot be split horizontally
+        let my_grid_problem: GridProblem = GridProblem::new(8, 8, [0, 3], [5, 3]);
+        assert_eq!(my_grid_problem.can_be_split_horizontally(), false);
+        assert_eq!(my_grid_problem.split_horizontally().is_none(), true);
+        assert_eq!(my_grid_problem.can_be_split_vertically(), true);
+
+        //Likewise with the endpoints on the same column it cannot be
+        //split vertically
+        let my_grid_problem: GridProblem = GridProblem::new(8, 8, [3, 0], [3, 5]);
+        assert_eq!(my_grid_problem.can_be_split_vertically(), false);
+        assert_eq!(my_grid_problem.split_vertically().is_none(), true);
+        assert_eq!(my_grid_problem.can_be_split_horizontally(), true);
+    }
+
+    #[test]
+    fn estimate_matches_strips() {
+        //Loop through every problem on grid graphs from 1 by 1 to 8 by 8
+        for width in 1..9 {
+            for height in 1..9 {
+                for start_index in 0..(width * height) {
+                    for end_index in 0..(width * height) {
+                        if start_index == end_index {
+                            continue;
+                        }
+                        let start_coords: [usize; 2] = GridGraph::coords_of_with_width(width, start_index);
+                        let end_coords: [usize; 2] = GridGraph::coords_of_with_width(width, end_index);
+                        let my_grid_problem: GridProblem = GridProblem::new(width, height, start_coords, end_coords);
+                        let estimate: SolveEstimate = my_grid_problem.estimate();
+                        assert_eq!(estimate.num_cells, width * height);
+
+                        //Strip the problem as the solver would, from the lesser
+                        //endpoint and only if it is not solved directly
+                        let mut stripped_grid_problem: GridProblem = if end_coords < start_coords {
+                            my_grid_problem.swap_start_end()
+                        } else {
+                            my_grid_problem
+                        };
+                        if stripped_grid_problem.is_acceptable() && width > 3 && height > 3 {
+                            while stripped_grid_problem.strip() {}
+
+                            //A split is expected exactly when the stripped problem
+                            //is neither prime nor solved directly
+                            let is_split_needed: bool = stripped_grid_problem.get_width() > 3 &&
+                                                        stripped_grid_problem.get_height() > 3 &&
+                                                        !GridPath::is_prime(
+                                stripped_grid_problem.get_width(),
+                                stripped_grid_problem.get_height(),
+                                stripped_grid_problem.start_coords,
+                                stripped_grid_problem.end_coords
+                            );
+                            assert_eq!(estimate.is_split_likely, is_split_needed);
+                        }
+
+                        //The estimated strip counts should match the actual strips
+                        let count = |direction: GridExtension| stripped_grid_problem.extensions.iter()
+                            .filter(|extension| **extension == direction)
+                            .count();
+                        assert_eq!(
+                            [estimate.num_strips_right, estimate.num_strips_up, estimate.num_strips_left, estimate.num_strips_down],
+                            [count(GridExtension::Right), count(GridExtension::Up), count(GridExtension::Left), count(GridExtension::Down)],
+                            "Estimate disagrees with strips on {} x {} from {:?} to {:?}", width, height, start_coords, end_coords
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_brute_force_budget() {
+        //Initialize a problem whose search must expand many nodes
+        let my_grid_problem: GridProblem = GridProblem::new(5, 4, [0, 0], [4, 3]);
+
+        //With a tiny node budget the search should give up
+        let mut options: SolveOptions = SolveOptions {
+            max_bruteforce_cells: 42,
+            max_bruteforce_nodes_expanded: 5,
+            ..SolveOptions::default()
+        };
+        assert_eq!(
+            my_grid_problem.solve_brute_force_with_options(&options).err(),
+            Some(SolveError::BudgetExceeded { limit: 5 })
+        );
+
+        //With the default budget the search should find a valid path
+        options.max_bruteforce_nodes_expanded = SolveOptions::default().max_bruteforce_nodes_expanded;
+        let solution: GridPath = my_grid_problem.solve_brute_force_with_options(&options).unwrap().unwrap();
+        assert!(is_hamiltonian_path(5, 4, [0, 0], [4, 3], solution.get_vertex_order()));
+    }
+
+    #[test]
+    fn solve_brute_force_too_many_cells() {
+        //Initialize a problem larger than the default cell limit, which
+        //should be rejected before searching
+        let my_grid_problem: GridProblem = GridProblem::new(7, 7, [0, 0], [6, 6]);
+        assert_eq!(
+            my_grid_problem.solve_brute_force_with_options(&SolveOptions::default()).err(),
+            Some(SolveError::TooManyCells { limit: 42, actual: 49 })
+        );
+    }
+
+    #[test]
+    fn sample_solution_valid() {
+        //Loop through a few acceptable problems on grid graphs of various
+        //dimensions and sample many solutions of each
+        let problems: Vec<(usize, usize, [usize; 2], [usize; 2])> = vec![
+            (4, 4, [0, 0], [3, 0]),
+            (5, 5, [0, 0], [4, 4]),
+            (6, 5, [2, 1], [3, 3]),
+            (7, 6, [0, 5], [6, 0]),
+            (8, 8, [3, 3], [4, 3])
+        ];
+        for (width, height, start_coords, end_coords) in problems {
+            let mut vertex_orders: Vec<Vec<[usize; 2]>> = Vec::new();
+            for seed in 0..20 {
+                let mut my_grid_problem: GridProblem = GridProblem::new(width, height, start_coords, end_coords);
+                let mut my_rng: GridRng = GridRng::new(seed);
+                let solution: GridPath = my_grid_problem.sample_solution(&mut my_rng).unwrap();
+                assert!(
+                    is_hamiltonian_path(width, height, start_coords, end_coords, solution.get_vertex_order()),
+                    "Invalid sample on {} x {} from {:?} to {:?} with seed {}", width, height, start_coords, end_coords, seed
+                );
+                let vertex_order: Vec<[usize; 2]> = solution.into_vertex_order();
+                if !vertex_orders.contains(&vertex_order) {
+                    vertex_orders.push(vertex_order);
+                }
+            }
+
+            //Different seeds should yield more than one distinct path
+            assert!(vertex_orders.len() > 1);
+        }
+    }
+
+    #[test]
+    fn sample_solution_reproducible() {
+        //Sampling twice with the same seed should yield the same path
+        let mut my_grid_problem: GridProblem = GridProblem::new(6, 6, [0, 0], [5, 0]);
+        let first_solution: GridPath = my_grid_problem.sample_solution(&mut GridRng::new(3)).unwrap();
+        let second_solution: GridPath = my_grid_problem.sample_solution(&mut GridRng::new(3)).unwrap();
+        assert_eq!(first_solution.get_vertex_order(), second_solution.get_vertex_order());
+    }
+
+    #[test]
+    fn solve_with_seed_reproducible() {
+        //Solving fresh problems with the same seed should yield the same
+        //path as sampling with a generator seeded alike
+        for seed in 0..5 {
+            let first_solution: GridPath = GridProblem::new(7, 6, [0, 5], [6, 0]).solve_with_seed(seed).unwrap();
+            let second_solution: GridPath = GridProblem::new(7, 6, [0, 5], [6, 0]).solve_with_seed(seed).unwrap();
+            let sampled_solution: GridPath = GridProblem::new(7, 6, [0, 5], [6, 0]).sample_solution(&mut GridRng::new(seed)).unwrap();
+            assert_eq!(first_solution.get_vertex_order(), second_solution.get_vertex_order());
+            assert_eq!(first_solution.get_vertex_order(), sampled_solution.get_vertex_order());
+            assert!(is_hamiltonian_path(7, 6, [0, 5], [6, 0], first_solution.get_vertex_order()));
+        }
+
+        //A problem which is not acceptable has no solution whatever the seed
+        assert!(GridProblem::new(4, 4, [0, 0], [1, 1]).solve_with_seed(7).is_none());
+    }
+
+    #[test]
+    fn solve_with_seed_large() {
+        //Only the split drawn is built at each level, so a seeded solve of a
+        //large grid should not hold every candidate split in memory
+        let solution: GridPath = GridProblem::new(70, 70, [0, 0], [69, 0]).solve_with_seed(1).unwrap();
+        assert!(is_hamiltonian_path(70, 70, [0, 0], [69, 0], solution.get_vertex_order()));
+    }
+
+    #[test]
+    fn sample_solution_not_acceptable() {
+        //A problem which is not acceptable has no sample solution
+        let mut my_grid_problem: GridProblem = GridProblem::new(4, 4, [0, 0], [1, 1]);
+        assert!(my_grid_problem.sample_solution(&mut GridRng::new(0)).is_none());
+    }
+
+    #[test]
+    fn solve_with_channel_observer() {
+        //Solve a large problem on another thread, forwarding its progress
+        //events over a channel
+        let (sender, receiver) = std::sync::mpsc::channel::<ProgressEvent>();
+        let solver = std::thread::spawn(move || {
+            let mut my_grid_problem: GridProblem = GridProblem::new(100, 100, [0, 0], [99, 0]);
+            let mut observer: ChannelObserver = ChannelObserver::new(sender);
+            my_grid_problem.solve_with_observer(&mut observer)
+        });
+
+        //The events should make nondecreasing progress towards the total
+        let events: Vec<ProgressEvent> = receiver.iter().collect();
+        let solution: GridPath = solver.join().unwrap().unwrap();
+        assert!(is_hamiltonian_path(100, 100, [0, 0], [99, 0], solution.get_vertex_order()));
+        assert!(events.len() > 0);
+        assert!(events.windows(2).all(|pair| pair[0].cells_completed <= pair[1].cells_completed));
+        assert!(events.iter().all(|event| event.cells_total == 10000));
+
+        //The final event should report the whole grid complete at the top level
+        let last_event: ProgressEvent = events[events.len() - 1];
+        assert_eq!(last_event.cells_completed, 10000);
+        assert_eq!(last_event.depth, 0);
+    }
+
+    #[test]
+    fn solve_with_observer_split() {
+        //Collect the progress events of a problem which must be split
+        struct RecordingObserver {
+            events: Vec<ProgressEvent>
+        }
+        impl SolveObserver for RecordingObserver {
+            fn on_progress(&mut self, event: ProgressEvent) {
+                self.events.push(event);
+            }
+        }
+        let mut observer: RecordingObserver = RecordingObserver { events: Vec::new() };
+        let mut my_grid_problem: GridProblem = (1..64)
+            .map(|end_index| GridProblem::new(8, 8, [0, 0], GridGraph::coords_of_with_width(8, end_index)))
+            .find(|grid_problem| grid_problem.estimate().is_split_likely)
+            .unwrap();
+        my_grid_problem.solve_with_observer(&mut observer).unwrap();
+
+        //Sub-problems should be reported deeper than the final event,
+        //which should report the whole grid complete
+        assert!(observer.events.len() > 1);
+        assert!(observer.events.iter().any(|event| event.depth > 0));
+        assert_eq!(observer.events[observer.events.len() - 1].cells_completed, 64);
+    }
+
+    /// Check whether the assignment setting exactly the given variables
+    /// true satisfies every clause of a DIMACS CNF formula
+    fn is_satisfied(cnf: &str, true_vars: &[isize]) -> bool {
+        cnf.lines()
+            .filter(|line| !line.starts_with('c') && !line.starts_with('p'))
+            .all(|line| line.split_whitespace()
+                .map(|literal| literal.parse::<isize>().unwrap())
+                .filter(|literal| *literal != 0)
+                .any(|literal| true_vars.contains(&literal.abs()) == (literal > 0)))
+    }
+
+    #[test]
+    fn to_dimacs_cnf_header() {
+        //A 2 by 2 problem has 16 variables, 8 exactly-one constraints of
+        //1 + 6 clauses each, 4 x 3 successor clauses and 2 endpoint clauses
+        let my_grid_problem: GridProblem = GridProblem::new(2, 2, [0, 0], [1, 0]);
+        let cnf: String = my_grid_problem.to_dimacs_cnf();
+        assert_eq!(cnf.lines().nth(1), Some("p cnf 16 70"));
+        assert_eq!(cnf.lines().count(), 72);
+    }
+
+    #[test]
+    fn to_dimacs_cnf_solution_satisfies() {
+        //Encode each vertex's position in a solution as the true variables
+        let mut my_grid_problem: GridProblem = GridProblem::new(3, 3, [0, 0], [2, 2]);
+        let cnf: String = my_grid_problem.to_dimacs_cnf();
+        let solution: GridPath = my_grid_problem.solve().unwrap();
+        let to_vars = |vertex_order: &[[usize; 2]]| vertex_order.iter().enumerate()
+            .map(|(k, v)| ((GridGraph::cell_index_with_width(3, *v) * 9) + k + 1) as isize)
+            .collect::<Vec<isize>>();
+
+        //The solution should satisfy the formula
+        assert_eq!(is_satisfied(&cnf, &to_vars(solution.get_vertex_order())), true);
+
+        //A vertex order which jumps should not
+        let jumping_order: Vec<[usize; 2]> = vec![
+            [0, 0], [1, 0], [2, 0], [0, 1], [1, 1],
+            [2, 1], [0, 2], [1, 2], [2, 2]
+        ];
+        assert_eq!(is_satisfied(&cnf, &to_vars(&jumping_order)), false);
+
+        //Nor should the solution reversed, which runs between the wrong endpoints
+        let mut reversed_order: Vec<[usize; 2]> = solution.get_vertex_order().to_vec();
+        reversed_order.reverse();
+        assert_eq!(is_satisfied(&cnf, &to_vars(&reversed_order)), false);
+    }
+
+    #[test]
+    fn entry_exit_on_edge_even() {
+        //On an even grid the end vertex should follow the start vertex
+        //along each edge
+        let cases: Vec<(GridExtension, usize, [usize; 2], [usize; 2])> = vec![
+            (GridExtension::Down, 3, [3, 0], [4, 0]),
+            (GridExtension::Up, 0, [0, 5], [1, 5]),
+            (GridExtension::Left, 2, [0, 2], [0, 3]),
+            (GridExtension::Right, 4, [5, 4], [5, 5])
+        ];
+        for (edge, position, start_coords, end_coords) in cases {
+            let mut my_grid_problem: GridProblem = GridProblem::entry_exit_on_edge(6, 6, edge, position).unwrap();
+            assert_eq!(my_grid_problem.get_start_coords(), start_coords);
+            assert_eq!(my_grid_problem.get_end_coords(), end_coords);
+            let solution: GridPath = my_grid_problem.solve().unwrap();
+            assert!(is_hamiltonian_path(6, 6, start_coords, end_coords, solution.get_vertex_order()));
+        }
+    }
+
+    #[test]
+    fn entry_exit_on_edge_far_corner() {
+        //At the far corner of an edge the end vertex should precede the
+        //start vertex instead
+        let my_grid_problem: GridProblem = GridProblem::entry_exit_on_edge(6, 4, GridExtension::Down, 5).unwrap();
+        assert_eq!(my_grid_problem.get_start_coords(), [5, 0]);
+        assert_eq!(my_grid_problem.get_end_coords(), [4, 0]);
+        let my_grid_problem: GridProblem = GridProblem::entry_exit_on_edge(6, 4, GridExtension::Right, 3).unwrap();
+        assert_eq!(my_grid_problem.get_start_coords(), [5, 3]);
+        assert_eq!(my_grid_problem.get_end_coords(), [5, 2]);
+    }
+
+    #[test]
+    fn entry_exit_on_edge_odd() {
+        //On an odd grid a majority colored start vertex should be kept,
+        //with the end vertex two along the edge
+        let mut my_grid_problem: GridProblem = GridProblem::entry_exit_on_edge(5, 5, GridExtension::Left, 0).unwrap();
+        assert_eq!(my_grid_problem.get_start_coords(), [0, 0]);
+        assert_eq!(my_grid_problem.get_end_coords(), [0, 2]);
+        let solution: GridPath = my_grid_problem.solve().unwrap();
+        assert!(is_hamiltonian_path(5, 5, [0, 0], [0, 2], solution.get_vertex_order()));
+
+        //A minority colored start vertex should move one along the edge
+        let my_grid_problem: GridProblem = GridProblem::entry_exit_on_edge(5, 5, GridExtension::Up, 1).unwrap();
+        assert_eq!(my_grid_problem.get_start_coords(), [2, 4]);
+        assert_eq!(my_grid_problem.get_end_coords(), [4, 4]);
+
+        //Near the far corner the end vertex should be two back instead
+        let my_grid_problem: GridProblem = GridProblem::entry_exit_on_edge(5, 5, GridExtension::Right, 4).unwrap();
+        assert_eq!(my_grid_problem.get_start_coords(), [4, 4]);
+        assert_eq!(my_grid_problem.get_end_coords(), [4, 2]);
+    }
+
+    #[test]
+    fn entry_exit_on_edge_errors() {
+        //Positions off the end of the edge should be rejected
+        assert_eq!(
+            GridProblem::entry_exit_on_edge(6, 4, GridExtension::Left, 4).err(),
+            Some(GridError::PositionOutOfBounds { position: 4, length: 4 })
+        );
+
+        //Edges too short to hold both endpoints should be rejected
+        assert_eq!(
+            GridProblem::entry_exit_on_edge(1, 5, GridExtension::Down, 0).err(),
+            Some(GridError::EdgeTooShort { length: 1 })
+        );
+
+        //Problems which are not acceptable should be rejected, here a 1
+        //wide grid whose endpoints are not both corners
+        assert_eq!(
+            GridProblem::entry_exit_on_edge(1, 5, GridExtension::Left, 2).err(),
+            Some(GridError::NotAcceptable { start_coords: [0, 2], end_coords: [0, 4] })
+        );
+    }
+
+    /// Write the model a SAT solver would output for the given vertex order
+    fn to_dimacs_model(num_cells: usize, width: usize, vertex_order: &[[usize; 2]]) -> String {
+        let mut model: String = String::from("s SATISFIABLE\nv");
+        for var in 1..=(num_cells * num_cells) {
+            let v: usize = (var - 1) / num_cells;
+            let k: usize = (var - 1) % num_cells;
+            let is_true: bool = GridGraph::cell_index_with_width(width, vertex_order[k]) == v;
+            model += &format!(" {}", if is_true { var as isize } else { -(var as isize) });
+        }
+        model + " 0\n"
+    }
+
+    #[test]
+    fn from_dimacs_model_round_trip() {
+        //A model of a solution should be read back as the same path
+        let mut my_grid_problem: GridProblem = GridProblem::new(4, 3, [0, 0], [3, 0]);
+        let solution: GridPath = my_grid_problem.solve().unwrap();
+        let model: String = to_dimacs_model(12, 4, solution.get_vertex_order());
+        let parsed: GridPath = GridProblem::from_dimacs_model(4, 3, &model).unwrap();
+        assert_eq!(parsed.get_vertex_order(), solution.get_vertex_order());
+    }
+
+    #[test]
+    fn from_dimacs_model_errors() {
+        //An unsatisfiable result should be reported as such
+        assert_eq!(
+            GridProblem::from_dimacs_model(2, 2, "s UNSATISFIABLE\n").err(),
+            Some(PathParseError::Unsatisfiable)
+        );
+
+        //Literals which are not integers or are out of range should be rejected
+        assert_eq!(
+            GridProblem::from_dimacs_model(2, 2, "v 1 x 0\n").err(),
+            Some(PathParseError::InvalidLiteral(String::from("x")))
+        );
+        assert_eq!(
+            GridProblem::from_dimacs_model(2, 2, "v 17 0\n").err(),
+            Some(PathParseError::VariableOutOfRange(17))
+        );
+
+        //Positions with no vertex or more than one vertex should be rejected
+        assert_eq!(
+            GridProblem::from_dimacs_model(2, 2, "v 1 6 11 0\n").err(),
+            Some(PathParseError::MissingPosition(3))
+        );
+        assert_eq!(
+            GridProblem::from_dimacs_model(2, 2, "v 1 5 0\n").err(),
+            Some(PathParseError::RepeatedPosition(0))
+        );
+
+        //A model whose vertices do not form a path should be rejected,
+        //here one stepping diagonally from (1, 0) to (0, 1)
+        assert_eq!(
+            GridProblem::from_dimacs_model(2, 2, "v 1 6 11 16 0\n").err(),
+            Some(PathParseError::InvalidPath(GridPathError::NonAdjacent([1, 0], [0, 1])))
+        );
+    }
+
+    #[test]
+    fn solve_one_wide_both_orientations() {
+        //Loop through 1 by n and n by 1 grid graphs in both directions
+        for length in 1..8 {
+            for (width, height) in [(1, length), (length, 1)] {
+                let far_coords: [usize; 2] = [width - 1, height - 1];
+                for (start_coords, end_coords) in [([0, 0], far_coords), (far_coords, [0, 0])] {
+                    let mut my_grid_problem: GridProblem = GridProblem::new(width, height, start_coords, end_coords);
+                    let solution: GridPath = my_grid_problem.solve().unwrap();
+                    assert!(is_hamiltonian_path(width, height, start_coords, end_coords, solution.get_vertex_order()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_one_wide_endpoints_error() {
+        //Bypassing the acceptability check, a 1 wide problem whose endpoints
+        //are not its two ends records an error rather than exiting
+        let mut my_grid_problem: GridProblem = GridProblem::new(1, 5, [0, 1], [0, 4]);
+        let mut context: SolveContext = SolveContext::new(5);
+        assert_eq!(my_grid_problem.solve_stripping(&mut context).is_none(), true);
+        let expected: SolveError = SolveError::OneWideEndpoints {
+            width: 1,
+            height: 5,
+            endpoints: ([0, 1], [0, 4])
+        };
+        assert_eq!(
+            format!("{}", expected),
+            "The endpoints (0, 1) and (0, 4) are not the two ends of the 1x5 sub-problem"
+        );
+        assert_eq!(context.violation, Some(expected));
+    }
+
+    #[test]
+    fn solve_one_wide_rejects_inner_endpoints() {
+        //Endpoints which are not both ends of the grid have no solution,
+        //and the direct solver should refuse them rather than run past them
+        let my_grid_problem: GridProblem = GridProblem::new(1, 5, [0, 0], [0, 3]);
+        assert!(my_grid_problem.solve_one_wide().is_none());
+        let my_grid_problem: GridProblem = GridProblem::new(5, 1, [1, 0], [4, 0]);
+        assert!(my_grid_problem.solve_one_wide().is_none());
+    }
+
+    #[test]
+    fn solve_one_wide_split_sub_problem() {
+        //Find splits which produce a 1 wide sub-problem and check that the
+        //sub-problem is solved between its own endpoints
+        let mut num_one_wide: usize = 0;
+        for (width, height) in [(4, 4), (4, 5), (5, 4), (5, 5)] {
+            for start_index in 0..(width * height) {
+                for end_index in 0..(width * height) {
+                    let start_coords: [usize; 2] = GridGraph::coords_of_with_width(width, start_index);
+                    let end_coords: [usize; 2] = GridGraph::coords_of_with_width(width, end_index);
+                    if start_index == end_index {
+                        continue;
+                    }
+                    let my_grid_problem: GridProblem = GridProblem::new(width, height, start_coords, end_coords);
+                    if !my_grid_problem.is_acceptable() {
+                        continue;
+                    }
+                    let problem: &GridProblem = &my_grid_problem;
+                    let splits: Vec<(GridProblem, GridProblem)> = [true, false].into_iter()
+                        .flat_map(|is_horizontal| problem.get_split_positions(is_horizontal)
+                            .filter_map(move |(i, j)| problem.split_at(is_horizontal, i, j)))
+                        .collect();
+                    for (p_first, p_second) in splits {
+                        for mut sub_problem in [p_first, p_second] {
+                            if sub_problem.get_width() != 1 && sub_problem.get_height() != 1 {
+                                continue;
+                            }
+                            num_one_wide += 1;
+                            let (sub_width, sub_height): (usize, usize) = (sub_problem.get_width(), sub_problem.get_height());
+                            let (sub_start, sub_end): ([usize; 2], [usize; 2]) = (sub_problem.start_coords, sub_problem.end_coords);
+                            let solution: GridPath = sub_problem.solve().unwrap();
+                            assert!(is_hamiltonian_path(sub_width, sub_height, sub_start, sub_end, solution.get_vertex_order()));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(num_one_wide > 0);
+    }
+
+    #[test]
+    fn snap_endpoints_cases() {
+        //Same colored endpoints on an even grid are snapped by one step,
+        //breaking the tie row-major by end vertex
+        assert_eq!(GridProblem::snap_endpoints(4, 4, [0, 0], [2, 2]), Ok(([0, 0], [2, 1])));
+        assert_eq!(GridProblem::snap_endpoints(4, 4, [0, 0], [3, 3]), Ok(([0, 0], [3, 2])));
+
+        //Odd colored endpoints on an odd grid are snapped to even ones
+        assert_eq!(GridProblem::snap_endpoints(5, 5, [0, 0], [1, 0]), Ok(([0, 0], [2, 0])));
+
+        //Forbidden endpoints of a single row are snapped to its ends, while
+        //acceptable endpoints are kept
+        assert_eq!(GridProblem::snap_endpoints(1, 5, [0, 1], [0, 3]), Ok(([0, 0], [0, 4])));
+        assert_eq!(GridProblem::snap_endpoints(2, 5, [0, 1], [0, 2]), Ok(([0, 1], [0, 2])));
+
+        //Equal endpoints are moved apart, and those outside the grid are
+        //first moved onto its corner
+        assert_eq!(GridProblem::snap_endpoints(4, 4, [1, 1], [1, 1]), Ok(([1, 0], [1, 1])));
+        assert_eq!(GridProblem::snap_endpoints(3, 4, [9, 9], [9, 9]), Ok(([2, 2], [2, 3])));
+        assert_eq!(GridProblem::snap_endpoints(1, 1, [3, 0], [0, 3]), Ok(([0, 0], [0, 0])));
+
+        //Grids without vertices have no endpoints to snap to
+        assert_eq!(
+            GridProblem::snap_endpoints(0, 3, [0, 0], [0, 1]),
+            Err(GridError::ZeroDimension { width: 0, height: 3 })
+        );
+    }
+
+    #[test]
+    fn snap_endpoints_always_acceptable() {
+        //Every pair of approximate endpoints of the small grids snaps to an
+        //acceptable problem no further away than any suggestion
+        for (width, height) in [(1, 1), (1, 4), (5, 1), (2, 3), (3, 3), (4, 4), (3, 6)] {
+            for approx_start in GridGraph::cells_with_dimensions(width, height) {
+                for approx_end in GridGraph::cells_with_dimensions(width, height) {
+                    let (start, end) = GridProblem::snap_endpoints(width, height, approx_start, approx_end).unwrap();
+                    assert_eq!(GridProblem::new(width, height, start, end).is_acceptable(), true);
+                    let distance: usize = start[0].abs_diff(approx_start[0]) + start[1].abs_diff(approx_start[1]) +
+                        end[0].abs_diff(approx_end[0]) + end[1].abs_diff(approx_end[1]);
+                    let nearest: ([usize; 2], [usize; 2], usize) = GridProblem::suggest_endpoints_with_dimensions(
+                        width, height, approx_start, approx_end, 2 * (width + height)
+                    )[0];
+                    assert_eq!((start, end, distance), nearest);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn snap_endpoints_long_thin_grids() {
+        //A long 1 wide grid snaps straight to its ends, in whichever order
+        //is nearer the approximate endpoints
+        assert_eq!(GridProblem::snap_endpoints(1, 100000, [0, 50000], [0, 50000]), Ok(([0, 0], [0, 99999])));
+        assert_eq!(GridProblem::snap_endpoints(100000, 1, [99000, 0], [10, 0]), Ok(([99999, 0], [0, 0])));
+
+        //Long 2 and 3 wide grids only scan the few rings nearest the
+        //approximate endpoints
+        for width in [2, 3] {
+            let (start, end) = GridProblem::snap_endpoints(width, 100000, [1, 50000], [1, 50000]).unwrap();
+            assert_eq!(GridProblem::new(width, 100000, start, end).is_acceptable(), true);
+            assert_eq!(start[1].abs_diff(50000) + end[1].abs_diff(50000) <= 2, true);
+        }
+    }
+
+    #[test]
+    fn to_json_follows_schema() {
+        //Every acceptable problem on a small grid, and the same problems once
+        //stripped, should serialize to a valid problem document
+        for (start, end) in AcceptablePairs::new(7, 6, PairOptions::default()) {
+            let mut my_grid_problem: GridProblem = GridProblem::new(7, 6, start, end);
+            assert_eq!(schema::validate_problem_json(&my_grid_problem.to_json()), Ok(()));
+            my_grid_problem.strip();
+            assert_eq!(schema::validate_problem_json(&my_grid_problem.to_json()), Ok(()));
+        }
+        assert_eq!(
+            GridProblem::new(3, 2, [0, 0], [2, 1]).to_json(),
+            r#"{"format_version":1,"width":3,"height":2,"start":[0,0],"end":[2,1]}"#
+        );
+    }
+
+    #[test]
+    fn support_bundle_parses_back() {
+        //Record a failed solve of a problem with same colored endpoints
+        let mut my_grid_problem: GridProblem = GridProblem::new(4, 4, [0, 0], [2, 2]);
+        let options: SolveOptions = SolveOptions::default();
+        let (solution, stats) = my_grid_problem.solve_with_stats();
+        let bundle: String = my_grid_problem.support_bundle(&options, &Ok(solution), Some(&stats));
+        let parsed: json::JsonValue = json::parse(&bundle).unwrap();
+
+        //The bundle carries the build info, including the prime table
+        //checksum, along with the problem, options and outcome
+        assert_eq!(parsed["format_version"].as_u32(), Some(crate::FORMAT_VERSION));
+        assert_eq!(
+            parsed["build"]["prime_table_checksum"].as_str(),
+            Some(format!("{:#018x}", crate::primetable::PrimeTable::checksum()).as_str())
+        );
+        assert_eq!(parsed["problem"]["width"].as_usize(), Some(4));
+        assert_eq!(parsed["problem"]["end"][1].as_usize(), Some(2));
+        assert_eq!(parsed["problem"]["is_acceptable"].as_bool(), Some(false));
+        assert_eq!(parsed["options"]["max_bruteforce_cells"].as_usize(), Some(options.max_bruteforce_cells));
+        assert_eq!(parsed["outcome"]["status"].as_str(), Some("no_solution"));
+        assert_eq!(parsed["stats"]["num_cells"].as_usize(), Some(16));
+
+        //Solved and failed outcomes record the path or the error
+        let mut my_grid_problem: GridProblem = GridProblem::new(3, 2, [0, 0], [2, 1]);
+        let solution: Option<GridPath> = my_grid_problem.solve();
+        let parsed: json::JsonValue = json::parse(&my_grid_problem.support_bundle(&options, &Ok(solution), None)).unwrap();
+        assert_eq!(parsed["outcome"]["status"].as_str(), Some("solved"));
+        assert_eq!(parsed["outcome"]["path"].len(), 6);
+        assert_eq!(parsed["stats"].is_null(), true);
+        let parsed: json::JsonValue = json::parse(&my_grid_problem.support_bundle(&options, &Err(SolveError::Infeasible), None)).unwrap();
+        assert_eq!(parsed["outcome"]["status"].as_str(), Some("error"));
+        assert_eq!(parsed["outcome"]["error"].as_str(), Some(format!("{}", SolveError::Infeasible).as_str()));
+    }
+
+    #[test]
+    fn forced_thin_splits() {
+        //Force every split of the 5 by 7 and 7 by 5 problems which cuts off
+        //a one wide or one tall sub-problem, and join it with every
+        //intermediate path checked
+        let mut num_thin_splits: usize = 0;
+        for (width, height) in [(5, 7), (7, 5)] {
+            for (start, end) in AcceptablePairs::new(width, height, PairOptions::default()) {
+                let my_grid_problem: GridProblem = GridProblem::new(width, height, start, end);
+                for is_horizontal in [true, false] {
+                    for (i, j) in my_grid_problem.get_split_positions(is_horizontal).collect::<Vec<(usize, usize)>>() {
+                        let (p_first, p_second) = match my_grid_problem.split_at(is_horizontal, i, j) {
+                            Some(x) => x,
+                            None => continue
+                        };
+                        let is_thin = |p: &GridProblem| p.get_width() == 1 || p.get_height() == 1;
+                        if !is_thin(&p_first) && !is_thin(&p_second) {
+                            continue;
+                        }
+                        num_thin_splits += 1;
+
+                        let mut context: SolveContext = SolveContext {
+                            paranoid: true,
+                            ..SolveContext::new(width * height)
+                        };
+                        let joined: GridPath = my_grid_problem.join_split(p_first, p_second, is_horizontal, &mut context).unwrap();
+                        assert_eq!(context.violation, None, "{}x{} {:?} to {:?} split at {:?}", width, height, start, end, (is_horizontal, i, j));
+                        assert_eq!(joined.check_hamiltonian(Some((start, end))), Ok(()));
+                    }
+                }
+            }
+        }
+        //The matrix should not be empty
+        assert_eq!(num_thin_splits > 0, true);
+    }
+}
@@ -0,0 +1,56 @@
+/// # GridRng struct
+///
+/// A `GridRng` is a small seeded pseudorandom number generator
+/// (SplitMix64) used wherever the solver makes random choices, so
+/// that a given seed always reproduces the same result.
+#[derive(Clone, Debug)]
+pub struct GridRng {
+    state: u64
+}
+
+impl GridRng {
+    /// Initialize a GridRng given a seed
+    pub fn new(seed: u64) -> GridRng {
+        GridRng {
+            state: seed
+        }
+    }
+
+    /// Get the next pseudorandom 64 bit integer
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Get a pseudorandom integer less than the given nonzero bound
+    pub fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % (bound as u64)) as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        //Two generators with the same seed should agree
+        let mut my_rng: GridRng = GridRng::new(42);
+        let mut my_other_rng: GridRng = GridRng::new(42);
+        for _ in 0..100 {
+            assert_eq!(my_rng.next_u64(), my_other_rng.next_u64());
+        }
+    }
+
+    #[test]
+    fn next_below_in_bounds() {
+        //Every value should fall below the bound
+        let mut my_rng: GridRng = GridRng::new(7);
+        for bound in 1..50 {
+            assert!(my_rng.next_below(bound) < bound);
+        }
+    }
+}
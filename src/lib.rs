@@ -0,0 +1,89 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod gridcore;
+
+#[cfg(feature = "std")]
+pub mod gridgraph;
+#[cfg(feature = "std")]
+pub mod gridpath;
+#[cfg(feature = "std")]
+pub mod gridproblem;
+#[cfg(feature = "std")]
+pub mod gridextension;
+#[cfg(feature = "std")]
+pub mod gridpatherror;
+#[cfg(feature = "std")]
+pub mod solveestimate;
+#[cfg(feature = "std")]
+pub mod solveerror;
+#[cfg(feature = "std")]
+pub mod solveoptions;
+#[cfg(feature = "std")]
+pub mod gridrng;
+#[cfg(feature = "std")]
+pub mod validationmode;
+#[cfg(feature = "std")]
+pub mod validationreport;
+#[cfg(feature = "std")]
+pub mod progressevent;
+#[cfg(feature = "std")]
+pub mod solveobserver;
+#[cfg(feature = "std")]
+pub mod griderror;
+#[cfg(feature = "std")]
+pub mod pathparseerror;
+#[cfg(feature = "std")]
+pub mod streamingvalidator;
+#[cfg(feature = "std")]
+pub mod primecoverage;
+#[cfg(feature = "std")]
+pub mod primetable;
+#[cfg(feature = "std")]
+pub mod glyphset;
+#[cfg(feature = "std")]
+pub mod renderoptions;
+#[cfg(feature = "std")]
+pub mod validate;
+#[cfg(feature = "std")]
+pub mod pairoptions;
+#[cfg(feature = "std")]
+pub mod acceptablepairs;
+#[cfg(feature = "std")]
+pub mod rect;
+#[cfg(feature = "std")]
+pub mod solvestats;
+#[cfg(feature = "std")]
+pub mod countingallocator;
+#[cfg(feature = "std")]
+pub mod parity;
+#[cfg(feature = "std")]
+pub mod colorrelation;
+#[cfg(feature = "std")]
+pub mod corner;
+#[cfg(feature = "std")]
+pub mod axisorder;
+#[cfg(feature = "std")]
+pub mod convention;
+#[cfg(feature = "std")]
+pub mod textnormalize;
+#[cfg(feature = "std")]
+pub mod buildinfo;
+#[cfg(feature = "std")]
+pub mod primeblock;
+#[cfg(feature = "std")]
+pub mod primetableerror;
+#[cfg(feature = "std")]
+pub mod schema;
+#[cfg(feature = "std")]
+pub mod schemaerror;
+
+#[cfg(feature = "std")]
+pub use buildinfo::build_info;
+
+/// The version of the JSON formats written by the crate, given as the
+/// `format_version` of each solution, problem, set of solve stats and
+/// support bundle, and bumped whenever the keys of any of them change
+pub const FORMAT_VERSION: u32 = 1;
@@ -1,18 +1,73 @@
-mod gridgraph;
-mod gridpath;
-mod gridproblem;
-mod gridextension;
 mod gridcli;
+mod parse;
 
+use std::fs;
+use std::path::Path;
 use std::process;
 use clap::Parser;
-use crate::gridcli::GridCli;
-use crate::gridpath::GridPath;
-use crate::gridproblem::GridProblem;
+use crate::gridcli::{GridCli, GridCommand, GlyphMode, TerminalEnv};
+use grid_solver::glyphset::GlyphSet;
+use grid_solver::gridpath::GridPath;
+use grid_solver::gridproblem::GridProblem;
+use grid_solver::primetable::PrimeTable;
+use grid_solver::renderoptions::RenderOptions;
+use grid_solver::schema;
+use grid_solver::solveerror::SolveError;
+use grid_solver::solveoptions::SolveOptions;
+use grid_solver::solvestats::SolveStats;
+
+/// Write a support bundle for a failed solve to the given file if one
+/// was requested
+fn write_bug_report(bug_report: Option<&Path>, problem: &GridProblem, options: &SolveOptions, outcome: &Result<Option<GridPath>, SolveError>, stats: Option<&SolveStats>) {
+    if let Some(path) = bug_report {
+        match fs::write(path, problem.support_bundle(options, outcome, stats)) {
+            Ok(()) => eprintln!("Wrote a bug report to {}", path.display()),
+            Err(e) => eprintln!("Could not write a bug report to {}: {}", path.display(), e)
+        }
+    }
+}
 
 fn main() {
     //Parse the command line args
     let cli_args = GridCli::parse();
+
+    //If the primes subcommand was given then print the prime table
+    //coverage report rather than solving a grid problem
+    if let Some(GridCommand::Primes) = cli_args.command {
+        for coverage in PrimeTable::coverage_report() {
+            println!("{}", coverage);
+        }
+        return;
+    }
+
+    //If the schema subcommand was given then print the embedded schema
+    if let Some(GridCommand::Schema) = cli_args.command {
+        println!("{}", schema::document());
+        return;
+    }
+
+    //Resolve the glyphs to draw the solution with, falling back when
+    //the terminal does not support those requested
+    let requested_glyphs: GlyphMode = if cli_args.color { GlyphMode::Color } else { cli_args.glyphs };
+    let (glyph_mode, note) = gridcli::resolve_glyph_mode(requested_glyphs, cli_args.force, &TerminalEnv::from_process());
+    if let Some(note) = note {
+        eprintln!("{}", note);
+    }
+    let render_options: RenderOptions = RenderOptions {
+        glyphs: match glyph_mode {
+            GlyphMode::Ascii | GlyphMode::Color => GlyphSet::ASCII,
+            GlyphMode::Unicode => GlyphSet::UNICODE
+        },
+        ..RenderOptions::default()
+    };
+    let draw = |path: &GridPath| -> String {
+        if glyph_mode == GlyphMode::Color {
+            path.to_ascii_colored()
+        } else {
+            path.render(&render_options)
+        }
+    };
+
     let width: usize = match cli_args.width {
         Some(x) => x as usize,
         None => {
@@ -27,39 +82,92 @@ fn main() {
             process::exit(1);
         }
     };
-    let start_x: usize = match cli_args.start_x {
-        Some(x) => x as usize,
-        None => {
-            eprintln!("Please specify the x coordinate of the start vertex using the --start-x argument");
-            process::exit(1);
+
+    //Initialize a grid problem given the dimensions of the grid graph
+    //and either an entry on its edge or the start and end coordinates
+    let mut problem: GridProblem = if let Some((edge, position)) = cli_args.entry {
+        match GridProblem::entry_exit_on_edge(width, height, edge, position) {
+            Ok(x) => x,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
         }
-    };
-    let start_y: usize = match cli_args.start_y {
-        Some(x) => x as usize,
-        None => {
-            eprintln!("Please specify the y coordinate of the start vertex using the --start-y argument");
-            process::exit(1);
+    } else {
+        let start_x: usize = match cli_args.start_x {
+            Some(x) => x as usize,
+            None => {
+                eprintln!("Please specify the x coordinate of the start vertex using the --start-x argument");
+                process::exit(1);
+            }
+        };
+        let start_y: usize = match cli_args.start_y {
+            Some(x) => x as usize,
+            None => {
+                eprintln!("Please specify the y coordinate of the start vertex using the --start-y argument");
+                process::exit(1);
+            }
+        };
+        let end_x: usize = match cli_args.end_x {
+            Some(x) => x as usize,
+            None => {
+                eprintln!("Please specify the x coordinate of the end vertex using the --end-x argument");
+                process::exit(1);
+            }
+        };
+        let end_y: usize = match cli_args.end_y {
+            Some(x) => x as usize,
+            None => {
+                eprintln!("Please specify the y coordinate of the end vertex using the --end-y argument");
+                process::exit(1);
+            }
+        };
+        match GridProblem::try_new(width, height, [start_x, start_y], [end_x, end_y]) {
+            Ok(x) => x,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
         }
     };
-    let end_x: usize = match cli_args.end_x {
-        Some(x) => x as usize,
-        None => {
-            eprintln!("Please specify the x coordinate of the end vertex using the --end-x argument");
+
+    //If a dry run was requested then describe the plan for the problem
+    //without solving it
+    if cli_args.dry_run {
+        let (is_acceptable, plan) = gridcli::describe_plan(&problem);
+        println!("{}", plan);
+        if !is_acceptable {
             process::exit(1);
         }
-    };
-    let end_y: usize = match cli_args.end_y {
-        Some(x) => x as usize,
-        None => {
-            eprintln!("Please specify the y coordinate of the end vertex using the --end-x argument");
-            process::exit(1);
+        return;
+    }
+
+    //If a brute force limit was given then solve the problem by exhaustive
+    //search within that limit
+    if let Some(limit) = cli_args.bruteforce_limit {
+        let options: SolveOptions = SolveOptions {
+            max_bruteforce_nodes_expanded: limit,
+            ..SolveOptions::default()
+        };
+        let outcome: Result<Option<GridPath>, SolveError> = problem.solve_brute_force_with_options(&options);
+        match &outcome {
+            Ok(Some(x)) => println!("{}", draw(x)),
+            Ok(None) => {
+                eprintln!("The grid problem has no Hamiltonian path between its start and end vertices");
+                write_bug_report(cli_args.bug_report.as_deref(), &problem, &options, &outcome, None);
+                process::exit(1);
+            },
+            Err(e) => {
+                eprintln!("{}", e);
+                write_bug_report(cli_args.bug_report.as_deref(), &problem, &options, &outcome, None);
+                process::exit(1);
+            }
         }
-    };
+        return;
+    }
 
-    //Initialize a grid problem given the dimensions of the grid graph
-    //and the start and end coordinates
-    let mut problem: GridProblem = GridProblem::new(width, height, [start_x, start_y], [end_x, end_y]);
-    let solution: GridPath = match problem.solve() {
+    let (solution, stats) = problem.solve_with_stats();
+    let solution: GridPath = match solution {
         Some(x) => x,
         None => {
             eprintln!(
@@ -67,8 +175,21 @@ fn main() {
     - Its start coordinates were not color compatible, or
     - It was a forbidden problem"
             );
+
+            //Suggest the nearest acceptable endpoints
+            let suggestions: Vec<([usize; 2], [usize; 2], usize)> = problem.suggest_endpoints(4);
+            if !suggestions.is_empty() {
+                eprintln!("Nearest acceptable endpoints:");
+                for (start, end, distance) in suggestions.iter().take(3) {
+                    eprintln!(
+                        "    - ({}, {}) to ({}, {}), moved by {}",
+                        start[0], start[1], end[0], end[1], distance
+                    );
+                }
+            }
+            write_bug_report(cli_args.bug_report.as_deref(), &problem, &SolveOptions::default(), &Ok(None), Some(&stats));
             process::exit(1);
         }
     };
-    println!("{}", solution);
+    println!("{}", draw(&solution));
 }
\ No newline at end of file
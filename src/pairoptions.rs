@@ -0,0 +1,27 @@
+use crate::rect::Rect;
+
+/// # PairOptions struct
+///
+/// The `PairOptions` control which (start, end) vertex pairs are
+/// yielded by `AcceptablePairs`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PairOptions {
+    /// Yield each unordered pair once, as the ordered pair whose start
+    /// comes first in row-major order
+    pub distinct_unordered: bool,
+    /// Only yield pairs whose start vertex is on the boundary of the grid
+    pub starts_on_boundary: bool,
+    /// Only yield pairs whose end vertex lies in the given region
+    pub ends_in_region: Option<Rect>
+}
+
+impl Default for PairOptions {
+    /// Initialize `PairOptions` which yield every acceptable ordered pair
+    fn default() -> PairOptions {
+        PairOptions {
+            distinct_unordered: false,
+            starts_on_boundary: false,
+            ends_in_region: None
+        }
+    }
+}
@@ -0,0 +1,26 @@
+/// # Parity enum
+///
+/// A `Parity` is whether a count is even or odd.  The color of a
+/// vertex of a grid graph is the parity of the sum of its coordinates,
+/// so that adjacent vertices always have different colors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Parity {
+    Even,
+    Odd
+}
+
+impl Parity {
+    /// Get the parity of a count
+    pub fn of(count: usize) -> Parity {
+        if count & 1 == 0 {
+            Parity::Even
+        } else {
+            Parity::Odd
+        }
+    }
+
+    /// Get the color of the vertex at the given coordinates
+    pub fn color_of(v_coords: [usize; 2]) -> Parity {
+        Parity::of(v_coords[0] + v_coords[1])
+    }
+}
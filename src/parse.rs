@@ -0,0 +1,127 @@
+//Import library modules
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// The most digits a number given on the command line may have, enough
+/// for any usize on a 64 bit target
+const MAX_DIGITS: usize = 20;
+
+/// A token of the input along with the byte offset at which it starts
+pub type Token<'a> = (&'a str, usize);
+
+/** ParseError struct schema
+ *
+ * The ParseError struct describes a malformed command line
+ * value, holding the byte span of the offending token within
+ * the value and a hint at the expected syntax
+ */
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub input: String,
+    pub span: Range<usize>,
+    pub message: String,
+    pub expected: &'static str
+}
+
+impl ParseError {
+    /// Initialize a parse error for the given span of the input
+    pub fn new(input: &str, span: Range<usize>, message: String, expected: &'static str) -> ParseError {
+        ParseError {
+            input: String::from(input),
+            span: span,
+            message: message,
+            expected: expected
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    /// Write the message and hint, then the input with a caret under
+    /// the offending token.  Columns are counted in chars rather than
+    /// bytes so the caret lines up under non-ASCII input.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let column: usize = self.input[..self.span.start].chars().count();
+        let width: usize = self.input[self.span.clone()].chars().count().max(1);
+        write!(
+            f, "{}, expected {}\n    {}\n    {}{}",
+            self.message, self.expected, self.input, " ".repeat(column), "^".repeat(width)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Split the input at the first occurrence of the separator, returning
+/// each side along with the byte offset at which it starts
+pub fn split_pair<'a>(input: &'a str, separator: char, expected: &'static str) -> Result<(Token<'a>, Token<'a>), ParseError> {
+    match input.split_once(separator) {
+        Some((left, right)) => Ok(((left, 0), (right, left.len() + separator.len_utf8()))),
+        None => Err(ParseError::new(
+            input, input.len()..input.len(), format!("missing '{}'", separator), expected
+        ))
+    }
+}
+
+/// Parse a token starting at the given byte offset of the input as a
+/// number.  Only the ASCII digits are accepted, so signs, whitespace,
+/// digit grouping and the digits of other scripts are rejected whatever
+/// the locale.
+pub fn parse_number(input: &str, token: &str, start: usize, expected: &'static str) -> Result<usize, ParseError> {
+    if token.is_empty() {
+        return Err(ParseError::new(input, start..start, String::from("missing number"), expected));
+    }
+    if let Some((offset, c)) = token.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(ParseError::new(
+            input, (start + offset)..(start + offset + c.len_utf8()),
+            format!("invalid digit '{}'", c), expected
+        ));
+    }
+    let span: Range<usize> = start..(start + token.len());
+    if token.len() > MAX_DIGITS {
+        return Err(ParseError::new(
+            input, span, format!("number has more than {} digits", MAX_DIGITS), expected
+        ));
+    }
+    match token.parse::<usize>() {
+        Ok(x) => Ok(x),
+        Err(_) => Err(ParseError::new(input, span, String::from("number is too large"), expected))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn split_pair_offsets() {
+        assert_eq!(split_pair("top:12", ':', "").unwrap(), (("top", 0), ("12", 4)));
+        assert_eq!(split_pair("é:1", ':', "").unwrap(), (("é", 0), ("1", 3)));
+        assert_eq!(split_pair("top", ':', "").unwrap_err().span, 3..3);
+    }
+
+    #[test]
+    fn parse_number_inputs() {
+        //Valid
+        assert_eq!(parse_number("top:12", "12", 4, ""), Ok(12));
+
+        //Empty
+        assert_eq!(parse_number("top:", "", 4, "").unwrap_err().span, 4..4);
+
+        //Overlong, whether by digit count or by value
+        let overlong: String = "9".repeat(MAX_DIGITS + 1);
+        assert_eq!(parse_number(&overlong, &overlong, 0, "").unwrap_err().span, 0..(MAX_DIGITS + 1));
+        assert_eq!(parse_number("99999999999999999999", "99999999999999999999", 0, "").unwrap_err().message, "number is too large");
+
+        //Non-ASCII digits and signs are rejected at the offending char
+        assert_eq!(parse_number("x:1٣", "1٣", 2, "").unwrap_err().span, 3..5);
+        assert_eq!(parse_number("+3", "+3", 0, "").unwrap_err().span, 0..1);
+    }
+
+    #[test]
+    fn caret_under_span() {
+        //The caret is placed by chars so it lines up after non-ASCII input
+        let error: ParseError = parse_number("é:1x", "1x", 3, "a number").unwrap_err();
+        assert_eq!(format!("{}", error), "invalid digit 'x', expected a number\n    é:1x\n       ^");
+    }
+}
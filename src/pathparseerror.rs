@@ -0,0 +1,60 @@
+use std::fmt;
+use std::error::Error;
+use crate::gridpatherror::GridPathError;
+
+/// # PathParseError enum
+///
+/// A `PathParseError` describes why a `GridPath` could not be read
+/// from its textual representation.
+#[derive(Debug, PartialEq)]
+pub enum PathParseError {
+    Unsatisfiable,
+    InvalidLiteral(String),
+    VariableOutOfRange(usize),
+    MissingPosition(usize),
+    RepeatedPosition(usize),
+    InvalidPath(GridPathError),
+    InvalidRun(String),
+    RunOutOfBounds(String)
+}
+
+impl fmt::Display for PathParseError {
+    /// Format a PathParseError as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathParseError::Unsatisfiable => write!(
+                f, "Model reports the formula is unsatisfiable"
+            ),
+            PathParseError::InvalidLiteral(literal) => write!(
+                f, "Invalid literal in model: {}",
+                literal
+            ),
+            PathParseError::VariableOutOfRange(var) => write!(
+                f, "Variable out of range of the grid: {}",
+                var
+            ),
+            PathParseError::MissingPosition(k) => write!(
+                f, "No vertex is assigned to position {}",
+                k
+            ),
+            PathParseError::RepeatedPosition(k) => write!(
+                f, "More than one vertex is assigned to position {}",
+                k
+            ),
+            PathParseError::InvalidPath(e) => write!(
+                f, "Input does not describe a valid path: {}",
+                e
+            ),
+            PathParseError::InvalidRun(run) => write!(
+                f, "Invalid run, expected a count then R, U, L or D: {}",
+                run
+            ),
+            PathParseError::RunOutOfBounds(run) => write!(
+                f, "Run leaves the grid: {}",
+                run
+            )
+        }
+    }
+}
+
+impl Error for PathParseError {}
@@ -0,0 +1,21 @@
+/// # PrimeBlock struct
+///
+/// A `PrimeBlock` holds the stored prime solutions of one grid dimension,
+/// each as the vertex order of a Hamiltonian path over the grid graph
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrimeBlock {
+    /// The width of the grid graph
+    pub width: usize,
+    /// The height of the grid graph
+    pub height: usize,
+    /// The vertex orders of the stored paths
+    pub paths: Vec<Vec<[usize; 2]>>
+}
+
+impl PrimeBlock {
+    /// Get the stored path between the given start and end vertices, if
+    /// there is one
+    pub fn find(&self, start: [usize; 2], end: [usize; 2]) -> Option<&Vec<[usize; 2]>> {
+        self.paths.iter().find(|path| path.first() == Some(&start) && path.last() == Some(&end))
+    }
+}
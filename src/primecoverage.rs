@@ -0,0 +1,34 @@
+use std::fmt;
+
+/// # PrimeCoverage struct
+///
+/// A `PrimeCoverage` describes how many of the acceptable endpoint
+/// pairs of one grid dimension have a stored prime solution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrimeCoverage {
+    /// The width of the grid graph
+    pub width: usize,
+    /// The height of the grid graph
+    pub height: usize,
+    /// The number of acceptable (start, end) pairs for the dimension
+    pub num_acceptable: usize,
+    /// The number of acceptable (start, end) pairs with a stored path
+    pub num_covered: usize
+}
+
+impl PrimeCoverage {
+    /// Check if every acceptable endpoint pair has a stored path
+    pub fn is_complete(&self) -> bool {
+        self.num_covered == self.num_acceptable
+    }
+}
+
+impl fmt::Display for PrimeCoverage {
+    /// Format a PrimeCoverage as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f, "{}x{}: {} of {} acceptable endpoint pairs stored",
+            self.width, self.height, self.num_covered, self.num_acceptable
+        )
+    }
+}
@@ -0,0 +1,265 @@
+use std::process;
+use crate::acceptablepairs::AcceptablePairs;
+use crate::gridpath::{GridPath, PRIME_SOLUTION_JSON};
+use crate::pairoptions::PairOptions;
+use crate::primeblock::PrimeBlock;
+use crate::primecoverage::PrimeCoverage;
+use crate::primetableerror::PrimeTableError;
+
+use json::JsonValue;
+use lazy_static::lazy_static;
+
+/// # PrimeTable struct
+///
+/// The `PrimeTable` describes the stored prime solutions which the
+/// solver bottoms out on once a problem can be neither stripped nor
+/// split.
+pub struct PrimeTable;
+
+impl PrimeTable {
+    /// Get the (width, height) dimensions which have stored prime
+    /// solutions, in the order they are stored
+    pub fn supported_dimensions() -> &'static [(usize, usize)] {
+        &SUPPORTED_DIMENSIONS
+    }
+
+    /// Get the blocks of stored prime solutions, in the order they are
+    /// stored.  The embedded table is loaded and checked on first use.
+    pub fn blocks() -> &'static [PrimeBlock] {
+        &PRIME_BLOCKS
+    }
+
+    /// Get the stored prime solution for the given dimensions and start
+    /// and end coordinates, if there is one
+    pub fn find(width: usize, height: usize, start: [usize; 2], end: [usize; 2]) -> Option<&'static Vec<[usize; 2]>> {
+        PrimeTable::blocks().iter()
+            .find(|block| block.width == width && block.height == height)?
+            .find(start, end)
+    }
+
+    /// Parse a table of prime solutions from JSON text, as in `from_json`
+    pub fn parse(source: &str) -> Result<Vec<PrimeBlock>, PrimeTableError> {
+        match json::parse(source) {
+            Ok(table) => PrimeTable::from_json(&table),
+            Err(e) => Err(PrimeTableError::InvalidJson(format!("{}", e)))
+        }
+    }
+
+    /// Convert a table of prime solutions from JSON, an array of blocks
+    /// each giving the dimensions n and m and an array of paths.  Every
+    /// vertex must be a pair of coordinates and every path a Hamiltonian
+    /// path over its block's grid graph, otherwise the error names the
+    /// offending block, path and vertex.
+    pub fn from_json(table: &JsonValue) -> Result<Vec<PrimeBlock>, PrimeTableError> {
+        if !table.is_array() {
+            return Err(PrimeTableError::NotAnArray);
+        }
+        let mut blocks: Vec<PrimeBlock> = Vec::new();
+        for (i, block_json) in table.members().enumerate() {
+            let (width, height): (usize, usize) = match (block_json["n"].as_usize(), block_json["m"].as_usize()) {
+                (Some(n), Some(m)) if block_json["paths"].is_array() => (n, m),
+                _ => return Err(PrimeTableError::InvalidBlock { block: i })
+            };
+            let mut paths: Vec<Vec<[usize; 2]>> = Vec::new();
+            for (j, path_json) in block_json["paths"].members().enumerate() {
+                if !path_json.is_array() {
+                    return Err(PrimeTableError::InvalidVertex { block: i, path: j, vertex: 0 });
+                }
+                let mut vertex_order: Vec<[usize; 2]> = Vec::new();
+                for (k, v) in path_json.members().enumerate() {
+                    match (v.len(), v[0].as_usize(), v[1].as_usize()) {
+                        (2, Some(x), Some(y)) if v.is_array() => vertex_order.push([x, y]),
+                        _ => return Err(PrimeTableError::InvalidVertex { block: i, path: j, vertex: k })
+                    }
+                }
+                if let Err(e) = GridPath::validate_vertex_order(width, height, &vertex_order) {
+                    return Err(PrimeTableError::InvalidPath { block: i, path: j, error: e });
+                }
+                paths.push(vertex_order);
+            }
+            blocks.push(PrimeBlock {
+                width: width,
+                height: height,
+                paths: paths
+            });
+        }
+        Ok(blocks)
+    }
+
+    /// Check if there is a stored prime solution for the given
+    /// dimensions and start and end coordinates
+    pub fn is_covered(width: usize, height: usize, start: [usize; 2], end: [usize; 2]) -> bool {
+        GridPath::is_prime(width, height, start, end)
+    }
+
+    /// Report, for each supported dimension, how many of its acceptable
+    /// endpoint pairs have a stored prime solution
+    pub fn coverage_report() -> Vec<PrimeCoverage> {
+        let mut report: Vec<PrimeCoverage> = Vec::new();
+        for &(width, height) in PrimeTable::supported_dimensions() {
+            let mut num_acceptable: usize = 0;
+            let mut num_covered: usize = 0;
+
+            //Count every ordered pair of vertices which the acceptability
+            //conditions allow, and those which are stored
+            for (start, end) in AcceptablePairs::new(width, height, PairOptions::default()) {
+                num_acceptable += 1;
+                if PrimeTable::is_covered(width, height, start, end) {
+                    num_covered += 1;
+                }
+            }
+            report.push(PrimeCoverage {
+                width: width,
+                height: height,
+                num_acceptable: num_acceptable,
+                num_covered: num_covered
+            });
+        }
+        report
+    }
+
+    /// Get a checksum of the stored prime solutions, which changes
+    /// whenever a stored path does
+    pub fn checksum() -> u64 {
+        checksum_of(&PRIME_SOLUTION_JSON)
+    }
+}
+
+/// Get the FNV-1a hash of the dimensions and vertices of every path in a
+/// table of prime solutions, in the order they are stored
+fn checksum_of(table: &JsonValue) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for dimension_solutions in table.members() {
+        let mut values: Vec<usize> = vec![
+            dimension_solutions["n"].as_usize().unwrap_or(0),
+            dimension_solutions["m"].as_usize().unwrap_or(0)
+        ];
+        for prime_path in dimension_solutions["paths"].members() {
+            values.push(prime_path.len());
+            for v in prime_path.members() {
+                values.push(v[0].as_usize().unwrap_or(0));
+                values.push(v[1].as_usize().unwrap_or(0));
+            }
+        }
+        for value in values {
+            for byte in (value as u64).to_le_bytes() {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+    hash
+}
+
+lazy_static!{
+    static ref PRIME_BLOCKS: Vec<PrimeBlock> = match PrimeTable::from_json(&PRIME_SOLUTION_JSON) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    static ref SUPPORTED_DIMENSIONS: Vec<(usize, usize)> = PRIME_BLOCKS
+        .iter()
+        .map(|block| (block.width, block.height))
+        .collect();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gridpatherror::GridPathError;
+
+    #[test]
+    fn supported_dimensions_match_table() {
+        //The shipped table stores 2x2, 2x3, 3x2, 3x3, 4x5 and 5x4 primes
+        assert_eq!(
+            PrimeTable::supported_dimensions(),
+            &[(2, 2), (2, 3), (3, 2), (3, 3), (4, 5), (5, 4)]
+        );
+    }
+
+    #[test]
+    fn is_covered_matches_stored_paths() {
+        //The 4x5 table stores the path from (0, 1) to (1, 1) but not
+        //the one from (0, 0) to (1, 0), and no 6x6 paths are stored
+        assert_eq!(PrimeTable::is_covered(4, 5, [0, 1], [1, 1]), true);
+        assert_eq!(PrimeTable::is_covered(4, 5, [0, 0], [1, 0]), false);
+        assert_eq!(PrimeTable::is_covered(6, 6, [0, 0], [5, 5]), false);
+    }
+
+    #[test]
+    fn coverage_report_counts() {
+        let report: Vec<PrimeCoverage> = PrimeTable::coverage_report();
+        let counts: Vec<(usize, usize, usize, usize)> = report.iter()
+            .map(|c| (c.width, c.height, c.num_covered, c.num_acceptable))
+            .collect();
+
+        //The small dimensions are fully covered while the
+        //4x5 and 5x4 tables only store a handful of their pairs
+        assert_eq!(counts, vec![
+            (2, 2, 8, 8),
+            (2, 3, 16, 16),
+            (3, 2, 16, 16),
+            (3, 3, 20, 20),
+            (4, 5, 8, 200),
+            (5, 4, 8, 200)
+        ]);
+        assert_eq!(report[0].is_complete(), true);
+        assert_eq!(report[4].is_complete(), false);
+        assert_eq!(format!("{}", report[4]), "4x5: 8 of 200 acceptable endpoint pairs stored");
+    }
+
+    #[test]
+    fn checksum_tracks_table() {
+        //The checksum is stable for the shipped table
+        assert_eq!(PrimeTable::checksum(), PrimeTable::checksum());
+        assert_eq!(PrimeTable::checksum(), checksum_of(&PRIME_SOLUTION_JSON));
+
+        //Changing a single stored vertex or adding a path changes it
+        let mut table: JsonValue = PRIME_SOLUTION_JSON.clone();
+        table[0]["paths"][0][0][0] = 1.into();
+        assert_eq!(checksum_of(&table) != PrimeTable::checksum(), true);
+        let mut table: JsonValue = PRIME_SOLUTION_JSON.clone();
+        table[0]["paths"].push(json::array![[0, 0], [0, 1], [1, 1], [1, 0]]).unwrap();
+        assert_eq!(checksum_of(&table) != PrimeTable::checksum(), true);
+    }
+
+    #[test]
+    fn embedded_table_loads() {
+        //The embedded table converts without error, block for block
+        let blocks: Vec<PrimeBlock> = PrimeTable::from_json(&PRIME_SOLUTION_JSON).unwrap();
+        assert_eq!(blocks.len(), PRIME_SOLUTION_JSON.len());
+        assert_eq!(PrimeTable::blocks(), blocks.as_slice());
+        assert_eq!(PrimeTable::find(2, 3, [0, 0], [1, 0]), Some(&vec![[0, 0], [0, 1], [0, 2], [1, 2], [1, 1], [1, 0]]));
+        assert_eq!(PrimeTable::find(6, 6, [0, 0], [5, 5]), None);
+    }
+
+    #[test]
+    fn corrupted_table_errors() {
+        //Malformed JSON and blocks
+        assert_eq!(matches!(PrimeTable::parse("[{"), Err(PrimeTableError::InvalidJson(_))), true);
+        assert_eq!(PrimeTable::parse("{}"), Err(PrimeTableError::NotAnArray));
+        assert_eq!(
+            PrimeTable::parse(r#"[{ "n": 2, "m": 2, "paths": [] }, { "n": 2, "paths": [] }]"#),
+            Err(PrimeTableError::InvalidBlock { block: 1 })
+        );
+
+        //A vertex which is not a pair of coordinates is named in full
+        let error: PrimeTableError = PrimeTable::parse(r#"[
+            { "n": 2, "m": 2, "paths": [
+                [ [0, 0], [1, 0], [1, 1], [0, 1] ],
+                [ [0, 0], [0, 1], [1, -1], [1, 0] ]
+            ] }
+        ]"#).unwrap_err();
+        assert_eq!(error, PrimeTableError::InvalidVertex { block: 0, path: 1, vertex: 2 });
+        assert_eq!(format!("{}", error), "Prime table block 0 path 1 vertex 2 is not a pair of coordinates");
+
+        //A path stepping out of bounds, like the 5x4 row once stored with
+        //[4, 4], is caught at load time
+        assert_eq!(
+            PrimeTable::parse(r#"[{ "n": 2, "m": 2, "paths": [ [ [0, 0], [1, 0], [1, 1], [1, 2] ] ] }]"#),
+            Err(PrimeTableError::InvalidPath { block: 0, path: 0, error: GridPathError::OutOfBounds([1, 2]) })
+        );
+    }
+}
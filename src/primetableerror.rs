@@ -0,0 +1,47 @@
+use std::fmt;
+use std::error::Error;
+use crate::gridpatherror::GridPathError;
+
+/// # PrimeTableError enum
+///
+/// A `PrimeTableError` describes why a table of prime solutions could
+/// not be loaded, naming the dimension block, path and vertex at fault
+/// where there is one.  Blocks, paths and vertices are counted from 0
+/// in the order they are stored.
+#[derive(Debug, PartialEq)]
+pub enum PrimeTableError {
+    InvalidJson(String),
+    NotAnArray,
+    InvalidBlock { block: usize },
+    InvalidVertex { block: usize, path: usize, vertex: usize },
+    InvalidPath { block: usize, path: usize, error: GridPathError }
+}
+
+impl fmt::Display for PrimeTableError {
+    /// Format a PrimeTableError as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrimeTableError::InvalidJson(e) => write!(
+                f, "Prime table is not valid JSON: {}",
+                e
+            ),
+            PrimeTableError::NotAnArray => write!(
+                f, "Prime table is not an array of dimension blocks"
+            ),
+            PrimeTableError::InvalidBlock { block } => write!(
+                f, "Prime table block {} does not give its dimensions n and m and an array of paths",
+                block
+            ),
+            PrimeTableError::InvalidVertex { block, path, vertex } => write!(
+                f, "Prime table block {} path {} vertex {} is not a pair of coordinates",
+                block, path, vertex
+            ),
+            PrimeTableError::InvalidPath { block, path, error } => write!(
+                f, "Prime table block {} path {} is not a Hamiltonian path: {}",
+                block, path, error
+            )
+        }
+    }
+}
+
+impl Error for PrimeTableError {}
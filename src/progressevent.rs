@@ -0,0 +1,13 @@
+/// # ProgressEvent struct
+///
+/// A `ProgressEvent` reports how far a solve has progressed each
+/// time one of its sub-problems is completed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProgressEvent {
+    /// The number of vertices covered by the completed sub-problems
+    pub cells_completed: usize,
+    /// The number of vertices in the grid problem being solved
+    pub cells_total: usize,
+    /// The recursion depth of the sub-problem just completed
+    pub depth: usize
+}
@@ -0,0 +1,172 @@
+use crate::gridgraph::GridGraph;
+
+/// # Rect struct
+///
+/// A `Rect` is an axis-aligned rectangle of vertices of a grid graph,
+/// given by its lower left and upper right corners, both of which
+/// belong to the rectangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    pub min: [usize; 2],
+    pub max: [usize; 2]
+}
+
+impl Rect {
+    /// Initialize a Rect given its lower left and upper right corners,
+    /// or None if the corners are out of order
+    pub fn new(min: [usize; 2], max: [usize; 2]) -> Option<Rect> {
+        if min[0] > max[0] || min[1] > max[1] {
+            return None;
+        }
+        Some(Rect {
+            min: min,
+            max: max
+        })
+    }
+
+    /// Initialize a Rect covering a whole width by height grid graph, or
+    /// None if the grid graph has no vertices
+    pub fn from_dimensions(width: usize, height: usize) -> Option<Rect> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        Rect::new([0, 0], [width - 1, height - 1])
+    }
+
+    /// Get the number of columns of the Rect
+    pub fn get_width(&self) -> usize {
+        self.max[0] - self.min[0] + 1
+    }
+
+    /// Get the number of rows of the Rect
+    pub fn get_height(&self) -> usize {
+        self.max[1] - self.min[1] + 1
+    }
+
+    /// Get the number of vertices in the Rect
+    pub fn get_area(&self) -> usize {
+        self.get_width() * self.get_height()
+    }
+
+    /// Check if the vertex at the given coordinates lies in the Rect
+    pub fn contains(&self, coords: [usize; 2]) -> bool {
+        coords[0] >= self.min[0] && coords[0] <= self.max[0] &&
+        coords[1] >= self.min[1] && coords[1] <= self.max[1]
+    }
+
+    /// Get the Rect of the vertices in both Rects, if there are any
+    pub fn intersect(&self, other: &Rect) -> Option<Rect> {
+        let min: [usize; 2] = [self.min[0].max(other.min[0]), self.min[1].max(other.min[1])];
+        let max: [usize; 2] = [self.max[0].min(other.max[0]), self.max[1].min(other.max[1])];
+        Rect::new(min, max)
+    }
+
+    /// Get the number of vertices in either Rect
+    pub fn union_area(&self, other: &Rect) -> usize {
+        let intersection_area: usize = match self.intersect(other) {
+            Some(x) => x.get_area(),
+            None => 0
+        };
+        self.get_area() + other.get_area() - intersection_area
+    }
+
+    /// Iterate over the coordinates of the vertices in the Rect row by
+    /// row from its lower left corner
+    pub fn cells(&self) -> impl Iterator<Item = [usize; 2]> {
+        let min: [usize; 2] = self.min;
+        GridGraph::cells_with_dimensions(self.get_width(), self.get_height())
+            .map(move |v_coords| [v_coords[0] + min[0], v_coords[1] + min[1]])
+    }
+
+    /// Get the coordinates of a vertex relative to the lower left corner
+    /// of the Rect, as in a grid graph of the Rect's dimensions
+    pub fn to_local(&self, coords: [usize; 2]) -> [usize; 2] {
+        [coords[0] - self.min[0], coords[1] - self.min[1]]
+    }
+
+    /// Split the Rect between row y and the row above it, returning the
+    /// lower then the upper Rect, or None if either would be empty
+    pub fn split_at_row(&self, y: usize) -> Option<(Rect, Rect)> {
+        Some((
+            Rect::new(self.min, [self.max[0], y])?,
+            Rect::new([self.min[0], y.checked_add(1)?], self.max)?
+        ))
+    }
+
+    /// Split the Rect between column x and the column to its right,
+    /// returning the left then the right Rect, or None if either would be
+    /// empty
+    pub fn split_at_column(&self, x: usize) -> Option<(Rect, Rect)> {
+        Some((
+            Rect::new(self.min, [x, self.max[1]])?,
+            Rect::new([x.checked_add(1)?, self.min[1]], self.max)?
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dimensions_and_contains() {
+        //A Rect includes both of its corners
+        let my_rect: Rect = Rect::new([1, 2], [3, 2]).unwrap();
+        assert_eq!((my_rect.get_width(), my_rect.get_height(), my_rect.get_area()), (3, 1, 3));
+        assert_eq!(my_rect.contains([1, 2]), true);
+        assert_eq!(my_rect.contains([3, 2]), true);
+        assert_eq!(my_rect.contains([0, 2]), false);
+        assert_eq!(my_rect.contains([2, 3]), false);
+        assert_eq!(Rect::from_dimensions(4, 3), Rect::new([0, 0], [3, 2]));
+    }
+
+    #[test]
+    fn intersect_and_union_area() {
+        //Two overlapping 3 by 3 Rects share a 2 by 2 corner
+        let a: Rect = Rect::new([0, 0], [2, 2]).unwrap();
+        let b: Rect = Rect::new([1, 1], [3, 3]).unwrap();
+        assert_eq!(a.intersect(&b), Rect::new([1, 1], [2, 2]));
+        assert_eq!(a.union_area(&b), 9 + 9 - 4);
+
+        //Rects which only touch along an edge do not intersect
+        let c: Rect = Rect::new([3, 0], [4, 2]).unwrap();
+        assert_eq!(a.intersect(&c), None);
+        assert_eq!(a.union_area(&c), 9 + 6);
+    }
+
+    #[test]
+    fn cells_and_to_local() {
+        //Cells are iterated row by row from the lower left corner
+        let my_rect: Rect = Rect::new([1, 1], [2, 2]).unwrap();
+        assert_eq!(my_rect.cells().collect::<Vec<[usize; 2]>>(), vec![[1, 1], [2, 1], [1, 2], [2, 2]]);
+        assert_eq!(my_rect.cells().map(|v| my_rect.to_local(v)).collect::<Vec<[usize; 2]>>(), vec![[0, 0], [1, 0], [0, 1], [1, 1]]);
+    }
+
+    #[test]
+    fn split_at_row_and_column() {
+        //Splitting keeps every vertex in exactly one of the two halves
+        let my_rect: Rect = Rect::from_dimensions(5, 4).unwrap();
+        let (lower, upper) = my_rect.split_at_row(1).unwrap();
+        assert_eq!((lower, upper), (Rect::new([0, 0], [4, 1]).unwrap(), Rect::new([0, 2], [4, 3]).unwrap()));
+        assert_eq!(lower.get_area() + upper.get_area(), my_rect.get_area());
+        let (left, right) = my_rect.split_at_column(3).unwrap();
+        assert_eq!((left, right), (Rect::new([0, 0], [3, 3]).unwrap(), Rect::new([4, 0], [4, 3]).unwrap()));
+        assert_eq!(left.intersect(&right), None);
+    }
+
+    #[test]
+    fn invalid_corners_and_dimensions() {
+        //Corners out of order and grids without vertices give no Rect
+        assert_eq!(Rect::new([2, 0], [1, 3]), None);
+        assert_eq!(Rect::new([0, 4], [1, 3]), None);
+        assert_eq!(Rect::from_dimensions(0, 3), None);
+        assert_eq!(Rect::from_dimensions(3, 0), None);
+        assert_eq!(Rect::from_dimensions(1, 1), Rect::new([0, 0], [0, 0]));
+
+        //Splitting at the last row or column would leave an empty half
+        let my_rect: Rect = Rect::from_dimensions(5, 4).unwrap();
+        assert_eq!(my_rect.split_at_row(3), None);
+        assert_eq!(my_rect.split_at_column(4), None);
+        assert_eq!(my_rect.split_at_column(usize::MAX), None);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::glyphset::GlyphSet;
+
+/// # RenderOptions struct
+///
+/// The `RenderOptions` control how a `GridPath` is drawn by
+/// `GridPath::render`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderOptions {
+    /// The glyphs used to draw the path
+    pub glyphs: GlyphSet,
+    /// The vertices to draw using the highlight glyph
+    pub highlight: Vec<[usize; 2]>
+}
+
+impl Default for RenderOptions {
+    /// Initialize `RenderOptions` which draw the path as its display does
+    fn default() -> RenderOptions {
+        RenderOptions {
+            glyphs: GlyphSet::ASCII,
+            highlight: Vec::new()
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::process;
+use json::JsonValue;
+use lazy_static::lazy_static;
+use crate::schemaerror::SchemaError;
+
+/// The JSON Schema document describing the problem and solution formats,
+/// with `FORMAT_VERSION` standing in for the crate's format version.
+///
+/// A problem gives the `format_version`, the grid's `width` and `height`
+/// and its `start` and `end` vertices as `[x, y]` pairs with the origin
+/// at the bottom left.  A solution gives the `format_version`, the
+/// `problem` it solves and the `path` as the vertices in the order
+/// visited.  No other fields are allowed, so consumers may rely on
+/// exactly these.
+const SCHEMA_TEMPLATE: &str = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "title": "grid-solver solution",
+  "$defs": {
+    "format_version": { "const": FORMAT_VERSION },
+    "vertex": {
+      "type": "array",
+      "items": { "type": "integer", "minimum": 0 },
+      "minItems": 2,
+      "maxItems": 2
+    },
+    "problem": {
+      "type": "object",
+      "required": ["format_version", "width", "height", "start", "end"],
+      "properties": {
+        "format_version": { "$ref": "#/$defs/format_version" },
+        "width": { "type": "integer", "minimum": 1 },
+        "height": { "type": "integer", "minimum": 1 },
+        "start": { "$ref": "#/$defs/vertex" },
+        "end": { "$ref": "#/$defs/vertex" }
+      },
+      "additionalProperties": false
+    },
+    "solution": {
+      "type": "object",
+      "required": ["format_version", "problem", "path"],
+      "properties": {
+        "format_version": { "$ref": "#/$defs/format_version" },
+        "problem": { "$ref": "#/$defs/problem" },
+        "path": {
+          "type": "array",
+          "items": { "$ref": "#/$defs/vertex" },
+          "minItems": 1
+        }
+      },
+      "additionalProperties": false
+    }
+  },
+  "$ref": "#/$defs/solution"
+}"##;
+
+lazy_static!{
+    static ref SCHEMA: String = SCHEMA_TEMPLATE.replace("FORMAT_VERSION", &crate::FORMAT_VERSION.to_string());
+    static ref SCHEMA_JSON: JsonValue = match json::parse(&SCHEMA) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("Embedded schema is not valid JSON: {}", e);
+            process::exit(1);
+        }
+    };
+}
+
+/// Get the JSON Schema document describing the problem and solution
+/// formats, which requires the `format_version` written by the crate
+pub fn document() -> &'static str {
+    &SCHEMA
+}
+
+/// Check that a JSON document follows the solution format of the
+/// embedded schema.  Only the structure is checked, so a document may
+/// pass whose path is not a Hamiltonian path of its problem.
+pub fn validate_solution_json(document: &str) -> Result<(), SchemaError> {
+    validate_against(document, &SCHEMA_JSON)
+}
+
+/// Check that a JSON document follows the problem format of the embedded
+/// schema.  Only the structure is checked, so a document may pass whose
+/// endpoints lie outside its grid.
+pub fn validate_problem_json(document: &str) -> Result<(), SchemaError> {
+    validate_against(document, &SCHEMA_JSON["$defs"]["problem"])
+}
+
+/// Parse a JSON document and check it against the given part of the
+/// embedded schema
+fn validate_against(document: &str, schema: &JsonValue) -> Result<(), SchemaError> {
+    let value: JsonValue = match json::parse(document) {
+        Ok(x) => x,
+        Err(e) => return Err(SchemaError::InvalidJson(format!("{}", e)))
+    };
+    check(schema, &value, "$")
+}
+
+/// Check a value at the given path against a schema, supporting only the
+/// keywords used by the embedded schema
+fn check(schema: &JsonValue, value: &JsonValue, path: &str) -> Result<(), SchemaError> {
+    let mismatch = |message: String| -> Result<(), SchemaError> {
+        Err(SchemaError::Mismatch { path: String::from(path), message: message })
+    };
+
+    //Follow a reference to one of the definitions of the embedded schema
+    if let Some(reference) = schema["$ref"].as_str() {
+        let name: &str = reference.trim_start_matches("#/$defs/");
+        return check(&SCHEMA_JSON["$defs"][name], value, path);
+    }
+
+    //Check the value's type and any constant it must equal
+    let is_integer: bool = value.as_f64().is_some_and(|x| x.is_finite() && x.fract() == 0.0);
+    let expected_type: &str = schema["type"].as_str().unwrap_or("");
+    let is_of_type: bool = match expected_type {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "integer" => is_integer,
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        _ => true
+    };
+    if !is_of_type {
+        let article: &str = if expected_type.starts_with(['a', 'e', 'i', 'o', 'u']) { "an" } else { "a" };
+        return mismatch(format!("expected {} {}", article, expected_type));
+    }
+    if !schema["const"].is_null() && *value != schema["const"] {
+        return mismatch(format!("expected {}", schema["const"]));
+    }
+    if let (Some(minimum), Some(x)) = (schema["minimum"].as_f64(), value.as_f64()) {
+        if x < minimum {
+            return mismatch(format!("expected at least {}", schema["minimum"]));
+        }
+    }
+
+    //Check the fields of an object, naming the first missing field
+    if value.is_object() {
+        for key in schema["required"].members().filter_map(|key| key.as_str()) {
+            if !value.has_key(key) {
+                return Err(SchemaError::Mismatch {
+                    path: format!("{}.{}", path, key),
+                    message: String::from("missing required field")
+                });
+            }
+        }
+        for (key, field) in value.entries() {
+            let field_path: String = format!("{}.{}", path, key);
+            if schema["properties"].has_key(key) {
+                check(&schema["properties"][key], field, &field_path)?;
+            } else if schema["additionalProperties"] == false {
+                return Err(SchemaError::Mismatch { path: field_path, message: String::from("unknown field") });
+            }
+        }
+    }
+
+    //Check the number of items of an array, then each item
+    if value.is_array() {
+        if let Some(min_items) = schema["minItems"].as_usize() {
+            if value.len() < min_items {
+                return mismatch(format!("expected at least {} items, found {}", min_items, value.len()));
+            }
+        }
+        if let Some(max_items) = schema["maxItems"].as_usize() {
+            if value.len() > max_items {
+                return mismatch(format!("expected at most {} items, found {}", max_items, value.len()));
+            }
+        }
+        if !schema["items"].is_null() {
+            for (i, item) in value.members().enumerate() {
+                check(&schema["items"], item, &format!("{}[{}]", path, i))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn schema_version_matches() {
+        //The version written by the serializers should be the one the
+        //embedded schema requires
+        assert_eq!(SCHEMA_JSON["$defs"]["format_version"]["const"].as_u32(), Some(crate::FORMAT_VERSION));
+        assert_eq!(document().contains("FORMAT_VERSION"), false);
+    }
+
+    #[test]
+    fn validate_solution_json_cases() {
+        //A well formed solution passes
+        let valid: &str = r#"{"format_version":1,"problem":{"format_version":1,"width":2,"height":1,"start":[0,0],"end":[1,0]},"path":[[0,0],[1,0]]}"#;
+        assert_eq!(validate_solution_json(valid), Ok(()));
+
+        //Each malformed solution fails at the field at fault
+        let cases: Vec<(&str, &str, &str)> = vec![
+            (r#"{"format_version":2,"problem":{"format_version":1,"width":2,"height":1,"start":[0,0],"end":[1,0]},"path":[[0,0]]}"#, "$.format_version", "expected 1"),
+            (r#"{"format_version":1,"problem":{"format_version":1,"width":2,"start":[0,0],"end":[1,0]},"path":[[0,0]]}"#, "$.problem.height", "missing required field"),
+            (r#"{"format_version":1,"problem":{"format_version":1,"width":0,"height":1,"start":[0,0],"end":[1,0]},"path":[[0,0]]}"#, "$.problem.width", "expected at least 1"),
+            (r#"{"format_version":1,"problem":{"format_version":1,"width":2,"height":1,"start":[0,0],"end":[1,0]},"path":[[0,0],[1]]}"#, "$.path[1]", "expected at least 2 items, found 1"),
+            (r#"{"format_version":1,"problem":{"format_version":1,"width":2,"height":1,"start":[0,0],"end":[1,0]},"path":[[0,0],[1,0.5]]}"#, "$.path[1][1]", "expected an integer"),
+            (r#"{"format_version":1,"problem":{"format_version":1,"width":2,"height":1,"start":[0,0],"end":[1,0]},"path":[],"extra":0}"#, "$.path", "expected at least 1 items, found 0"),
+            (r#"{"format_version":1,"problem":{"format_version":1,"width":2,"height":1,"start":[0,0],"end":[1,0]},"path":[[0,0]],"extra":0}"#, "$.extra", "unknown field"),
+            (r#"[]"#, "$", "expected an object")
+        ];
+        for (document, path, message) in cases {
+            assert_eq!(
+                validate_solution_json(document),
+                Err(SchemaError::Mismatch { path: String::from(path), message: String::from(message) })
+            );
+        }
+
+        //A document which is not JSON at all fails to parse
+        assert_eq!(matches!(validate_solution_json("{"), Err(SchemaError::InvalidJson(_))), true);
+    }
+
+    #[test]
+    fn validate_problem_json_cases() {
+        assert_eq!(validate_problem_json(r#"{"format_version":1,"width":3,"height":2,"start":[0,0],"end":[2,1]}"#), Ok(()));
+        assert_eq!(
+            validate_problem_json(r#"{"format_version":1,"width":3,"height":2,"start":[0,-1],"end":[2,1]}"#),
+            Err(SchemaError::Mismatch { path: String::from("$.start[1]"), message: String::from("expected at least 0") })
+        );
+        assert_eq!(
+            validate_problem_json(r#"{"format_version":1,"width":"3","height":2,"start":[0,0],"end":[2,1]}"#),
+            Err(SchemaError::Mismatch { path: String::from("$.width"), message: String::from("expected an integer") })
+        );
+    }
+}
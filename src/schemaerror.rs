@@ -0,0 +1,31 @@
+use std::fmt;
+use std::error::Error;
+
+/// # SchemaError enum
+///
+/// A `SchemaError` describes why a JSON document does not follow the
+/// embedded schema, naming the path to the field at fault from the root
+/// of the document, written as `$`, e.g. `$.problem.start[1]`.
+#[derive(Debug, PartialEq)]
+pub enum SchemaError {
+    InvalidJson(String),
+    Mismatch { path: String, message: String }
+}
+
+impl fmt::Display for SchemaError {
+    /// Format a SchemaError as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::InvalidJson(e) => write!(
+                f, "Document is not valid JSON: {}",
+                e
+            ),
+            SchemaError::Mismatch { path, message } => write!(
+                f, "{}: {}",
+                path, message
+            )
+        }
+    }
+}
+
+impl Error for SchemaError {}
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::error::Error;
+use crate::gridpatherror::GridPathError;
+
+/// # SolveError enum
+///
+/// A `SolveError` describes why the solver gave up on a grid
+/// problem before determining whether it has a solution, or why
+/// a partial solution it was given cannot be completed.
+#[derive(Debug, PartialEq)]
+pub enum SolveError {
+    TooManyCells { limit: usize, actual: usize },
+    BudgetExceeded { limit: usize },
+    InvalidPrefix(GridPathError),
+    Unsupported { num_remaining: usize, limit: usize },
+    Infeasible,
+    InvalidIntermediate { stage: String, width: usize, height: usize, error: GridPathError },
+    OneWideEndpoints { width: usize, height: usize, endpoints: ([usize; 2], [usize; 2]) },
+    ReconstructionMismatch {
+        expected_dimensions: [usize; 2],
+        actual_dimensions: [usize; 2],
+        expected_endpoints: ([usize; 2], [usize; 2]),
+        actual_endpoints: ([usize; 2], [usize; 2])
+    }
+}
+
+impl fmt::Display for SolveError {
+    /// Format a SolveError as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::TooManyCells { limit, actual } => write!(
+                f, "Grid has {} vertices, more than the brute force limit of {}",
+                actual, limit
+            ),
+            SolveError::BudgetExceeded { limit } => write!(
+                f, "Brute force search expanded more than {} nodes",
+                limit
+            ),
+            SolveError::InvalidPrefix(e) => write!(
+                f, "Invalid path prefix: {}",
+                e
+            ),
+            SolveError::Unsupported { num_remaining, limit } => write!(
+                f, "Completing the path needs {} more vertices, more than the brute force limit of {}",
+                num_remaining, limit
+            ),
+            SolveError::Infeasible => write!(
+                f, "The path cannot be completed to a Hamiltonian path"
+            ),
+            SolveError::InvalidIntermediate { stage, width, height, error } => write!(
+                f, "The {} on the {}x{} sub-problem produced an invalid path: {}",
+                stage, width, height, error
+            ),
+            SolveError::OneWideEndpoints { width, height, endpoints } => write!(
+                f, "The endpoints ({}, {}) and ({}, {}) are not the two ends of the {}x{} sub-problem",
+                endpoints.0[0], endpoints.0[1], endpoints.1[0], endpoints.1[1], width, height
+            ),
+            SolveError::ReconstructionMismatch { expected_dimensions, actual_dimensions, expected_endpoints, actual_endpoints } => write!(
+                f, "Reconstruction gave a {}x{} grid from ({}, {}) to ({}, {}), expected a {}x{} grid from ({}, {}) to ({}, {})",
+                actual_dimensions[0], actual_dimensions[1],
+                actual_endpoints.0[0], actual_endpoints.0[1], actual_endpoints.1[0], actual_endpoints.1[1],
+                expected_dimensions[0], expected_dimensions[1],
+                expected_endpoints.0[0], expected_endpoints.0[1], expected_endpoints.1[0], expected_endpoints.1[1]
+            )
+        }
+    }
+}
+
+impl Error for SolveError {}
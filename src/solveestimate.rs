@@ -0,0 +1,27 @@
+/// # SolveEstimate struct
+///
+/// A `SolveEstimate` describes the work the solver is expected to
+/// perform on a `GridProblem`, computed from its dimensions and
+/// endpoints alone without running the solver.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolveEstimate {
+    /// The number of vertices in the grid graph
+    pub num_cells: usize,
+    /// The number of strips expected to be taken from the right
+    pub num_strips_right: usize,
+    /// The number of strips expected to be taken from above
+    pub num_strips_up: usize,
+    /// The number of strips expected to be taken from the left
+    pub num_strips_left: usize,
+    /// The number of strips expected to be taken from below
+    pub num_strips_down: usize,
+    /// Whether the stripped problem is expected to be split
+    pub is_split_likely: bool
+}
+
+impl SolveEstimate {
+    /// Get the total number of strips expected to be taken
+    pub fn get_num_strips(&self) -> usize {
+        self.num_strips_right + self.num_strips_up + self.num_strips_left + self.num_strips_down
+    }
+}
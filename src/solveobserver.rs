@@ -0,0 +1,37 @@
+use std::sync::mpsc::Sender;
+use crate::progressevent::ProgressEvent;
+
+/// # SolveObserver trait
+///
+/// A `SolveObserver` is notified of the progress of a solve, so
+/// that applications embedding the solver can report it however
+/// they see fit.
+pub trait SolveObserver {
+    /// Handle a progress event, emitted each time a sub-problem
+    /// of the solve is completed
+    fn on_progress(&mut self, event: ProgressEvent);
+}
+
+/// # ChannelObserver struct
+///
+/// A `ChannelObserver` forwards progress events over a channel so
+/// that they may be consumed on another thread while the solve runs.
+pub struct ChannelObserver {
+    sender: Sender<ProgressEvent>
+}
+
+impl ChannelObserver {
+    /// Initialize a ChannelObserver given the sending half of a channel
+    pub fn new(sender: Sender<ProgressEvent>) -> ChannelObserver {
+        ChannelObserver {
+            sender: sender
+        }
+    }
+}
+
+impl SolveObserver for ChannelObserver {
+    /// Send the progress event, ignoring it if the receiver has hung up
+    fn on_progress(&mut self, event: ProgressEvent) {
+        let _ = self.sender.send(event);
+    }
+}
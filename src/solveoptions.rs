@@ -0,0 +1,26 @@
+/// # SolveOptions struct
+///
+/// `SolveOptions` bound the work the solver may do on a grid
+/// problem before giving up with a `SolveError`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolveOptions {
+    /// The largest number of vertices the brute force search will accept
+    pub max_bruteforce_cells: usize,
+    /// The largest number of nodes the brute force search may expand
+    pub max_bruteforce_nodes_expanded: usize,
+    /// Whether to check every intermediate path the solver builds and
+    /// report the first which is not a Hamiltonian path over its grid
+    pub paranoid: bool
+}
+
+impl Default for SolveOptions {
+    /// Initialize `SolveOptions` with limits suited to the prime
+    /// sized problems the brute force search is meant for
+    fn default() -> SolveOptions {
+        SolveOptions {
+            max_bruteforce_cells: 42,
+            max_bruteforce_nodes_expanded: 10_000_000,
+            paranoid: false
+        }
+    }
+}
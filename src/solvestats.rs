@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+/// # SolveStats struct
+///
+/// A `SolveStats` records the time and memory spent by the solver on a
+/// single `GridProblem`, as returned by `GridProblem::solve_with_stats`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolveStats {
+    /// The number of vertices in the grid graph
+    pub num_cells: usize,
+    /// The wall time spent solving the problem
+    pub wall_time: Duration,
+    /// The peak number of bytes allocated during the solve beyond those
+    /// already allocated when it began, only measured when the crate is
+    /// built with the `stats-alloc` feature
+    pub peak_bytes_allocated: Option<usize>,
+    /// The number of positions a scan of every position along every cut
+    /// would have probed when splitting the problem and its sub-problems
+    pub num_split_candidates: usize,
+    /// The number of positions actually probed when splitting, after
+    /// skipping those whose sub-problems cannot be color compatible
+    pub num_split_probes: usize
+}
+
+impl SolveStats {
+    /// Get the number of cells solved per second of wall time
+    pub fn get_cells_per_second(&self) -> f64 {
+        //Guard against a zero duration on coarse clocks
+        let secs: f64 = self.wall_time.as_secs_f64().max(f64::MIN_POSITIVE);
+        self.num_cells as f64 / secs
+    }
+
+    /// Convert the stats to a JSON object with the keys `format_version`,
+    /// `num_cells`, `wall_time_secs`, `cells_per_second`, `peak_bytes_allocated`,
+    /// `num_split_candidates` and `num_split_probes`, the peak being null
+    /// when not measured
+    pub fn to_json_value(&self) -> json::JsonValue {
+        let peak: json::JsonValue = match self.peak_bytes_allocated {
+            Some(x) => x.into(),
+            None => json::JsonValue::Null
+        };
+        json::object!{
+            format_version: crate::FORMAT_VERSION,
+            num_cells: self.num_cells,
+            wall_time_secs: self.wall_time.as_secs_f64(),
+            cells_per_second: self.get_cells_per_second(),
+            peak_bytes_allocated: peak,
+            num_split_candidates: self.num_split_candidates,
+            num_split_probes: self.num_split_probes
+        }
+    }
+
+    /// Convert the stats to a single-line JSON object, as in
+    /// `to_json_value`
+    pub fn to_json(&self) -> String {
+        json::stringify(self.to_json_value())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn to_json_schema() {
+        let stats: SolveStats = SolveStats {
+            num_cells: 12,
+            wall_time: Duration::from_millis(500),
+            peak_bytes_allocated: None,
+            num_split_candidates: 40,
+            num_split_probes: 10
+        };
+        let parsed: json::JsonValue = json::parse(&stats.to_json()).unwrap();
+
+        //Assert each key is present with the expected type
+        assert_eq!(parsed["num_cells"].as_usize(), Some(12));
+        assert_eq!(parsed["wall_time_secs"].as_f64(), Some(0.5));
+        assert_eq!(parsed["cells_per_second"].as_f64(), Some(24.0));
+        assert_eq!(parsed["peak_bytes_allocated"].is_null(), true);
+        assert_eq!(parsed["num_split_candidates"].as_usize(), Some(40));
+        assert_eq!(parsed["num_split_probes"].as_usize(), Some(10));
+        assert_eq!(parsed["format_version"].as_u32(), Some(crate::FORMAT_VERSION));
+        assert_eq!(parsed.len(), 7);
+
+        //Assert a measured peak is written as a number
+        let measured: SolveStats = SolveStats {
+            peak_bytes_allocated: Some(1024),
+            ..stats
+        };
+        let parsed: json::JsonValue = json::parse(&measured.to_json()).unwrap();
+        assert_eq!(parsed["peak_bytes_allocated"].as_usize(), Some(1024));
+    }
+}
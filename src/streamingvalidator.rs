@@ -0,0 +1,136 @@
+use crate::gridgraph::GridGraph;
+use crate::gridpatherror::GridPathError;
+
+/// # StreamingValidator struct
+///
+/// A `StreamingValidator` checks that a vertex order forms a
+/// Hamiltonian path over an n by m grid graph as its vertices are
+/// received one at a time, without holding the vertex order in
+/// memory.  Visited vertices are tracked in a bitset of one bit per
+/// vertex.
+pub struct StreamingValidator {
+    n: usize,
+    m: usize,
+    visited: Vec<u64>,
+    num_vertices: usize,
+    last_vertex: Option<[usize; 2]>,
+    error: Option<GridPathError>
+}
+
+impl StreamingValidator {
+    /// Initialize a StreamingValidator given the grid dimensions (n by m)
+    pub fn new(n: usize, m: usize) -> StreamingValidator {
+        StreamingValidator {
+            n: n,
+            m: m,
+            visited: vec![0; (n * m).div_ceil(64)],
+            num_vertices: 0,
+            last_vertex: None,
+            error: None
+        }
+    }
+
+    /// Get the number of vertices received so far
+    pub fn get_num_vertices(&self) -> usize {
+        self.num_vertices
+    }
+
+    /// Receive the next vertex of the vertex order, returning the first
+    /// error found in the vertex order so far if there is one
+    pub fn push(&mut self, vertex: [usize; 2]) -> Result<(), GridPathError> {
+        //Count the vertex, and stop checking once an error has been found
+        self.num_vertices += 1;
+        if let Some(ref e) = self.error {
+            return Err(e.clone());
+        }
+
+        //Check that the vertex is in bounds, not yet visited, and adjacent
+        //to the vertex before it
+        let error: Option<GridPathError> = if vertex[0] >= self.n || vertex[1] >= self.m {
+            Some(GridPathError::OutOfBounds(vertex))
+        } else if self.is_visited(vertex) {
+            Some(GridPathError::RepeatedVertex(vertex))
+        } else {
+            match self.last_vertex {
+                Some(v) if v[0].abs_diff(vertex[0]) + v[1].abs_diff(vertex[1]) != 1 => {
+                    Some(GridPathError::NonAdjacent(v, vertex))
+                },
+                _ => None
+            }
+        };
+        if let Some(e) = error {
+            self.error = Some(e.clone());
+            return Err(e);
+        }
+
+        //Mark the vertex visited
+        let index: usize = GridGraph::cell_index_with_width(self.n, vertex);
+        self.visited[index / 64] |= 1 << (index % 64);
+        self.last_vertex = Some(vertex);
+        Ok(())
+    }
+
+    /// Finish receiving the vertex order, checking that it visited
+    /// every vertex and returning the number of vertices received
+    pub fn finish(self) -> Result<usize, GridPathError> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        if self.num_vertices != self.n * self.m {
+            return Err(GridPathError::WrongLength { expected: self.n * self.m, actual: self.num_vertices });
+        }
+        Ok(self.num_vertices)
+    }
+
+    /// Check whether the vertex has been received
+    fn is_visited(&self, vertex: [usize; 2]) -> bool {
+        let index: usize = GridGraph::cell_index_with_width(self.n, vertex);
+        (self.visited[index / 64] >> (index % 64)) & 1 == 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn streaming_valid() {
+        //A Hamiltonian path should be accepted vertex by vertex
+        let mut validator: StreamingValidator = StreamingValidator::new(3, 2);
+        for vertex in [[0, 0], [0, 1], [1, 1], [2, 1], [2, 0], [1, 0]] {
+            assert_eq!(validator.push(vertex), Ok(()));
+        }
+        assert_eq!(validator.finish(), Ok(6));
+    }
+
+    #[test]
+    fn streaming_duplicate() {
+        //An injected duplicate should be caught as it arrives, and the
+        //error should persist while the count of vertices continues
+        let mut validator: StreamingValidator = StreamingValidator::new(3, 2);
+        for vertex in [[0, 0], [0, 1], [1, 1], [1, 0]] {
+            assert_eq!(validator.push(vertex), Ok(()));
+        }
+        assert_eq!(validator.push([1, 1]), Err(GridPathError::RepeatedVertex([1, 1])));
+        assert_eq!(validator.push([2, 1]), Err(GridPathError::RepeatedVertex([1, 1])));
+        assert_eq!(validator.get_num_vertices(), 6);
+        assert_eq!(validator.finish(), Err(GridPathError::RepeatedVertex([1, 1])));
+    }
+
+    #[test]
+    fn streaming_bounds_adjacency_and_length() {
+        //Out of bounds vertices should be rejected
+        let mut validator: StreamingValidator = StreamingValidator::new(3, 2);
+        assert_eq!(validator.push([3, 0]), Err(GridPathError::OutOfBounds([3, 0])));
+
+        //Jumps between vertices should be rejected
+        let mut validator: StreamingValidator = StreamingValidator::new(3, 2);
+        assert_eq!(validator.push([0, 0]), Ok(()));
+        assert_eq!(validator.push([1, 1]), Err(GridPathError::NonAdjacent([0, 0], [1, 1])));
+
+        //Vertex orders which stop short should be rejected on finishing
+        let mut validator: StreamingValidator = StreamingValidator::new(3, 2);
+        assert_eq!(validator.push([0, 0]), Ok(()));
+        assert_eq!(validator.finish(), Err(GridPathError::WrongLength { expected: 6, actual: 1 }));
+    }
+}
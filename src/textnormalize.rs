@@ -0,0 +1,28 @@
+/// Normalize the text drawn by a renderer so that every renderer lays out
+/// its lines the same way: trailing spaces are trimmed from each line and
+/// the text ends without a trailing newline, as a `Display` impl should.
+/// Callers printing the text add the final newline themselves.
+pub fn normalize_rendered(rendered: &str) -> String {
+    rendered
+        .trim_end_matches('\n')
+        .split('\n')
+        .map(|line| line.trim_end_matches(' '))
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize_rendered_lines() {
+        //Trailing spaces are trimmed from every line but leading and inner
+        //spaces are kept
+        assert_eq!(normalize_rendered("o---o  \n|   \n  o o"), "o---o\n|\n  o o");
+
+        //Trailing newlines are dropped, keeping blank lines within the text
+        assert_eq!(normalize_rendered("o\n\no\n\n"), "o\n\no");
+        assert_eq!(normalize_rendered(""), "");
+    }
+}
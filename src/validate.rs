@@ -0,0 +1,99 @@
+use crate::griderror::GridError;
+
+/// The largest number of vertices a grid may have.  Each vertex and
+/// edge of a grid graph takes a `u32` index, and a grid has nearly
+/// twice as many edges as vertices.
+pub const MAX_NUM_VERTICES: usize = (u32::MAX / 2) as usize;
+
+/// Check that a width by height grid has at least one vertex and no
+/// more than `MAX_NUM_VERTICES` vertices
+pub fn validate_dimensions(width: usize, height: usize) -> Result<(), GridError> {
+    if width == 0 || height == 0 {
+        return Err(GridError::ZeroDimension { width: width, height: height });
+    }
+    match width.checked_mul(height) {
+        Some(x) if x <= MAX_NUM_VERTICES => Ok(()),
+        _ => Err(GridError::TooManyVertices { width: width, height: height, limit: MAX_NUM_VERTICES })
+    }
+}
+
+/// Check that the given coordinates lie within a width by height grid
+pub fn validate_coords(width: usize, height: usize, coords: [usize; 2]) -> Result<(), GridError> {
+    if coords[0] >= width || coords[1] >= height {
+        return Err(GridError::CoordsOutOfBounds { coords: coords, width: width, height: height });
+    }
+    Ok(())
+}
+
+/// Check that a grid problem over a width by height grid from the start
+/// to the end coordinates is well formed, that is:
+/// - the grid has at least one vertex and is not too large,
+/// - both endpoints lie within the grid, and
+/// - the endpoints differ unless the grid is a single vertex
+///
+/// A well formed problem may still have no solution, which is checked
+/// by `GridProblem::is_acceptable`.
+pub fn validate_problem(width: usize, height: usize, start: [usize; 2], end: [usize; 2]) -> Result<(), GridError> {
+    validate_dimensions(width, height)?;
+    validate_coords(width, height, start)?;
+    validate_coords(width, height, end)?;
+    if start == end && width * height > 1 {
+        return Err(GridError::EqualEndpoints { coords: start });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validate_zero_dimension() {
+        //A grid with no columns or no rows has no vertices
+        assert_eq!(validate_problem(0, 3, [0, 0], [0, 1]), Err(GridError::ZeroDimension { width: 0, height: 3 }));
+        assert_eq!(validate_problem(3, 0, [0, 0], [1, 0]), Err(GridError::ZeroDimension { width: 3, height: 0 }));
+        assert_eq!(
+            format!("{}", validate_dimensions(0, 3).unwrap_err()),
+            "Grid dimensions must be at least 1 x 1, got 0 x 3"
+        );
+    }
+
+    #[test]
+    fn validate_too_many_vertices() {
+        //Grids whose vertex count overflows or exceeds the limit are rejected
+        assert_eq!(
+            validate_dimensions(usize::MAX, 2),
+            Err(GridError::TooManyVertices { width: usize::MAX, height: 2, limit: MAX_NUM_VERTICES })
+        );
+        assert_eq!(
+            validate_dimensions(MAX_NUM_VERTICES, 2),
+            Err(GridError::TooManyVertices { width: MAX_NUM_VERTICES, height: 2, limit: MAX_NUM_VERTICES })
+        );
+        assert_eq!(validate_dimensions(MAX_NUM_VERTICES, 1), Ok(()));
+    }
+
+    #[test]
+    fn validate_coords_out_of_bounds() {
+        //Either coordinate of either endpoint may be out of bounds
+        assert_eq!(
+            validate_problem(3, 2, [3, 0], [1, 0]),
+            Err(GridError::CoordsOutOfBounds { coords: [3, 0], width: 3, height: 2 })
+        );
+        assert_eq!(
+            validate_problem(3, 2, [0, 0], [1, 2]),
+            Err(GridError::CoordsOutOfBounds { coords: [1, 2], width: 3, height: 2 })
+        );
+        assert_eq!(
+            format!("{}", validate_coords(3, 2, [1, 2]).unwrap_err()),
+            "Vertex (1, 2) is out of bounds of a 3 x 2 grid, coordinates must be below (3, 2)"
+        );
+    }
+
+    #[test]
+    fn validate_equal_endpoints() {
+        //Equal endpoints are only allowed on a single vertex grid
+        assert_eq!(validate_problem(3, 3, [1, 1], [1, 1]), Err(GridError::EqualEndpoints { coords: [1, 1] }));
+        assert_eq!(validate_problem(1, 1, [0, 0], [0, 0]), Ok(()));
+        assert_eq!(validate_problem(3, 2, [0, 0], [1, 0]), Ok(()));
+    }
+}
@@ -0,0 +1,13 @@
+/// # ValidationMode enum
+///
+/// A `ValidationMode` describes what a vertex order is required to
+/// be when a `GridPath` is validated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidationMode {
+    /// Visit every vertex exactly once, stepping between adjacent vertices
+    Hamiltonian,
+    /// Visit no vertex more than once, stepping between adjacent vertices
+    SimplePath,
+    /// Step between adjacent vertices
+    Walk
+}
@@ -0,0 +1,37 @@
+use crate::validationmode::ValidationMode;
+
+/// # ValidationReport struct
+///
+/// A `ValidationReport` describes how closely the vertex order of a
+/// `GridPath` comes to forming a path over its grid graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationReport {
+    /// The mode the path was validated against
+    pub mode: ValidationMode,
+    /// The fraction of the grid's vertices visited at least once
+    pub coverage: f64,
+    /// The vertices outside the grid, in order of their first visit
+    pub out_of_bounds_vertices: Vec<[usize; 2]>,
+    /// The vertices visited more than once, in order of their first repeat
+    pub repeated_vertices: Vec<[usize; 2]>,
+    /// The first pair of consecutive vertices which are not adjacent
+    pub first_adjacency_violation: Option<([usize; 2], [usize; 2])>
+}
+
+impl ValidationReport {
+    /// Check whether the path satisfies the mode it was validated against
+    pub fn is_ok(&self) -> bool {
+        self.is_ok_for(self.mode)
+    }
+
+    /// Check whether the path satisfies the given mode
+    pub fn is_ok_for(&self, mode: ValidationMode) -> bool {
+        let is_walk: bool = self.out_of_bounds_vertices.len() == 0 && self.first_adjacency_violation.is_none();
+        let is_simple: bool = is_walk && self.repeated_vertices.len() == 0;
+        match mode {
+            ValidationMode::Walk        => is_walk,
+            ValidationMode::SimplePath  => is_simple,
+            ValidationMode::Hamiltonian => is_simple && self.coverage == 1.0
+        }
+    }
+}
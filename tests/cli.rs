@@ -0,0 +1,236 @@
+use grid_solver::gridextension::GridExtension;
+use grid_solver::gridpath::GridPath;
+use grid_solver::gridproblem::GridProblem;
+use grid_solver::schema;
+use grid_solver::validate;
+use std::process::{Command, Output};
+
+/// Run the grid solver binary with the given arguments
+fn run_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_grid-solver"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// Run the grid solver binary on a problem given by its dimensions and
+/// endpoints, returning whether it succeeded and its stderr
+fn run_cli_problem(width: usize, height: usize, start: [usize; 2], end: [usize; 2]) -> (bool, String) {
+    let args: Vec<String> = vec![
+        String::from("--width"), width.to_string(),
+        String::from("--height"), height.to_string(),
+        String::from("--start-x"), start[0].to_string(),
+        String::from("--start-y"), start[1].to_string(),
+        String::from("--end-x"), end[0].to_string(),
+        String::from("--end-y"), end[1].to_string()
+    ];
+    let output: Output = run_cli(&args.iter().map(|arg| arg.as_str()).collect::<Vec<&str>>());
+    (output.status.success(), String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn cli_reports_validation_errors() {
+    //Each malformed problem should fail with the library's message
+    let problems: Vec<(usize, usize, [usize; 2], [usize; 2])> = vec![
+        (0, 3, [0, 0], [0, 1]),
+        (3, 0, [0, 0], [1, 0]),
+        (3, 2, [3, 0], [1, 0]),
+        (3, 2, [0, 0], [1, 2]),
+        (3, 3, [1, 1], [1, 1])
+    ];
+    for (width, height, start, end) in problems {
+        let (success, stderr) = run_cli_problem(width, height, start, end);
+        let expected: String = format!("{}\n", validate::validate_problem(width, height, start, end).unwrap_err());
+        assert_eq!(success, false);
+        assert_eq!(stderr, expected);
+
+        //The library constructor should report the same error
+        assert_eq!(
+            format!("{}\n", GridProblem::try_new(width, height, start, end).err().unwrap()),
+            expected
+        );
+    }
+}
+
+#[test]
+fn cli_reports_entry_validation_errors() {
+    //A zero width grid is rejected before the entry is placed
+    let output: Output = run_cli(&["--width", "0", "--height", "4", "--entry", "bottom:0"]);
+    let expected: String = format!(
+        "{}\n",
+        GridProblem::entry_exit_on_edge(0, 4, GridExtension::Down, 0).err().unwrap()
+    );
+    assert_eq!(output.status.success(), false);
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), expected);
+    assert_eq!(expected, "Grid dimensions must be at least 1 x 1, got 0 x 4\n");
+}
+
+#[test]
+fn cli_solves_valid_problem() {
+    //A well formed acceptable problem is solved and printed
+    let (success, stderr) = run_cli_problem(3, 2, [0, 0], [1, 0]);
+    assert_eq!(success, true);
+    assert_eq!(stderr, "");
+}
+
+#[test]
+fn cli_suggests_endpoints() {
+    //An unacceptable problem should be followed by the top suggestions
+    let (success, stderr) = run_cli_problem(4, 4, [0, 0], [1, 1]);
+    assert_eq!(success, false);
+    assert!(stderr.ends_with(
+        "Nearest acceptable endpoints:
+    - (0, 0) to (1, 0), moved by 1
+    - (0, 0) to (0, 1), moved by 1
+    - (0, 0) to (2, 1), moved by 1
+"
+    ));
+}
+
+#[test]
+fn cli_falls_back_from_unicode_glyphs() {
+    //Output captured by the test is not a terminal, so unicode glyphs
+    //fall back to ascii with a note on stderr
+    let args: [&str; 12] = [
+        "--width", "3", "--height", "2", "--start-x", "0", "--start-y", "0",
+        "--end-x", "1", "--end-y", "0"
+    ];
+    let mut unicode_args: Vec<&str> = args.to_vec();
+    unicode_args.extend(["--glyphs", "unicode"]);
+    let ascii: Output = run_cli(&args);
+    let fallback: Output = run_cli(&unicode_args);
+    assert_eq!(fallback.status.success(), true);
+    assert_eq!(fallback.stdout, ascii.stdout);
+    assert_eq!(String::from_utf8(fallback.stderr).unwrap().contains("falling back to ascii"), true);
+
+    //Forcing draws with the unicode glyphs regardless
+    unicode_args.push("--force");
+    let forced: Output = run_cli(&unicode_args);
+    assert_eq!(forced.status.success(), true);
+    assert_eq!(String::from_utf8(forced.stdout).unwrap().contains("●"), true);
+    assert_eq!(forced.stderr.is_empty(), true);
+}
+
+#[test]
+fn cli_solves_single_vertex() {
+    //The only problem on a 1 by 1 grid is solved by its single vertex
+    let output: Output = run_cli(&[
+        "--width", "1", "--height", "1", "--start-x", "0", "--start-y", "0",
+        "--end-x", "0", "--end-y", "0"
+    ]);
+    assert_eq!(output.status.success(), true);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "o\n");
+}
+
+#[test]
+fn cli_solves_thin_grids() {
+    //Each 1 wide and 1 tall grid is solved end to end as the library does
+    for len in 1..51 {
+        for (width, height) in [(len, 1), (1, len)] {
+            let end: [usize; 2] = [width - 1, height - 1];
+            let args: Vec<String> = vec![
+                String::from("--width"), width.to_string(),
+                String::from("--height"), height.to_string(),
+                String::from("--start-x"), String::from("0"),
+                String::from("--start-y"), String::from("0"),
+                String::from("--end-x"), end[0].to_string(),
+                String::from("--end-y"), end[1].to_string()
+            ];
+            let output: Output = run_cli(&args.iter().map(|arg| arg.as_str()).collect::<Vec<&str>>());
+            let solution: GridPath = GridProblem::new(width, height, [0, 0], end).solve().unwrap();
+            assert_eq!(output.status.success(), true);
+            assert_eq!(String::from_utf8(output.stdout).unwrap(), format!("{}\n", solution));
+        }
+    }
+}
+
+#[test]
+fn cli_writes_bug_report_on_failure() {
+    //An unacceptable problem writes a support bundle describing it
+    let path: std::path::PathBuf = std::env::temp_dir().join(format!("grid-solver-bug-report-{}.json", std::process::id()));
+    let path_arg: String = path.display().to_string();
+    let output: Output = run_cli(&[
+        "--width", "4", "--height", "4", "--start-x", "0", "--start-y", "0",
+        "--end-x", "2", "--end-y", "2", "--bug-report", &path_arg
+    ]);
+    assert_eq!(output.status.success(), false);
+    assert_eq!(String::from_utf8(output.stderr).unwrap().ends_with(&format!("Wrote a bug report to {}\n", path_arg)), true);
+    let bundle: json::JsonValue = json::parse(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(bundle["problem"]["end"][0].as_usize(), Some(2));
+    assert_eq!(bundle["outcome"]["status"].as_str(), Some("no_solution"));
+    assert_eq!(bundle["build"]["version"].as_str(), Some(grid_solver::build_info().version));
+
+    //A solved problem writes no bundle
+    let output: Output = run_cli(&[
+        "--width", "4", "--height", "4", "--start-x", "0", "--start-y", "0",
+        "--end-x", "3", "--end-y", "0", "--bug-report", &path_arg
+    ]);
+    assert_eq!(output.status.success(), true);
+    assert_eq!(path.exists(), false);
+}
+
+#[test]
+fn cli_dry_run_prints_plan() {
+    //An acceptable problem prints its verdict and estimate but no solution
+    let output: Output = run_cli(&[
+        "--width", "5", "--height", "4", "--start-x", "0", "--start-y", "0",
+        "--end-x", "4", "--end-y", "1", "--dry-run"
+    ]);
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.status.success(), true);
+    assert_eq!(stdout.lines().nth(1), Some("Verdict: acceptable"));
+    assert_eq!(stdout.lines().nth(2).unwrap().starts_with("Estimate: 20 cells"), true);
+    assert_eq!(stdout.lines().count(), 3);
+    assert_eq!(stdout.contains("---"), false);
+
+    //An unacceptable problem prints its verdict and fails
+    let output: Output = run_cli(&[
+        "--width", "4", "--height", "4", "--start-x", "0", "--start-y", "0",
+        "--end-x", "2", "--end-y", "2", "--dry-run"
+    ]);
+    assert_eq!(output.status.success(), false);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Problem: 4x4 from (0, 0) to (2, 2)\nVerdict: not acceptable, the endpoints must have different colors\n"
+    );
+}
+
+#[test]
+fn cli_color_output() {
+    //Output captured by the test is not a terminal, so color falls back
+    //to the plain drawing with a note on stderr
+    let args: [&str; 12] = [
+        "--width", "5", "--height", "4", "--start-x", "0", "--start-y", "0",
+        "--end-x", "4", "--end-y", "1"
+    ];
+    let plain: Output = run_cli(&args);
+    let fallback: Output = run_cli(&[&args[..], &["--color"]].concat());
+    assert_eq!(fallback.status.success(), true);
+    assert_eq!(fallback.stdout, plain.stdout);
+    assert_eq!(String::from_utf8(fallback.stderr).unwrap().contains("support color glyphs, falling back to ascii"), true);
+
+    //Forcing draws the colored drawing, which escapes the plain drawing
+    let colored: Output = run_cli(&[&args[..], &["--color", "--force"]].concat());
+    assert_eq!(colored.status.success(), true);
+    assert_eq!(colored.stderr.is_empty(), true);
+    let plain_stdout: String = String::from_utf8(plain.stdout).unwrap();
+    let colored_stdout: String = String::from_utf8(colored.stdout).unwrap();
+    assert_eq!(plain_stdout.contains('\x1b'), false);
+    assert_eq!(colored_stdout.contains("\x1b[1;38;2;0;0;255mo"), true);
+    assert_eq!(colored_stdout.lines().count(), plain_stdout.lines().count());
+
+    //Color cannot be combined with other glyphs
+    let conflicting: Output = run_cli(&[&args[..], &["--color", "--glyphs", "unicode"]].concat());
+    assert_eq!(conflicting.status.success(), false);
+}
+
+#[test]
+fn cli_prints_schema() {
+    //The schema subcommand prints the embedded schema as valid JSON
+    let output: Output = run_cli(&["schema"]);
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.status.success(), true);
+    assert_eq!(stdout, format!("{}\n", schema::document()));
+    assert_eq!(json::parse(&stdout).is_ok(), true);
+}
@@ -0,0 +1,149 @@
+use grid_solver::glyphset::GlyphSet;
+use grid_solver::gridpath::GridPath;
+use grid_solver::gridproblem::GridProblem;
+use grid_solver::renderoptions::RenderOptions;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Get the directory holding the golden rendered outputs
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden")
+}
+
+/// Strip the trailing whitespace from each line of a rendered output and
+/// end it with a single newline, so that editors trimming the golden
+/// files do not break the comparison
+fn normalize(rendered: &str) -> String {
+    let mut normalized: String = rendered.lines()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n");
+    while normalized.ends_with('\n') {
+        normalized.pop();
+    }
+    normalized.push('\n');
+    normalized
+}
+
+/// Get the fixed set of paths the corpus is rendered from
+fn golden_paths() -> Vec<(&'static str, GridPath)> {
+    vec![
+        ("snake_3x2", GridPath::new(3, 2, vec![
+            [0, 0], [0, 1], [1, 1], [1, 0], [2, 0], [2, 1]
+        ])),
+        ("single_row_4x1", GridPath::new(4, 1, vec![
+            [0, 0], [1, 0], [2, 0], [3, 0]
+        ])),
+        ("solved_5x4", GridProblem::new(5, 4, [0, 0], [4, 1]).solve().unwrap()),
+        ("solved_7x6", GridProblem::new(7, 6, [1, 0], [4, 2]).solve().unwrap())
+    ]
+}
+
+/// Render every path of the corpus under every set of render options,
+/// keyed by the name of the golden file each is stored in.  The outputs
+/// are kept as the renderer drew them and normalized when compared.
+fn render_corpus() -> BTreeMap<String, String> {
+    let mut corpus: BTreeMap<String, String> = BTreeMap::new();
+    for (name, path) in golden_paths() {
+        //Highlight every other vertex of the path as markers
+        let markers: Vec<[usize; 2]> = path.get_vertex_order().iter().step_by(2).copied().collect();
+        let variants: Vec<(&str, RenderOptions)> = vec![
+            ("ascii", RenderOptions::default()),
+            ("unicode", RenderOptions { glyphs: GlyphSet::UNICODE, ..RenderOptions::default() }),
+            ("ascii_markers", RenderOptions { highlight: markers.clone(), ..RenderOptions::default() }),
+            ("unicode_markers", RenderOptions { glyphs: GlyphSet::UNICODE, highlight: markers })
+        ];
+        for (variant, options) in variants {
+            corpus.insert(format!("{}.{}.txt", name, variant), path.render(&options));
+        }
+    }
+    corpus
+}
+
+/// Get the names of the golden files currently stored on disk
+fn stored_golden_files() -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    if let Ok(entries) = fs::read_dir(golden_dir()) {
+        for entry in entries {
+            let entry_path: PathBuf = entry.unwrap().path();
+            if entry_path.extension().is_some_and(|extension| extension == "txt") {
+                names.push(entry_path.file_name().unwrap().to_string_lossy().into_owned());
+            }
+        }
+    }
+    names.sort();
+    names
+}
+
+/// Rewrite the golden corpus from the current renderer, removing any
+/// golden files no longer produced
+fn update_golden(corpus: &BTreeMap<String, String>) {
+    fs::create_dir_all(golden_dir()).unwrap();
+    for name in stored_golden_files() {
+        if !corpus.contains_key(&name) {
+            fs::remove_file(golden_dir().join(name)).unwrap();
+        }
+    }
+    for (name, rendered) in corpus.iter() {
+        fs::write(golden_dir().join(name), normalize(rendered)).unwrap();
+    }
+}
+
+#[test]
+fn rendered_outputs_match_golden() {
+    let corpus: BTreeMap<String, String> = render_corpus();
+    if env::var("UPDATE_GOLDEN").is_ok_and(|x| x == "1") {
+        update_golden(&corpus);
+        return;
+    }
+
+    //Collect every mismatch so one run reports all of them
+    let mut failures: Vec<String> = Vec::new();
+    for (name, rendered) in corpus.iter() {
+        match fs::read_to_string(golden_dir().join(name)) {
+            Ok(stored) if normalize(&stored) == normalize(rendered) => {},
+            Ok(stored) => failures.push(format!(
+                "{} differs\n--- golden\n{}--- rendered\n{}", name, normalize(&stored), normalize(rendered)
+            )),
+            Err(_) => failures.push(format!("{} is missing", name))
+        }
+    }
+    for name in stored_golden_files() {
+        if !corpus.contains_key(&name) {
+            failures.push(format!("{} is no longer rendered", name));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{}\n\nRun UPDATE_GOLDEN=1 cargo test --test golden to regenerate the golden files",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn normalize_trims_trailing_whitespace() {
+    assert_eq!(normalize("o---o  \no   o\n\n"), "o---o\no   o\n");
+    assert_eq!(normalize("o"), "o\n");
+}
+
+#[test]
+fn rendered_outputs_are_normalized() {
+    //The renderer itself trims trailing spaces and ends without a newline,
+    //so the golden normalization should find nothing to change
+    for (name, rendered) in render_corpus() {
+        for (i, line) in rendered.lines().enumerate() {
+            assert!(!line.ends_with(' '), "{} has trailing spaces on line {}", name, i + 1);
+        }
+        assert!(!rendered.ends_with('\n'), "{} ends with a newline", name);
+    }
+
+    //The stored golden files should carry no trailing spaces either
+    for name in stored_golden_files() {
+        let stored: String = fs::read_to_string(golden_dir().join(&name)).unwrap();
+        for (i, line) in stored.lines().enumerate() {
+            assert!(!line.ends_with(' '), "{} has trailing spaces on line {}", name, i + 1);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use grid_solver::gridpath::GridPath;
+use grid_solver::gridproblem::GridProblem;
+use grid_solver::schema;
+use grid_solver::schemaerror::SchemaError;
+
+#[test]
+fn serialized_solution_follows_schema() {
+    //A solution serialized by the library should pass the embedded schema
+    let mut problem: GridProblem = GridProblem::new(7, 6, [1, 0], [4, 2]);
+    assert_eq!(schema::validate_problem_json(&problem.to_json()), Ok(()));
+    let solution: GridPath = problem.solve().unwrap();
+    let document: String = solution.to_json();
+    assert_eq!(schema::validate_solution_json(&document), Ok(()));
+
+    //The document should describe the problem it solves
+    let parsed: json::JsonValue = json::parse(&document).unwrap();
+    assert_eq!(parsed["problem"], problem.to_json_value());
+    assert_eq!(parsed["path"].len(), 42);
+}
+
+#[test]
+fn broken_solution_names_field() {
+    //Break one coordinate of the serialized solution and the validator
+    //should name the path to it
+    let solution: GridPath = GridProblem::new(5, 4, [0, 0], [4, 1]).solve().unwrap();
+    let mut parsed: json::JsonValue = json::parse(&solution.to_json()).unwrap();
+    parsed["path"][3][1] = "up".into();
+    let error: SchemaError = schema::validate_solution_json(&json::stringify(parsed)).unwrap_err();
+    assert_eq!(error, SchemaError::Mismatch {
+        path: String::from("$.path[3][1]"),
+        message: String::from("expected an integer")
+    });
+    assert_eq!(format!("{}", error), "$.path[3][1]: expected an integer");
+
+    //Dropping a field of the problem should name the missing field
+    let mut parsed: json::JsonValue = json::parse(&solution.to_json()).unwrap();
+    parsed["problem"].remove("end");
+    assert_eq!(
+        schema::validate_solution_json(&json::stringify(parsed)).unwrap_err(),
+        SchemaError::Mismatch { path: String::from("$.problem.end"), message: String::from("missing required field") }
+    );
+}